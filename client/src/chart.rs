@@ -0,0 +1,283 @@
+//! Chart settings and the conversion of statement results into plottable series.
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use crate::rpc::StatementResult;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+pub enum ChartKind {
+    #[default]
+    Line,
+    Bar,
+    Scatter,
+    Histogram,
+}
+
+impl ChartKind {
+    pub const ALL: [ChartKind; 4] = [
+        ChartKind::Line,
+        ChartKind::Bar,
+        ChartKind::Scatter,
+        ChartKind::Histogram,
+    ];
+}
+
+impl Display for ChartKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            ChartKind::Line => "Line",
+            ChartKind::Bar => "Bar",
+            ChartKind::Scatter => "Scatter",
+            ChartKind::Histogram => "Histogram",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+/// What to plot, columns are referred to by name.
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct ChartSettings {
+    pub kind: ChartKind,
+    pub x: Option<String>,
+    /// unused by histograms, they count the values of `x`
+    pub y: Option<String>,
+    /// one series per distinct value of this column
+    pub series_by: Option<String>,
+    /// histogram bins, derived from the row count if 0
+    pub bins: usize,
+}
+
+impl ChartSettings {
+    /// Picks the first column for x and the first other numeric column for y if they are unset.
+    pub fn fill_defaults(&mut self, result: &StatementResult) {
+        let names = result.columns.iter().map(|c| &c.name);
+        if self.x.is_none() {
+            self.x = names.clone().next().cloned();
+        }
+        if self.y.is_none() {
+            self.y = names
+                .enumerate()
+                .find(|(i, name)| Some(*name) != self.x.as_ref() && has_numbers(result, *i))
+                .map(|(_, name)| name.clone());
+        }
+    }
+}
+
+impl From<proto_gen::saved::Chart> for ChartSettings {
+    fn from(chart: proto_gen::saved::Chart) -> Self {
+        use proto_gen::saved::ChartKind as Kind;
+        Self {
+            kind: match chart.kind() {
+                Kind::Line => ChartKind::Line,
+                Kind::Bar => ChartKind::Bar,
+                Kind::Scatter => ChartKind::Scatter,
+                Kind::Histogram => ChartKind::Histogram,
+            },
+            x: chart.x,
+            y: chart.y,
+            series_by: chart.series_by,
+            bins: chart.bins as usize,
+        }
+    }
+}
+
+impl From<ChartSettings> for proto_gen::saved::Chart {
+    fn from(settings: ChartSettings) -> Self {
+        use proto_gen::saved::ChartKind as Kind;
+        let kind = match settings.kind {
+            ChartKind::Line => Kind::Line,
+            ChartKind::Bar => Kind::Bar,
+            ChartKind::Scatter => Kind::Scatter,
+            ChartKind::Histogram => Kind::Histogram,
+        };
+        Self {
+            kind: kind as i32,
+            x: settings.x,
+            y: settings.y,
+            series_by: settings.series_by,
+            bins: settings.bins as u32,
+        }
+    }
+}
+
+pub enum Axis {
+    Number,
+    /// milliseconds since the epoch
+    Time,
+    /// values are indexes into the category names
+    Category(Vec<String>),
+}
+
+pub struct Series {
+    pub name: String,
+    pub points: Vec<[f64; 2]>,
+}
+
+pub struct ChartData {
+    pub x_axis: Axis,
+    pub series: Vec<Series>,
+    /// in x units, bars of different series are placed next to each other
+    pub bar_width: f64,
+}
+
+pub fn chart_data(result: &StatementResult, settings: &ChartSettings) -> Result<ChartData, String> {
+    let index = |name: &Option<String>| {
+        name.as_ref()
+            .and_then(|name| result.columns.iter().position(|c| &c.name == name))
+    };
+    let x = index(&settings.x).ok_or("pick a column for x")?;
+    let series_by = index(&settings.series_by);
+    if settings.kind == ChartKind::Histogram {
+        return histogram(result, x, series_by, settings.bins);
+    }
+    let y = index(&settings.y).ok_or("pick a column for y")?;
+    if !has_numbers(result, y) {
+        return Err(format!("{} has no numeric values", result.columns[y].name));
+    }
+
+    let mut x_axis = axis(result, x);
+    let mut categories = HashMap::new();
+    let mut series = Series::group(result, series_by, &result.columns[y].name, |cells| {
+        let x = match &mut x_axis {
+            Axis::Category(names) => {
+                let text = &cells[x].text;
+                *categories.entry(text.clone()).or_insert_with(|| {
+                    names.push(text.clone());
+                    names.len() - 1
+                }) as f64
+            }
+            Axis::Number | Axis::Time => cells[x].number?,
+        };
+        Some([x, cells[y].number?])
+    });
+    if settings.kind == ChartKind::Line {
+        for s in series.iter_mut() {
+            s.points.sort_by(|a, b| a[0].total_cmp(&b[0]));
+        }
+    }
+
+    let mut xs = series
+        .iter()
+        .flat_map(|s| s.points.iter().map(|p| p[0]))
+        .collect::<Vec<_>>();
+    xs.sort_by(f64::total_cmp);
+    xs.dedup();
+    let gap = xs
+        .windows(2)
+        .map(|w| w[1] - w[0])
+        .min_by(f64::total_cmp)
+        .unwrap_or(1.0);
+    let bar_width = gap * 0.8 / series.len().max(1) as f64;
+
+    Ok(ChartData {
+        x_axis,
+        series,
+        bar_width,
+    })
+}
+
+fn histogram(
+    result: &StatementResult,
+    x: usize,
+    series_by: Option<usize>,
+    bins: usize,
+) -> Result<ChartData, String> {
+    let values = result
+        .rows
+        .iter()
+        .filter_map(|r| r.cells[x].number)
+        .collect::<Vec<_>>();
+    if values.is_empty() {
+        return Err(format!("{} has no numeric values", result.columns[x].name));
+    }
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let bins = match bins {
+        0 => (values.len() as f64).sqrt().ceil() as usize,
+        bins => bins,
+    }
+    .clamp(1, 100);
+    let width = if max > min {
+        (max - min) / bins as f64
+    } else {
+        1.0
+    };
+
+    let grouped = Series::group(result, series_by, &result.columns[x].name, |cells| {
+        Some([cells[x].number?, 0.0])
+    });
+    let series = grouped
+        .into_iter()
+        .map(|s| {
+            let mut counts = vec![0usize; bins];
+            for [value, _] in s.points {
+                counts[(((value - min) / width) as usize).min(bins - 1)] += 1;
+            }
+            let points = counts
+                .into_iter()
+                .enumerate()
+                .map(|(bin, count)| [min + (bin as f64 + 0.5) * width, count as f64])
+                .collect();
+            Series {
+                name: s.name,
+                points,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    Ok(ChartData {
+        x_axis: match axis(result, x) {
+            Axis::Time => Axis::Time,
+            _ => Axis::Number,
+        },
+        bar_width: width / series.len().max(1) as f64,
+        series,
+    })
+}
+
+impl Series {
+    /// Splits the rows into series by the text of the `series_by` column, or puts them all into
+    /// one series called `name`. Rows `point` returns `None` for are left out.
+    fn group(
+        result: &StatementResult,
+        series_by: Option<usize>,
+        name: &str,
+        mut point: impl FnMut(&[proto_gen::query::Cell]) -> Option<[f64; 2]>,
+    ) -> Vec<Series> {
+        let mut series: Vec<Series> = vec![];
+        let mut by_name = HashMap::new();
+        for row in result.rows.iter() {
+            let Some(p) = point(&row.cells) else {
+                continue;
+            };
+            let name = match series_by {
+                Some(i) => row.cells[i].text.clone(),
+                None => name.to_string(),
+            };
+            let i = *by_name.entry(name.clone()).or_insert_with(|| {
+                series.push(Series {
+                    name,
+                    points: vec![],
+                });
+                series.len() - 1
+            });
+            series[i].points.push(p);
+        }
+        series
+    }
+}
+
+fn has_numbers(result: &StatementResult, column: usize) -> bool {
+    result.rows.iter().any(|r| r.cells[column].number.is_some())
+}
+
+fn axis(result: &StatementResult, column: usize) -> Axis {
+    let data_type = &result.columns[column].data_type;
+    if data_type.starts_with("Timestamp") || data_type.starts_with("Date") {
+        Axis::Time
+    } else if has_numbers(result, column) {
+        Axis::Number
+    } else {
+        Axis::Category(vec![])
+    }
+}
@@ -6,7 +6,7 @@ use futures_util::{
     FutureExt,
     SinkExt, stream::{SplitSink, SplitStream}, StreamExt,
 };
-use gloo_net::websocket::{futures::WebSocket, Message as WsMessage};
+use gloo_net::websocket::{futures::WebSocket, Message as WsMessage, WebSocketError};
 use gloo_timers::future::sleep;
 use log::{error, info};
 use tokio::sync::mpsc::{channel, Receiver, Sender};
@@ -15,6 +15,9 @@ use crate::RefCell;
 use crate::rpc::RpcCaller;
 use crate::status::VolatileStatus;
 
+/// Close code of a server refusing a connection that did not log in.
+const POLICY_VIOLATION: u16 = 1008;
+
 pub struct Client {
     url: String,
     tx: Option<RefCell<SplitSink<WebSocket, WsMessage>>>,
@@ -52,7 +55,11 @@ impl Client {
         self.tx.replace(RefCell::new(tx));
 
         // ws.close()
-        self.status.borrow_mut().connected = true;
+        {
+            let mut status = self.status.borrow_mut();
+            status.connected = true;
+            status.login_required = None;
+        }
         let _ = select! {
             r = self.handle(&mut rx).fuse() => r,
             r = self.wait_cancel().fuse() => r,
@@ -81,6 +88,11 @@ impl Client {
         while let Some(msg) = rx.next().await {
             let m = match msg {
                 Ok(m) => m,
+                Err(WebSocketError::ConnectionClose(e)) if e.code == POLICY_VIOLATION => {
+                    info!("server requires a login: {}", e.reason);
+                    self.status.borrow_mut().login_required = Some(e.reason);
+                    break;
+                }
                 Err(e) => {
                     error!("failed to receive message, err: {e}");
                     break;
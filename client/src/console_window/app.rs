@@ -1,8 +1,178 @@
+use std::collections::HashMap;
+use std::ops::Range;
 use std::rc::Rc;
 
+use egui::Visuals;
+use proto_gen::admin::QueryAudit;
+use proto_gen::auth::Login;
+use proto_gen::config::Config;
+use proto_gen::query::{
+    CandidateKind, PartitionColumn, RegisterTable, Severity, SortColumn, StatementLimits,
+};
+use proto_gen::saved::SavedQuery;
+
 use crate::{Ref, RefCell};
-use crate::rpc::HelloRpc;
+use crate::chart::ChartSettings;
+use crate::custom_widgets::code_editor::{Marker, Suggestion};
+use crate::file_io;
+use crate::history::{History, HistoryEntry};
+use crate::logs::LogView;
+use crate::rpc::{
+    AdminRpc, AuthRpc, ConfigRpc, EditorRpc, HelloRpc, HistoryRpc, LogsRpc, MonitorRpc, QueryRpc,
+    SavedRpc,
+};
+use crate::saved::{self, QueryDraft};
+use crate::sql;
 use crate::status::VolatileStatus;
+use crate::time::parse_date;
+
+/// What Monitor mode shows.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MonitorTab {
+    #[default]
+    Queries,
+    Logs,
+    Sessions,
+    Audit,
+}
+
+/// Credentials being typed into the login window.
+#[derive(Default)]
+pub struct LoginForm {
+    pub user: String,
+    pub password: String,
+    pub token: String,
+    /// log in with an API token instead of a password
+    pub use_token: bool,
+}
+
+impl LoginForm {
+    pub fn login(&self) -> Login {
+        if self.use_token {
+            Login {
+                token: self.token.trim().to_string(),
+                ..Default::default()
+            }
+        } else {
+            Login {
+                user: self.user.trim().to_string(),
+                password: self.password.clone(),
+                ..Default::default()
+            }
+        }
+    }
+}
+
+/// Caps of the statements this client runs, 0 for none.
+#[derive(Clone, Copy, Default, serde::Deserialize, serde::Serialize)]
+pub struct QueryLimits {
+    pub timeout_s: u64,
+    pub max_rows: u64,
+    pub max_mb: u64,
+}
+
+impl QueryLimits {
+    pub fn to_proto(self) -> StatementLimits {
+        StatementLimits {
+            timeout_ms: self.timeout_s * 1000,
+            max_rows: self.max_rows,
+            max_bytes: self.max_mb * 1024 * 1024,
+        }
+    }
+}
+
+/// Filters of the audit log search as typed, empty ones match every statement.
+#[derive(Default)]
+pub struct AuditFilter {
+    pub user: String,
+    pub context: String,
+    pub search: String,
+    pub outcome: String,
+    /// `YYYY-MM-DD`, both days included
+    pub since: String,
+    pub until: String,
+}
+
+impl AuditFilter {
+    pub fn query(&self) -> QueryAudit {
+        const DAY_MS: u64 = 86_400_000;
+        QueryAudit {
+            user: self.user.trim().to_string(),
+            context: self.context.trim().to_string(),
+            search: self.search.trim().to_string(),
+            outcome: self.outcome.clone(),
+            from_ms: parse_date(&self.since).unwrap_or_default(),
+            to_ms: parse_date(&self.until)
+                .map(|ms| ms + DAY_MS - 1)
+                .unwrap_or_default(),
+            ..Default::default()
+        }
+    }
+}
+
+/// A table over the files below a directory as typed, rows without a name are left out.
+pub struct TableForm {
+    pub name: String,
+    pub location: String,
+    pub file_type: String,
+    /// name and Arrow type, in the order of the directory levels
+    pub partition_columns: Vec<(String, String)>,
+    pub file_extension: String,
+    /// column and whether it is descending
+    pub sort_order: Vec<(String, bool)>,
+    pub has_header: bool,
+    pub replace: bool,
+}
+
+impl Default for TableForm {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            location: String::new(),
+            file_type: "parquet".to_string(),
+            partition_columns: vec![],
+            file_extension: String::new(),
+            sort_order: vec![],
+            has_header: true,
+            replace: false,
+        }
+    }
+}
+
+impl TableForm {
+    pub fn request(&self, context: Option<String>) -> RegisterTable {
+        RegisterTable {
+            context: context.unwrap_or_default(),
+            name: self.name.trim().to_string(),
+            location: self.location.trim().to_string(),
+            file_type: self.file_type.clone(),
+            partition_columns: self
+                .partition_columns
+                .iter()
+                .filter(|(name, _)| !name.trim().is_empty())
+                .map(|(name, data_type)| PartitionColumn {
+                    name: name.trim().to_string(),
+                    data_type: data_type.trim().to_string(),
+                })
+                .collect(),
+            file_extension: self.file_extension.trim().to_string(),
+            sort_order: self
+                .sort_order
+                .iter()
+                .filter(|(name, _)| !name.trim().is_empty())
+                .map(|(name, descending)| SortColumn {
+                    name: name.trim().to_string(),
+                    descending: *descending,
+                    // as ORDER BY does by default
+                    nulls_first: *descending,
+                })
+                .collect(),
+            has_header: self.has_header,
+            replace: self.replace,
+            ..Default::default()
+        }
+    }
+}
 
 #[derive(serde::Deserialize, serde::Serialize)]
 pub struct ConsoleApp {
@@ -13,6 +183,101 @@ pub struct ConsoleApp {
     volatile_status: Rc<RefCell<VolatileStatus>>,
     #[serde(skip, default = "default_hello_service")]
     hello_service: Rc<RefCell<HelloRpc>>,
+    #[serde(skip, default = "default_query_service")]
+    query_service: Rc<RefCell<QueryRpc>>,
+    #[serde(skip, default = "default_editor_service")]
+    editor_service: Rc<RefCell<EditorRpc>>,
+    #[serde(skip, default = "default_history_service")]
+    history_service: Rc<RefCell<HistoryRpc>>,
+    #[serde(skip, default = "default_saved_service")]
+    saved_service: Rc<RefCell<SavedRpc>>,
+    #[serde(skip, default = "default_monitor_service")]
+    monitor_service: Rc<RefCell<MonitorRpc>>,
+    #[serde(skip, default = "default_config_service")]
+    config_service: Rc<RefCell<ConfigRpc>>,
+    #[serde(skip, default = "default_logs_service")]
+    logs_service: Rc<RefCell<LogsRpc>>,
+    #[serde(skip, default = "default_admin_service")]
+    admin_service: Rc<RefCell<AdminRpc>>,
+    #[serde(skip, default = "default_auth_service")]
+    auth_service: Rc<RefCell<AuthRpc>>,
+    /// resumes the last login on the next start, until it expires
+    #[serde(default)]
+    session_token: Option<String>,
+    #[serde(skip)]
+    login_form: LoginForm,
+    // editor
+    #[serde(skip)]
+    sql_text: String,
+    #[serde(skip)]
+    script_mode: bool,
+    #[serde(skip)]
+    selected_result: usize,
+    /// time (`InputState::time`) the editor text gets validated at, set while typing
+    #[serde(skip)]
+    validate_at: Option<f64>,
+    // results
+    #[serde(skip)]
+    show_chart: bool,
+    /// keyed by the statement text, only those the user changed
+    #[serde(default)]
+    chart_settings: HashMap<String, ChartSettings>,
+    /// keys of `chart_settings`, least recently changed first
+    #[serde(default)]
+    chart_order: Vec<String>,
+    // history
+    #[serde(default)]
+    history: History,
+    #[serde(skip)]
+    history_search: String,
+    /// list the history the server recorded for the current context instead of the local one
+    #[serde(skip)]
+    server_history: bool,
+    // saved queries
+    /// the side panel lists saved queries instead of the history
+    #[serde(skip)]
+    show_saved: bool,
+    #[serde(skip)]
+    saved_search: String,
+    #[serde(skip)]
+    query_draft: Option<QueryDraft>,
+    /// content of the file picked for import, filled in asynchronously
+    #[serde(skip)]
+    imported_file: Rc<RefCell<Option<String>>>,
+    #[serde(skip)]
+    import_error: Option<String>,
+    // monitor
+    /// hide finished queries in the monitor
+    #[serde(skip)]
+    monitor_running_only: bool,
+    #[serde(skip)]
+    monitor_tab: MonitorTab,
+    /// time (`InputState::time`) the session list was last requested at
+    #[serde(skip)]
+    sessions_listed_at: Option<f64>,
+    #[serde(skip)]
+    audit_filter: AuditFilter,
+    #[serde(default)]
+    log_view: LogView,
+    /// server log filter being edited, filled with the one in effect
+    #[serde(skip)]
+    log_filter_edit: Option<String>,
+    // connection settings
+    #[serde(skip)]
+    show_settings: bool,
+    /// 0 for the server default
+    #[serde(default)]
+    monitor_interval_ms: u32,
+    /// DataFusion options for the statements of this client, as key and value
+    #[serde(default)]
+    session_options: Vec<(String, String)>,
+    #[serde(default)]
+    query_limits: QueryLimits,
+    // table registration
+    #[serde(skip)]
+    show_register_table: bool,
+    #[serde(skip)]
+    table_form: TableForm,
 }
 
 fn default_status() -> Rc<RefCell<VolatileStatus>> {
@@ -23,6 +288,42 @@ fn default_hello_service() -> Rc<RefCell<HelloRpc>> {
     Rc::new(RefCell::new(HelloRpc::default()))
 }
 
+fn default_query_service() -> Rc<RefCell<QueryRpc>> {
+    Rc::new(RefCell::new(QueryRpc::default()))
+}
+
+fn default_editor_service() -> Rc<RefCell<EditorRpc>> {
+    Rc::new(RefCell::new(EditorRpc::default()))
+}
+
+fn default_history_service() -> Rc<RefCell<HistoryRpc>> {
+    Rc::new(RefCell::new(HistoryRpc::default()))
+}
+
+fn default_saved_service() -> Rc<RefCell<SavedRpc>> {
+    Rc::new(RefCell::new(SavedRpc::default()))
+}
+
+fn default_monitor_service() -> Rc<RefCell<MonitorRpc>> {
+    Rc::new(RefCell::new(MonitorRpc::default()))
+}
+
+fn default_config_service() -> Rc<RefCell<ConfigRpc>> {
+    Rc::new(RefCell::new(ConfigRpc::default()))
+}
+
+fn default_logs_service() -> Rc<RefCell<LogsRpc>> {
+    Rc::new(RefCell::new(LogsRpc::default()))
+}
+
+fn default_admin_service() -> Rc<RefCell<AdminRpc>> {
+    Rc::new(RefCell::new(AdminRpc::default()))
+}
+
+fn default_auth_service() -> Rc<RefCell<AuthRpc>> {
+    Rc::new(RefCell::new(AuthRpc::default()))
+}
+
 impl Default for ConsoleApp {
     fn default() -> Self {
         Self {
@@ -30,6 +331,44 @@ impl Default for ConsoleApp {
             address: "".to_string(),
             volatile_status: default_status(),
             hello_service: default_hello_service(),
+            query_service: default_query_service(),
+            editor_service: default_editor_service(),
+            history_service: default_history_service(),
+            saved_service: default_saved_service(),
+            monitor_service: default_monitor_service(),
+            config_service: default_config_service(),
+            logs_service: default_logs_service(),
+            admin_service: default_admin_service(),
+            auth_service: default_auth_service(),
+            session_token: None,
+            login_form: Default::default(),
+            sql_text: "".to_string(),
+            script_mode: false,
+            selected_result: 0,
+            validate_at: None,
+            show_chart: false,
+            chart_settings: HashMap::new(),
+            chart_order: vec![],
+            history: History::default(),
+            history_search: "".to_string(),
+            server_history: false,
+            show_saved: false,
+            saved_search: "".to_string(),
+            query_draft: None,
+            imported_file: Default::default(),
+            import_error: None,
+            monitor_running_only: false,
+            monitor_tab: MonitorTab::Queries,
+            sessions_listed_at: None,
+            audit_filter: Default::default(),
+            log_view: Default::default(),
+            log_filter_edit: None,
+            show_settings: false,
+            monitor_interval_ms: 0,
+            session_options: vec![],
+            query_limits: Default::default(),
+            show_register_table: false,
+            table_form: Default::default(),
         }
     }
 }
@@ -42,6 +381,544 @@ impl ConsoleApp {
     pub fn clone_hello_service_rc(&self) -> Rc<RefCell<HelloRpc>> {
         self.hello_service.clone()
     }
+
+    pub fn get_query_service(&self) -> Ref<QueryRpc> {
+        self.query_service.borrow()
+    }
+
+    pub fn clone_query_service_rc(&self) -> Rc<RefCell<QueryRpc>> {
+        self.query_service.clone()
+    }
+
+    pub fn get_editor_service(&self) -> Ref<EditorRpc> {
+        self.editor_service.borrow()
+    }
+
+    pub fn clone_editor_service_rc(&self) -> Rc<RefCell<EditorRpc>> {
+        self.editor_service.clone()
+    }
+
+    pub fn get_history_service(&self) -> Ref<HistoryRpc> {
+        self.history_service.borrow()
+    }
+
+    pub fn clone_history_service_rc(&self) -> Rc<RefCell<HistoryRpc>> {
+        self.history_service.clone()
+    }
+
+    pub fn get_saved_service(&self) -> Ref<SavedRpc> {
+        self.saved_service.borrow()
+    }
+
+    pub fn clone_saved_service_rc(&self) -> Rc<RefCell<SavedRpc>> {
+        self.saved_service.clone()
+    }
+
+    pub fn get_monitor_service(&self) -> Ref<MonitorRpc> {
+        self.monitor_service.borrow()
+    }
+
+    pub fn clone_monitor_service_rc(&self) -> Rc<RefCell<MonitorRpc>> {
+        self.monitor_service.clone()
+    }
+
+    pub fn get_config_service(&self) -> Ref<ConfigRpc> {
+        self.config_service.borrow()
+    }
+
+    pub fn clone_config_service_rc(&self) -> Rc<RefCell<ConfigRpc>> {
+        self.config_service.clone()
+    }
+
+    pub fn get_logs_service(&self) -> Ref<LogsRpc> {
+        self.logs_service.borrow()
+    }
+
+    pub fn clone_logs_service_rc(&self) -> Rc<RefCell<LogsRpc>> {
+        self.logs_service.clone()
+    }
+
+    pub fn get_admin_service(&self) -> Ref<AdminRpc> {
+        self.admin_service.borrow()
+    }
+
+    pub fn clone_admin_service_rc(&self) -> Rc<RefCell<AdminRpc>> {
+        self.admin_service.clone()
+    }
+
+    pub fn get_auth_service(&self) -> Ref<AuthRpc> {
+        self.auth_service.borrow()
+    }
+
+    pub fn clone_auth_service_rc(&self) -> Rc<RefCell<AuthRpc>> {
+        self.auth_service.clone()
+    }
+}
+
+impl ConsoleApp {
+    pub fn get_sql_text_mut(&mut self) -> &mut String {
+        &mut self.sql_text
+    }
+
+    pub fn get_script_mode_mut(&mut self) -> &mut bool {
+        &mut self.script_mode
+    }
+
+    pub fn get_selected_result_mut(&mut self) -> &mut usize {
+        &mut self.selected_result
+    }
+
+    pub fn get_show_chart_mut(&mut self) -> &mut bool {
+        &mut self.show_chart
+    }
+
+    pub fn clear_history(&mut self) {
+        self.history.clear();
+    }
+
+    /// Local history entries matching the search text, newest first.
+    pub fn search_history(&self) -> impl Iterator<Item = &HistoryEntry> {
+        self.history.search(&self.history_search)
+    }
+
+    pub fn get_history_search_mut(&mut self) -> &mut String {
+        &mut self.history_search
+    }
+
+    pub fn get_server_history_mut(&mut self) -> &mut bool {
+        &mut self.server_history
+    }
+
+    pub fn get_show_saved_mut(&mut self) -> &mut bool {
+        &mut self.show_saved
+    }
+
+    pub fn get_saved_search_mut(&mut self) -> &mut String {
+        &mut self.saved_search
+    }
+
+    pub fn get_query_draft_mut(&mut self) -> &mut Option<QueryDraft> {
+        &mut self.query_draft
+    }
+
+    pub fn get_import_error(&self) -> Option<&str> {
+        self.import_error.as_deref()
+    }
+
+    pub fn get_monitor_running_only_mut(&mut self) -> &mut bool {
+        &mut self.monitor_running_only
+    }
+
+    pub fn get_monitor_tab_mut(&mut self) -> &mut MonitorTab {
+        &mut self.monitor_tab
+    }
+
+    pub fn get_sessions_listed_at_mut(&mut self) -> &mut Option<f64> {
+        &mut self.sessions_listed_at
+    }
+
+    pub fn get_audit_filter_mut(&mut self) -> &mut AuditFilter {
+        &mut self.audit_filter
+    }
+
+    /// Searches the audit log of the server with the filters as typed.
+    pub fn search_audit(&self) {
+        let query = self.audit_filter.query();
+        self.admin_service.borrow_mut().query_audit(query);
+    }
+
+    pub fn get_log_view_mut(&mut self) -> &mut LogView {
+        &mut self.log_view
+    }
+
+    pub fn get_log_filter_edit_mut(&mut self) -> &mut Option<String> {
+        &mut self.log_filter_edit
+    }
+
+    /// Follows the server logs with the filter of the log view while it is shown.
+    pub fn sync_log_subscription(&self, shown: bool) {
+        let wanted = shown.then(|| self.log_view.filter());
+        let mut logs = self.logs_service.borrow_mut();
+        if wanted.as_ref() == logs.subscribed_filter() {
+            return;
+        }
+        match wanted {
+            Some(filter) => logs.subscribe(filter),
+            None => logs.unsubscribe(),
+        }
+    }
+
+    pub fn get_login_form_mut(&mut self) -> &mut LoginForm {
+        &mut self.login_form
+    }
+
+    /// Logs in with the credentials of the login window on the next connection.
+    pub fn submit_login(&mut self) {
+        let login = self.login_form.login();
+        self.login_form.password.clear();
+        self.auth_service.borrow_mut().set_credentials(login);
+    }
+
+    /// Keeps the session token the server issued last, to log in with it after a restart.
+    pub fn sync_session_token(&mut self) {
+        if let Some(token) = self.auth_service.borrow_mut().take_token_change() {
+            self.session_token = token;
+        }
+    }
+
+    /// Logs in with the session token of an earlier start on the next connection.
+    pub fn restore_session(&self) {
+        if let Some(token) = self.session_token.as_ref() {
+            self.auth_service.borrow_mut().restore_token(token);
+        }
+    }
+
+    /// Forgets the login and closes the connection.
+    pub fn log_out(&mut self) {
+        self.auth_service.borrow_mut().log_out();
+        self.session_token = None;
+        self.get_status().close_notify.notify_one();
+    }
+
+    pub fn get_show_settings_mut(&mut self) -> &mut bool {
+        &mut self.show_settings
+    }
+
+    pub fn get_monitor_interval_ms_mut(&mut self) -> &mut u32 {
+        &mut self.monitor_interval_ms
+    }
+
+    pub fn get_session_options_mut(&mut self) -> &mut Vec<(String, String)> {
+        &mut self.session_options
+    }
+
+    pub fn get_query_limits_mut(&mut self) -> &mut QueryLimits {
+        &mut self.query_limits
+    }
+
+    pub fn get_show_register_table_mut(&mut self) -> &mut bool {
+        &mut self.show_register_table
+    }
+
+    pub fn get_table_form_mut(&mut self) -> &mut TableForm {
+        &mut self.table_form
+    }
+
+    /// Registers the table as typed in the current context.
+    pub fn register_table(&self) {
+        let context = self.get_status().current_context_name.borrow().clone();
+        let req = self.table_form.request(context);
+        self.query_service.borrow_mut().register_table(req);
+    }
+
+    /// The connection settings as edited, options without a key are left out.
+    pub fn client_config(&self) -> Config {
+        Config {
+            pause_monitor: *self.get_status().pause_server_yields.borrow(),
+            monitor_interval_ms: self.monitor_interval_ms,
+            options: self
+                .session_options
+                .iter()
+                .filter(|(key, _)| !key.trim().is_empty())
+                .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+                .collect(),
+        }
+    }
+
+    pub fn apply_config(&self) {
+        let config = self.client_config();
+        self.config_service.borrow_mut().set_config(config);
+    }
+
+    /// Sends the settings once per connection, the server starts every connection with defaults.
+    pub fn sync_config(&self) {
+        let config_service = self.config_service.borrow();
+        let unsent = config_service.applied().is_none()
+            && !config_service.is_waiting()
+            && config_service.error().is_none();
+        drop(config_service);
+        if self.get_status().connected && unsent {
+            self.apply_config();
+        }
+    }
+
+    /// Keeps the monitor feed subscribed exactly while the monitor is shown.
+    pub fn sync_monitor_subscription(&self, shown: bool) {
+        let mut monitor = self.monitor_service.borrow_mut();
+        if shown && !monitor.is_subscribed() {
+            monitor.subscribe();
+        } else if !shown && monitor.is_subscribed() {
+            monitor.unsubscribe();
+        }
+    }
+
+    /// Chart settings of the statement `sql`, the defaults unless changed.
+    pub fn chart_settings(&self, sql: &str) -> ChartSettings {
+        self.chart_settings
+            .get(&chart_key(sql))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Keeps the settings of the statement `sql`, forgetting the least recently changed ones
+    /// beyond `MAX_CHART_SETTINGS`.
+    pub fn set_chart_settings(&mut self, sql: &str, settings: ChartSettings) {
+        let key = chart_key(sql);
+        self.chart_order.retain(|k| *k != key);
+        self.chart_order.push(key.clone());
+        self.chart_settings.insert(key, settings);
+        while self.chart_order.len() > MAX_CHART_SETTINGS {
+            let oldest = self.chart_order.remove(0);
+            self.chart_settings.remove(&oldest);
+        }
+    }
+
+    /// Runs the editor content: every statement in script mode, otherwise the statements
+    /// inside a non-empty `selection` (char range), or the statement under the cursor.
+    pub fn run_sql(&mut self, selection: Option<Range<usize>>) {
+        let text = &self.sql_text;
+        let (base, ranges) = match selection {
+            _ if self.script_mode => (0, sql::split_statements(text)),
+            Some(s) if !s.is_empty() => {
+                let start = sql::char_to_byte(text, s.start);
+                let end = sql::char_to_byte(text, s.end);
+                (start, sql::split_statements(&text[start..end]))
+            }
+            s => {
+                let cursor = s.map(|s| sql::char_to_byte(text, s.start)).unwrap_or(0);
+                (0, sql::statement_at(text, cursor).into_iter().collect())
+            }
+        };
+        let statements = ranges
+            .into_iter()
+            .map(|r| text[base + r.start..base + r.end].to_string())
+            .collect();
+
+        let context = self.get_status().current_context_name.borrow().clone();
+        self.selected_result = 0;
+        self.query_service
+            .borrow_mut()
+            .execute(context, statements, self.query_limits.to_proto());
+    }
+
+    /// Moves the statements that finished since the last frame into the history.
+    pub fn record_finished_statements(&mut self) {
+        for entry in self.query_service.borrow_mut().take_finished() {
+            self.history.record(entry);
+        }
+    }
+
+    /// Lists the contexts the server kept from earlier runs along with the ones created here.
+    pub fn sync_catalog_contexts(&mut self) {
+        let contexts = self.query_service.borrow_mut().take_new_contexts();
+        self.get_status()
+            .context_names
+            .borrow_mut()
+            .extend(contexts);
+    }
+
+    /// Replaces the editor content with the statement of `entry` and runs it in its context.
+    pub fn rerun(&mut self, entry: &HistoryEntry) {
+        self.sql_text = entry.sql.clone();
+        self.selected_result = 0;
+        self.query_service.borrow_mut().execute(
+            entry.context.clone(),
+            vec![entry.sql.clone()],
+            self.query_limits.to_proto(),
+        );
+    }
+
+    /// Fetches the server recorded history of the current context matching the search text.
+    pub fn fetch_server_history(&self) {
+        const LIMIT: u32 = 200;
+        let context = self.get_status().current_context_name.borrow().clone();
+        self.history_service
+            .borrow_mut()
+            .fetch(context, self.history_search.clone(), LIMIT);
+    }
+
+    /// Puts the query into the editor, selects its context and restores its chart settings.
+    pub fn load_saved(&mut self, query: &SavedQuery) {
+        self.sql_text = query.sql.clone();
+        let context = (!query.context.is_empty()).then(|| query.context.clone());
+        self.set_current_context(context);
+        if let Some(chart) = query.chart.clone() {
+            for range in sql::split_statements(&query.sql) {
+                self.set_chart_settings(&query.sql[range], chart.clone().into());
+            }
+        }
+    }
+
+    /// Loads the query and runs all of its statements.
+    pub fn run_saved(&mut self, query: &SavedQuery) {
+        self.load_saved(query);
+        let statements = sql::split_statements(&query.sql)
+            .into_iter()
+            .map(|r| query.sql[r].to_string())
+            .collect();
+        let context = self.get_status().current_context_name.borrow().clone();
+        self.selected_result = 0;
+        self.query_service
+            .borrow_mut()
+            .execute(context, statements, self.query_limits.to_proto());
+    }
+
+    /// Starts editing a new saved query holding the editor content.
+    pub fn draft_from_editor(&mut self) {
+        let context = self.get_status().current_context_name.borrow().clone();
+        self.query_draft = Some(QueryDraft {
+            sql: self.sql_text.clone(),
+            context: context.unwrap_or_default(),
+            ..Default::default()
+        });
+    }
+
+    /// Saves the draft along with the chart settings of its first charted statement.
+    pub fn save_draft(&mut self) {
+        let Some(draft) = self.query_draft.take() else {
+            return;
+        };
+        let chart = sql::split_statements(&draft.sql)
+            .into_iter()
+            .find_map(|r| self.chart_settings.get(&chart_key(&draft.sql[r])))
+            .cloned();
+        self.saved_service
+            .borrow_mut()
+            .save(draft.into_query(chart));
+    }
+
+    /// Saved queries matching the search text.
+    pub fn search_saved(&self) -> Vec<SavedQuery> {
+        self.saved_service
+            .borrow()
+            .queries()
+            .iter()
+            .filter(|q| saved::matches(q, &self.saved_search))
+            .cloned()
+            .collect()
+    }
+
+    pub fn export_saved(&mut self) {
+        match saved::export(self.saved_service.borrow().queries()) {
+            Ok(json) => file_io::download(saved::EXPORT_FILE_NAME, "application/json", &json),
+            Err(e) => self.import_error = Some(e),
+        }
+    }
+
+    /// Lets the user pick an export file, it is imported by `import_picked_file` once read.
+    pub fn pick_import_file(&self, ctx: &egui::Context) {
+        let imported_file = self.imported_file.clone();
+        let ctx = ctx.clone();
+        file_io::open(".json,application/json", move |text| {
+            imported_file.borrow_mut().replace(text);
+            ctx.request_repaint();
+        });
+    }
+
+    pub fn import_picked_file(&mut self) {
+        let Some(text) = self.imported_file.borrow_mut().take() else {
+            return;
+        };
+        match saved::import(&text) {
+            Ok(queries) => {
+                self.import_error = None;
+                self.saved_service.borrow_mut().import(queries);
+            }
+            Err(e) => self.import_error = Some(e),
+        }
+    }
+
+    fn set_current_context(&self, context: Option<String>) {
+        let status = self.get_status();
+        if let Some(name) = context.as_ref() {
+            status.context_names.borrow_mut().insert(name.clone());
+        }
+        *status.edit_ctx_name.borrow_mut() = context.clone().unwrap_or_default();
+        *status.current_context_name.borrow_mut() = context;
+    }
+
+    /// Requests completions for the cursor at the end of `selection` (char range).
+    pub fn request_completion(&self, selection: Option<Range<usize>>) {
+        let Some(cursor) = selection.map(|s| sql::char_to_byte(&self.sql_text, s.end)) else {
+            return;
+        };
+        let context = self.get_status().current_context_name.borrow().clone();
+        self.editor_service
+            .borrow_mut()
+            .complete(context, self.sql_text.clone(), cursor);
+    }
+
+    /// Completion candidates as editor suggestions, and whether they just arrived.
+    pub fn take_suggestions(&self) -> (Vec<Suggestion>, bool) {
+        let mut editor_service = self.editor_service.borrow_mut();
+        let suggestions = editor_service
+            .candidates()
+            .iter()
+            .map(|c| Suggestion {
+                text: c.text.clone(),
+                hint: match c.kind() {
+                    CandidateKind::Keyword => "keyword".to_string(),
+                    CandidateKind::Function => format!("{} function", c.detail),
+                    CandidateKind::Table => format!("table in {}", c.detail),
+                    CandidateKind::Column => c.detail.clone(),
+                    CandidateKind::Unspecified => c.detail.clone(),
+                },
+            })
+            .collect();
+        (suggestions, editor_service.take_fresh_candidates())
+    }
+
+    /// Drops the diagnostics of the old text and validates the new one once typing paused.
+    pub fn schedule_validation(&mut self, now: f64) {
+        const DEBOUNCE_SECS: f64 = 0.5;
+        self.editor_service.borrow_mut().clear_diagnostics();
+        self.validate_at = Some(now + DEBOUNCE_SECS);
+    }
+
+    /// Sends the scheduled validation, returns the seconds left until it is due otherwise.
+    pub fn validate_if_due(&mut self, now: f64) -> Option<f64> {
+        let due = self.validate_at?;
+        if now < due {
+            return Some(due - now);
+        }
+        self.validate_at = None;
+        let context = self.get_status().current_context_name.borrow().clone();
+        self.editor_service
+            .borrow_mut()
+            .validate(context, self.sql_text.clone());
+        None
+    }
+
+    /// Diagnostics of the editor text as underlines.
+    pub fn markers(&self, visuals: &Visuals) -> Vec<Marker> {
+        self.editor_service
+            .borrow()
+            .diagnostics()
+            .iter()
+            .map(|d| {
+                let span = d.span.clone().unwrap_or_default();
+                let start = sql::line_column_to_char(
+                    &self.sql_text,
+                    span.start_line as usize,
+                    span.start_column as usize,
+                );
+                let end = sql::line_column_to_char(
+                    &self.sql_text,
+                    span.end_line as usize,
+                    span.end_column as usize,
+                );
+                let color = match d.severity() {
+                    Severity::Warning => visuals.warn_fg_color,
+                    Severity::Error | Severity::Unspecified => visuals.error_fg_color,
+                };
+                Marker {
+                    chars: start..end,
+                    message: d.message.clone(),
+                    color,
+                }
+            })
+            .collect()
+    }
 }
 
 impl ConsoleApp {
@@ -57,6 +934,19 @@ impl ConsoleApp {
         Self {
             label: self.label.clone(),
             address: self.address.clone(),
+            // settings stored before only changed ones were have no order, they are dropped
+            chart_settings: self
+                .chart_order
+                .iter()
+                .filter_map(|k| Some((k.clone(), self.chart_settings.get(k)?.clone())))
+                .collect(),
+            chart_order: self.chart_order.clone(),
+            history: self.history.clone(),
+            monitor_interval_ms: self.monitor_interval_ms,
+            session_options: self.session_options.clone(),
+            query_limits: self.query_limits,
+            log_view: self.log_view.clone(),
+            session_token: self.session_token.clone(),
             ..Default::default()
         }
     }
@@ -85,3 +975,25 @@ impl ConsoleApp {
         &mut self.address
     }
 }
+
+/// Statements whose chart settings are kept at most.
+const MAX_CHART_SETTINGS: usize = 200;
+
+/// Statements differing only in whitespace share their chart settings.
+fn chart_key(sql: &str) -> String {
+    sql.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chart_key_ignores_whitespace_only() {
+        let key = chart_key("SELECT a, b\nFROM t\n  WHERE a > 1;");
+        assert_eq!(key, "SELECT a, b FROM t WHERE a > 1;");
+        assert_eq!(chart_key("\tSELECT  a,b FROM t "), "SELECT a,b FROM t");
+        assert_ne!(chart_key("SELECT a FROM t"), chart_key("select a from t"));
+        assert_eq!(chart_key(" \n "), "");
+    }
+}
@@ -32,6 +32,16 @@ pub async fn set_current_host(url: String) {
     error!("failed to set current url as {url}");
 }
 
+/// `wss` when the page was served over https, browsers refuse plain websockets there.
+pub fn websocket_scheme() -> &'static str {
+    let protocol = web_sys::window().and_then(|w| w.location().protocol().ok());
+    if protocol.as_deref() == Some("https:") {
+        "wss"
+    } else {
+        "ws"
+    }
+}
+
 pub fn get_current_host() -> String {
     for _ in 0..10 {
         if let Ok(r) = CURRENT_HOST.try_read() {
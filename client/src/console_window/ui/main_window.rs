@@ -1,12 +1,41 @@
+use std::time::Duration;
+
 use eframe::{Frame, Storage};
 use egui::Context;
 
-use crate::console_window::app::ConsoleApp;
+use crate::console_window::app::{ConsoleApp, MonitorTab};
 use crate::status::Mode;
 
 impl eframe::App for ConsoleApp {
     fn update(&mut self, ctx: &Context, frame: &mut Frame) {
+        // results arrive outside of egui's input events, keep polling while a query runs
+        if self.get_query_service().is_running()
+            || self.get_query_service().is_registering()
+            || self.get_editor_service().is_waiting()
+            || self.get_history_service().is_loading()
+            || self.get_saved_service().is_loading()
+            || self.get_monitor_service().is_subscribed()
+            || self.get_config_service().is_waiting()
+            || self.get_logs_service().is_subscribed()
+            || self.get_admin_service().is_waiting()
+            || self.get_auth_service().is_waiting()
+        {
+            ctx.request_repaint_after(Duration::from_millis(100));
+        }
+        self.record_finished_statements();
+        self.sync_catalog_contexts();
+        self.import_picked_file();
+        self.sync_session_token();
         self.draw_top_menu_in_ctx(ctx);
+        self.draw_login_in_ctx(ctx);
+        self.sync_config();
+        self.draw_settings_in_ctx(ctx);
+        self.draw_register_table_in_ctx(ctx);
+        let monitor_shown =
+            self.get_status().connected && *self.get_status().mode.borrow() == Mode::Monitor;
+        let tab = *self.get_monitor_tab_mut();
+        self.sync_monitor_subscription(monitor_shown && tab == MonitorTab::Queries);
+        self.sync_log_subscription(monitor_shown && tab == MonitorTab::Logs);
         egui::CentralPanel::default().show(ctx, |ui| {
             let connected = self.get_status().connected;
             let mode = self.get_status().mode.borrow().clone();
@@ -16,7 +45,7 @@ impl eframe::App for ConsoleApp {
                     self.draw_console_side_panel_in_ui(ui);
                     self.draw_console_center_panel_in_ui(ui);
                 }
-                Mode::Monitor => {}
+                Mode::Monitor => self.draw_monitor_in_ui(ui),
             });
         });
     }
@@ -1,6 +1,19 @@
-use super::{Client, ConsoleApp, get_current_host, Mode};
+use super::{Client, ConsoleApp, get_current_host, Mode, websocket_scheme};
+use super::app::MonitorTab;
 
 mod main_window;
+mod panel_audit;
 mod panel_center;
+mod panel_history;
+mod panel_login;
+mod panel_logs;
+mod panel_monitor;
+mod panel_register;
+mod panel_resources;
+mod panel_saved;
+mod panel_sessions;
+mod panel_settings;
 mod panel_side;
 mod panel_top;
+mod result_chart;
+mod result_grid;
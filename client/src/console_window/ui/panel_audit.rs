@@ -0,0 +1,143 @@
+use egui::{Color32, TextEdit, Ui};
+use egui_extras::{Column, TableBuilder};
+use proto_gen::admin::AuditEntries;
+
+use crate::time::format_time;
+
+use super::ConsoleApp;
+
+const OUTCOMES: [&str; 4] = ["succeeded", "partial", "failed", "killed"];
+
+impl ConsoleApp {
+    /// Statements executed by every client as recorded by the server, admins only.
+    pub fn draw_audit_in_ui(&mut self, ui: &mut Ui) {
+        let admin_service = self.clone_admin_service_rc();
+        let searching = admin_service.borrow().is_searching_audit();
+        let mut search = !searching && admin_service.borrow().audit().is_none();
+
+        ui.horizontal_wrapped(|ui| {
+            let filter = self.get_audit_filter_mut();
+            for (text, hint, width) in [
+                (&mut filter.user, "user", 100.0),
+                (&mut filter.context, "context", 100.0),
+                (&mut filter.search, "sql", 200.0),
+                (&mut filter.since, "since YYYY-MM-DD", 120.0),
+                (&mut filter.until, "until YYYY-MM-DD", 120.0),
+            ] {
+                let edit = ui.add(
+                    TextEdit::singleline(text)
+                        .hint_text(hint)
+                        .desired_width(width),
+                );
+                search |= edit.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+            }
+            let selected = match filter.outcome.as_str() {
+                "" => "any outcome",
+                outcome => outcome,
+            };
+            egui::ComboBox::from_id_source("audit_outcome")
+                .selected_text(selected)
+                .show_ui(ui, |ui| {
+                    search |= ui
+                        .selectable_value(&mut filter.outcome, String::new(), "any outcome")
+                        .clicked();
+                    for outcome in OUTCOMES {
+                        search |= ui
+                            .selectable_value(&mut filter.outcome, outcome.to_string(), outcome)
+                            .clicked();
+                    }
+                });
+            search |= ui
+                .add_enabled(!searching, egui::Button::new("Search"))
+                .clicked();
+            if searching {
+                ui.spinner();
+            }
+        });
+        ui.separator();
+        if search && !searching {
+            self.search_audit();
+        }
+
+        let admin = admin_service.borrow();
+        let Some(entries) = admin.audit() else {
+            return;
+        };
+        if !entries.error.is_empty() {
+            ui.colored_label(Color32::LIGHT_RED, &entries.error);
+            return;
+        }
+        ui.label(format!(
+            "{} statements, newest first",
+            entries.entries.len()
+        ));
+        draw_entries(ui, entries);
+    }
+}
+
+fn outcome_color(outcome: &str) -> Color32 {
+    match outcome {
+        "succeeded" => Color32::LIGHT_GREEN,
+        "partial" => Color32::GOLD,
+        _ => Color32::LIGHT_RED,
+    }
+}
+
+fn draw_entries(ui: &mut Ui, entries: &AuditEntries) {
+    TableBuilder::new(ui)
+        .striped(true)
+        .resizable(true)
+        .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+        .column(Column::auto().at_least(140.0))
+        .columns(Column::auto().at_least(80.0).clip(true), 3)
+        .column(Column::auto().at_least(70.0))
+        .columns(Column::auto().at_least(60.0), 2)
+        .column(Column::auto().at_least(90.0))
+        .column(Column::remainder().at_least(200.0).clip(true))
+        .header(20.0, |mut header| {
+            for name in [
+                "Time", "User", "Client", "Context", "Outcome", "Rows", "Duration", "Plan", "SQL",
+            ] {
+                header.col(|ui| {
+                    ui.strong(name);
+                });
+            }
+        })
+        .body(|body| {
+            body.rows(18.0, entries.entries.len(), |mut row| {
+                let entry = &entries.entries[row.index()];
+                row.col(|ui| {
+                    ui.label(format_time(entry.timestamp_ms as f64));
+                });
+                row.col(|ui| {
+                    ui.label(&entry.user);
+                });
+                row.col(|ui| {
+                    ui.label(&entry.peer_addr);
+                });
+                row.col(|ui| {
+                    ui.label(&entry.context);
+                });
+                row.col(|ui| {
+                    let outcome = ui.colored_label(outcome_color(&entry.outcome), &entry.outcome);
+                    if !entry.error.is_empty() {
+                        outcome.on_hover_text(&entry.error);
+                    }
+                });
+                row.col(|ui| {
+                    ui.label(entry.rows.to_string());
+                });
+                row.col(|ui| {
+                    ui.label(format!("{} ms", entry.duration_ms));
+                });
+                row.col(|ui| {
+                    let short = entry.plan_hash.get(..12).unwrap_or(&entry.plan_hash);
+                    ui.monospace(short).on_hover_text(&entry.plan_hash);
+                });
+                row.col(|ui| {
+                    ui.monospace(entry.sql.replace('\n', " "))
+                        .on_hover_text(&entry.sql);
+                });
+            });
+        });
+}
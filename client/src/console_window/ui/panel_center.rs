@@ -1,7 +1,12 @@
+use std::time::Duration;
+
 use egui::{Button, Color32, Ui};
 use egui_extras::{Size, StripBuilder};
 use log::info;
 
+use crate::custom_widgets::code_editor::CodeEditor;
+use crate::custom_widgets::toggle_ui::toggle_ui;
+
 use super::ConsoleApp;
 
 impl ConsoleApp {
@@ -23,25 +28,34 @@ impl ConsoleApp {
                         rows_builder
                             // .size(Size::relative(0.9).at_least(480.0).at_most(640.0))
                             // .size(Size::relative(0.1).at_least(12.0).at_most(24.0))
-                            .size(Size::relative(0.9).at_least(540.0))
-                            .size(Size::relative(0.1).at_most(30.0))
+                            .size(Size::remainder().at_least(240.0))
+                            .size(Size::relative(0.3).at_least(120.0))
+                            .size(Size::exact(30.0))
                             .vertical(|mut strip| {
                                 strip.cell(|ui| {
                                     self.draw_content_scroll_area(ui);
-                                    // ui.painter().rect_stroke(
-                                    //     ui.available_rect_before_wrap(),
-                                    //     0.0,
-                                    //     Stroke::new(1.0, Color32::LIGHT_BLUE),
-                                    // );
-                                    // self.draw_content_scroll_area(ui);
-                                    // egui::ScrollArea::vertical()
-                                    //     .enable_scrolling(true)
-                                    //     .hscroll(true)
-                                    //     .stick_to_bottom(true)
-                                    //     .show(ui, |ui| {
-                                    //         ui.label(RichText::new("hello").color(Color32::RED));
-                                    //         ui.label(RichText::new("world").color(Color32::BLUE));
-                                    //     });
+                                });
+                                let mut selection = None;
+                                let mut run = false;
+                                strip.cell(|ui| {
+                                    let (suggestions, open_suggestions) = self.take_suggestions();
+                                    let markers = self.markers(ui.visuals());
+                                    let output = CodeEditor::new(self.get_sql_text_mut(), "sql_editor")
+                                        .suggestions(&suggestions, open_suggestions)
+                                        .markers(&markers)
+                                        .show(ui);
+                                    if output.complete_requested {
+                                        self.request_completion(output.selection.clone());
+                                    }
+                                    let now = ui.input(|i| i.time);
+                                    if output.response.changed() {
+                                        self.schedule_validation(now);
+                                    }
+                                    if let Some(left) = self.validate_if_due(now) {
+                                        ui.ctx().request_repaint_after(Duration::from_secs_f64(left));
+                                    }
+                                    selection = output.selection;
+                                    run = output.run_requested;
                                 });
                                 strip.strip(|strip_h| {
                                     strip_h
@@ -49,26 +63,43 @@ impl ConsoleApp {
                                         .size(Size::relative(0.1))
                                         .size(Size::relative(0.1))
                                         .horizontal(|mut strip| {
-                                            strip.cell(|_| {});
                                             strip.cell(|ui| {
-                                                let size = ui.available_rect_before_wrap().size();
-
+                                                ui.horizontal_centered(|ui| {
+                                                    toggle_ui(ui, self.get_script_mode_mut())
+                                                        .on_hover_text(
+                                                            "run every statement of the editor in order",
+                                                        );
+                                                    ui.label("Script mode");
+                                                });
+                                            });
+                                            strip.cell(|ui| {
+                                                let running = self.get_query_service().is_running();
+                                                let size = ui.available_size_before_wrap();
                                                 if ui
-                                                    .add_sized(
-                                                        ui.available_size_before_wrap(),
-                                                        Button::new("hello").rounding(5.0),
-                                                    )
+                                                    .add_enabled_ui(!running, |ui| {
+                                                        ui.add_sized(
+                                                            size,
+                                                            Button::new("Run").rounding(5.0),
+                                                        )
+                                                    })
+                                                    .inner
+                                                    .on_hover_text("Ctrl+Enter")
                                                     .clicked()
                                                 {
-                                                    self.get_hello_service().say_hello();
-                                                    info!("saying hello");
+                                                    run = true;
                                                 };
                                             });
                                             strip.cell(|ui| {
-                                                ui.label("right");
+                                                if self.get_query_service().is_running() {
+                                                    ui.spinner();
+                                                }
                                             });
                                         });
                                 });
+                                if run && !self.get_query_service().is_running() {
+                                    info!("running sql, selection: {selection:?}");
+                                    self.run_sql(selection);
+                                }
                             });
                     });
                     strip_v.cell(|ui| {
@@ -88,11 +119,10 @@ impl ConsoleApp {
         } else {
             Color32::GRAY
         };
-        egui::ScrollArea::vertical().show(ui, |ui| {
-            ui.painter()
-                .rect_filled(ui.available_rect_before_wrap(), 0.0, area_color);
-            // ui.label(RichText::new("hello").color(Color32::RED));
-            // ui.label(RichText::new("world").color(Color32::BLUE));
-        });
+        ui.painter()
+            .rect_filled(ui.available_rect_before_wrap(), 0.0, area_color);
+        self.draw_result_grid_in_ui(ui);
+        // ui.label(RichText::new("hello").color(Color32::RED));
+        // ui.label(RichText::new("world").color(Color32::BLUE));
     }
 }
@@ -0,0 +1,130 @@
+use egui::{Color32, Label, RichText, Sense, TextEdit, Ui};
+
+use crate::history::HistoryEntry;
+use crate::time::format_time;
+
+use super::ConsoleApp;
+
+enum HistoryAction {
+    Load(HistoryEntry),
+    Rerun(HistoryEntry),
+}
+
+impl ConsoleApp {
+    pub fn draw_history_in_ui(&mut self, ui: &mut Ui) {
+        let (mut refresh, mut clear) = (false, false);
+        ui.horizontal(|ui| {
+            let server_history = self.get_server_history_mut();
+            refresh |= ui
+                .selectable_value(server_history, false, "Local")
+                .clicked();
+            refresh |= ui
+                .selectable_value(server_history, true, "Server")
+                .on_hover_text("statements run in the current context by any client")
+                .clicked();
+            if *server_history {
+                refresh |= ui.small_button("⟳").on_hover_text("refresh").clicked();
+            } else {
+                clear = ui
+                    .small_button("🗑")
+                    .on_hover_text("clear the local history")
+                    .clicked();
+            }
+        });
+        if clear {
+            self.clear_history();
+        }
+        refresh |= ui
+            .add(TextEdit::singleline(self.get_history_search_mut()).hint_text("search"))
+            .changed();
+        let server_history = *self.get_server_history_mut();
+        if server_history && refresh {
+            self.fetch_server_history();
+        }
+
+        let action = {
+            let history_service = self.clone_history_service_rc();
+            let history_service = history_service.borrow();
+            if let Some(error) = history_service.error().filter(|_| server_history) {
+                ui.colored_label(Color32::LIGHT_RED, error);
+            }
+            let entries = if server_history {
+                history_service.entries().iter().collect::<Vec<_>>()
+            } else {
+                self.search_history().collect()
+            };
+            let mut action = None;
+            egui::ScrollArea::vertical()
+                .id_source("history")
+                .auto_shrink(false)
+                .show(ui, |ui| {
+                    if entries.is_empty() {
+                        ui.weak("no statements yet");
+                    }
+                    for (i, entry) in entries.into_iter().enumerate() {
+                        ui.push_id(i, |ui| {
+                            if let Some(a) = draw_entry(ui, entry) {
+                                action = Some(a);
+                            }
+                        });
+                    }
+                });
+            action
+        };
+
+        match action {
+            Some(HistoryAction::Load(entry)) => *self.get_sql_text_mut() = entry.sql,
+            Some(HistoryAction::Rerun(entry)) if !self.get_query_service().is_running() => {
+                self.rerun(&entry)
+            }
+            _ => {}
+        }
+    }
+}
+
+/// One line per entry: click loads the statement into the editor, the button runs it again.
+fn draw_entry(ui: &mut Ui, entry: &HistoryEntry) -> Option<HistoryAction> {
+    let first_line = entry
+        .sql
+        .lines()
+        .map(str::trim)
+        .find(|l| !l.is_empty())
+        .unwrap_or_default();
+    let color = match entry.error {
+        Some(_) => Color32::LIGHT_RED,
+        None => ui.visuals().text_color(),
+    };
+
+    let mut action = None;
+    ui.horizontal(|ui| {
+        if ui.small_button("▶").on_hover_text("run again").clicked() {
+            action = Some(HistoryAction::Rerun(entry.clone()));
+        }
+        let r = ui
+            .add(
+                Label::new(RichText::new(first_line).monospace().color(color))
+                    .truncate(true)
+                    .sense(Sense::click()),
+            )
+            .on_hover_ui(|ui| {
+                ui.monospace(&entry.sql);
+                ui.separator();
+                ui.label(format!(
+                    "{} in {}",
+                    format_time(entry.started_at_ms as f64),
+                    entry.context.as_deref().unwrap_or("Default")
+                ));
+                match &entry.error {
+                    Some(error) => ui.colored_label(Color32::LIGHT_RED, error),
+                    None => ui.label(format!(
+                        "{} rows in {} ms",
+                        entry.row_count, entry.duration_ms
+                    )),
+                };
+            });
+        if r.clicked() {
+            action = Some(HistoryAction::Load(entry.clone()));
+        }
+    });
+    action
+}
@@ -0,0 +1,62 @@
+use egui::{Color32, TextEdit};
+
+use super::ConsoleApp;
+
+impl ConsoleApp {
+    /// Window asking for credentials once the server refused the connection or the login.
+    pub fn draw_login_in_ctx(&mut self, ctx: &egui::Context) {
+        let required = self.get_status().login_required.clone();
+        let error = self.get_auth_service().error().map(str::to_string);
+        if self.get_status().connected || (required.is_none() && error.is_none()) {
+            return;
+        }
+        let mut submit = false;
+        let mut cancel = false;
+        egui::Window::new("Log in")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.label(format!("{} requires a login.", self.get_addr()));
+                let form = self.get_login_form_mut();
+                ui.horizontal(|ui| {
+                    ui.selectable_value(&mut form.use_token, false, "Password");
+                    ui.selectable_value(&mut form.use_token, true, "API token");
+                });
+                egui::Grid::new("login").num_columns(2).show(ui, |ui| {
+                    if form.use_token {
+                        ui.label("Token");
+                        let edit = ui.add(TextEdit::singleline(&mut form.token).password(true));
+                        submit |=
+                            edit.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                        ui.end_row();
+                    } else {
+                        ui.label("User");
+                        ui.add(TextEdit::singleline(&mut form.user));
+                        ui.end_row();
+                        ui.label("Password");
+                        let edit = ui.add(TextEdit::singleline(&mut form.password).password(true));
+                        submit |=
+                            edit.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                        ui.end_row();
+                    }
+                });
+                if let Some(error) = error.as_ref() {
+                    ui.colored_label(Color32::LIGHT_RED, error);
+                } else if let Some(reason) = required.as_ref().filter(|r| !r.is_empty()) {
+                    ui.weak(format!("server: {reason}"));
+                }
+                ui.horizontal(|ui| {
+                    submit |= ui.button("Log in").clicked();
+                    cancel = ui.button("Cancel").clicked();
+                });
+            });
+        if submit {
+            self.submit_login();
+            self.connect();
+        } else if cancel {
+            self.clone_status_rc().borrow_mut().login_required = None;
+            self.clone_auth_service_rc().borrow_mut().clear_error();
+        }
+    }
+}
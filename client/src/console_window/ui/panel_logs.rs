@@ -0,0 +1,222 @@
+use egui::{Button, Color32, ComboBox, RichText, TextEdit, Ui};
+use egui_extras::{Column, TableBuilder};
+use proto_gen::logs::{Level, LogEvent};
+
+use crate::time::format_clock;
+
+use super::ConsoleApp;
+
+const LEVELS: [Level; 5] = [
+    Level::Trace,
+    Level::Debug,
+    Level::Info,
+    Level::Warn,
+    Level::Error,
+];
+
+impl ConsoleApp {
+    pub fn draw_logs_in_ui(&mut self, ui: &mut Ui) {
+        let logs_service = self.clone_logs_service_rc();
+        let mut clear = false;
+        let view = self.get_log_view_mut();
+        ui.horizontal(|ui| {
+            ComboBox::from_id_source("log_level")
+                .width(60.0)
+                .selected_text(level_name(view.min_level))
+                .show_ui(ui, |ui| {
+                    for level in LEVELS {
+                        ui.selectable_value(
+                            &mut view.min_level,
+                            level.into(),
+                            level_name(level.into()),
+                        );
+                    }
+                })
+                .response
+                .on_hover_text("lowest level shown");
+            ui.add(
+                TextEdit::singleline(&mut view.target)
+                    .hint_text("target prefix")
+                    .desired_width(140.0),
+            );
+            ui.add(
+                TextEdit::singleline(&mut view.span_fields)
+                    .hint_text("span fields, name=value, ...")
+                    .desired_width(180.0),
+            )
+            .on_hover_text("only events inside spans with all of these fields");
+            ui.separator();
+            ui.add(
+                TextEdit::singleline(&mut view.search)
+                    .hint_text("search")
+                    .desired_width(140.0),
+            );
+            ui.checkbox(&mut view.follow, "follow");
+            clear = ui.button("Clear").clicked();
+        });
+        if clear {
+            logs_service.borrow_mut().clear();
+        }
+        self.draw_log_filter_in_ui(ui);
+
+        let view = self.get_log_view_mut().clone();
+        let logs = logs_service.borrow();
+        if let Some(error) = logs.error() {
+            ui.colored_label(Color32::LIGHT_RED, error);
+        }
+        if logs.dropped() > 0 {
+            ui.colored_label(
+                Color32::YELLOW,
+                format!(
+                    "{} events were dropped, the client fell behind",
+                    logs.dropped()
+                ),
+            );
+        }
+        let events = logs
+            .events()
+            .iter()
+            .filter(|e| view.matches(e))
+            .collect::<Vec<_>>();
+        draw_events(ui, &events, view.follow);
+    }
+}
+
+impl ConsoleApp {
+    /// The filter deciding which events the server records at all, shared by every client.
+    fn draw_log_filter_in_ui(&mut self, ui: &mut Ui) {
+        let admin_service = self.clone_admin_service_rc();
+        let admin = admin_service.borrow();
+        let in_effect = admin.log_filter().map(str::to_string);
+        let waiting = admin.is_log_filter_pending();
+        let error = admin.log_filter_error().map(str::to_string);
+        drop(admin);
+        if in_effect.is_none() && !waiting && self.get_status().connected {
+            admin_service.borrow_mut().get_log_filter();
+        }
+
+        let mut apply = None;
+        let edit = self.get_log_filter_edit_mut();
+        if edit.is_none() {
+            edit.clone_from(&in_effect);
+        }
+        ui.horizontal(|ui| {
+            ui.label("Server filter");
+            let Some(text) = edit else {
+                ui.spinner();
+                return;
+            };
+            ui.add(
+                TextEdit::singleline(text)
+                    .hint_text("RUST_LOG directive, e.g. info,datafusion=debug")
+                    .desired_width(320.0),
+            )
+            .on_hover_text("events filtered out here are not recorded for any client");
+            let changed = in_effect.as_deref() != Some(text.trim());
+            if ui
+                .add_enabled(changed && !waiting, Button::new("Apply"))
+                .clicked()
+            {
+                apply = Some(text.trim().to_string());
+            }
+            if ui.add_enabled(changed, Button::new("Reset")).clicked() {
+                edit.clone_from(&in_effect);
+            }
+            if waiting {
+                ui.spinner();
+            }
+        });
+        if let Some(error) = error {
+            ui.colored_label(Color32::LIGHT_RED, error);
+        }
+        if let Some(directive) = apply {
+            admin_service.borrow_mut().set_log_filter(directive);
+        }
+    }
+}
+
+fn level_name(level: i32) -> &'static str {
+    match Level::try_from(level).unwrap_or_default() {
+        Level::Trace => "TRACE",
+        Level::Debug => "DEBUG",
+        Level::Info => "INFO",
+        Level::Warn => "WARN",
+        Level::Error => "ERROR",
+    }
+}
+
+fn level_color(level: i32) -> Color32 {
+    match Level::try_from(level).unwrap_or_default() {
+        Level::Trace => Color32::GRAY,
+        Level::Debug => Color32::LIGHT_BLUE,
+        Level::Info => Color32::LIGHT_GREEN,
+        Level::Warn => Color32::YELLOW,
+        Level::Error => Color32::LIGHT_RED,
+    }
+}
+
+fn draw_events(ui: &mut Ui, events: &[&LogEvent], follow: bool) {
+    TableBuilder::new(ui)
+        .striped(true)
+        .resizable(true)
+        .stick_to_bottom(follow)
+        .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+        .column(Column::auto().at_least(90.0))
+        .column(Column::auto().at_least(50.0))
+        .column(Column::initial(160.0).clip(true))
+        .column(Column::remainder().clip(true))
+        .header(20.0, |mut header| {
+            for name in ["Time", "Level", "Target", "Message"] {
+                header.col(|ui| {
+                    ui.strong(name);
+                });
+            }
+        })
+        .body(|body| {
+            body.rows(18.0, events.len(), |mut row| {
+                let event = events[row.index()];
+                row.col(|ui| {
+                    ui.monospace(format_clock(event.time_ms));
+                });
+                row.col(|ui| {
+                    ui.label(
+                        RichText::new(level_name(event.level))
+                            .monospace()
+                            .color(level_color(event.level)),
+                    );
+                });
+                row.col(|ui| {
+                    ui.label(&event.target);
+                });
+                row.col(|ui| {
+                    let fields = event
+                        .fields
+                        .iter()
+                        .map(|f| format!(" {}={}", f.name, f.value))
+                        .collect::<String>();
+                    ui.label(format!("{}{fields}", event.message))
+                        .on_hover_ui(|ui| draw_event_detail(ui, event));
+                });
+            });
+        });
+}
+
+fn draw_event_detail(ui: &mut Ui, event: &LogEvent) {
+    ui.label(&event.message);
+    for field in event.fields.iter() {
+        ui.monospace(format!("{} = {}", field.name, field.value));
+    }
+    if event.spans.is_empty() {
+        return;
+    }
+    ui.separator();
+    for span in event.spans.iter() {
+        let fields = span
+            .fields
+            .iter()
+            .map(|f| format!("{}={}", f.name, f.value))
+            .collect::<Vec<_>>()
+            .join(" ");
+        ui.monospace(format!("in {} {fields}", span.name));
+    }
+}
@@ -0,0 +1,316 @@
+use egui::{Color32, ProgressBar, RichText, Ui};
+use egui_extras::{Column, TableBuilder};
+use proto_gen::monitor::{OperatorMetrics, QueryDetail, QueryInfo, QueryState};
+
+use crate::custom_widgets::toggle_ui::toggle_ui;
+use crate::time::format_time;
+
+use super::panel_resources::draw_resources;
+use super::{ConsoleApp, MonitorTab};
+
+enum MonitorAction {
+    Inspect(u64),
+    Kill(u64),
+}
+
+impl ConsoleApp {
+    pub fn draw_monitor_in_ui(&mut self, ui: &mut Ui) {
+        if !self.get_status().connected {
+            ui.centered_and_justified(|ui| {
+                ui.weak("Connect to a server to monitor it");
+            });
+            return;
+        }
+
+        let mut pause_changed = false;
+        ui.horizontal(|ui| {
+            let tab = self.get_monitor_tab_mut();
+            ui.selectable_value(tab, MonitorTab::Queries, "Queries");
+            ui.selectable_value(tab, MonitorTab::Logs, "Logs");
+            ui.selectable_value(tab, MonitorTab::Sessions, "Sessions");
+            ui.selectable_value(tab, MonitorTab::Audit, "Audit");
+            ui.separator();
+            pause_changed = toggle_ui(ui, &mut self.get_status().pause_server_yields.borrow_mut())
+                .on_hover_text("stop the server from pushing updates")
+                .changed();
+            ui.label("Pause");
+        });
+        ui.separator();
+        if pause_changed {
+            self.apply_config();
+        }
+        match *self.get_monitor_tab_mut() {
+            MonitorTab::Queries => self.draw_queries_in_ui(ui),
+            MonitorTab::Logs => self.draw_logs_in_ui(ui),
+            MonitorTab::Sessions => self.draw_sessions_in_ui(ui),
+            MonitorTab::Audit => self.draw_audit_in_ui(ui),
+        }
+    }
+
+    fn draw_queries_in_ui(&mut self, ui: &mut Ui) {
+        let monitor_service = self.clone_monitor_service_rc();
+        let running_only = *self.get_monitor_running_only_mut();
+        let action = {
+            let monitor = monitor_service.borrow();
+            // newest first
+            let queries = monitor
+                .queries()
+                .iter()
+                .rev()
+                .filter(|q| !running_only || is_active(q.state()))
+                .collect::<Vec<_>>();
+            let count = |state| {
+                monitor
+                    .queries()
+                    .iter()
+                    .filter(|q| q.state() == state)
+                    .count()
+            };
+            let (running, queued) = (count(QueryState::Running), count(QueryState::Queued));
+
+            ui.horizontal(|ui| {
+                ui.label(format!(
+                    "{running} running, {queued} queued, {} recent",
+                    monitor.queries().len() - running - queued
+                ));
+                ui.separator();
+                ui.checkbox(self.get_monitor_running_only_mut(), "running only");
+                if let Some(error) = monitor.feed_error().or(monitor.kill_error()) {
+                    ui.separator();
+                    ui.colored_label(Color32::LIGHT_RED, error);
+                }
+            });
+            ui.separator();
+            draw_resources(ui, monitor.resources());
+            ui.separator();
+            draw_queries(ui, &queries)
+        };
+
+        match action {
+            Some(MonitorAction::Inspect(id)) => monitor_service.borrow_mut().inspect(id),
+            Some(MonitorAction::Kill(id)) => monitor_service.borrow_mut().kill(id),
+            None => {}
+        }
+
+        let mut close = false;
+        if let Some(detail) = monitor_service.borrow().detail() {
+            let mut open = true;
+            egui::Window::new("Query")
+                .open(&mut open)
+                .resizable(true)
+                .show(ui.ctx(), |ui| draw_detail(ui, detail));
+            close = !open;
+        }
+        if close {
+            monitor_service.borrow_mut().close_detail();
+        }
+    }
+}
+
+fn state_color(state: QueryState) -> Color32 {
+    match state {
+        QueryState::Queued => Color32::YELLOW,
+        QueryState::Running => Color32::LIGHT_BLUE,
+        QueryState::Finished => Color32::LIGHT_GREEN,
+        QueryState::Failed => Color32::LIGHT_RED,
+        QueryState::Killed => Color32::GRAY,
+    }
+}
+
+fn state_name(state: QueryState) -> &'static str {
+    match state {
+        QueryState::Queued => "queued",
+        QueryState::Running => "running",
+        QueryState::Finished => "finished",
+        QueryState::Failed => "failed",
+        QueryState::Killed => "killed",
+    }
+}
+
+/// Whether the query may still be killed.
+fn is_active(state: QueryState) -> bool {
+    matches!(state, QueryState::Queued | QueryState::Running)
+}
+
+fn format_nanos(nanos: u64) -> String {
+    match nanos {
+        0..=999_999 => format!("{:.1} µs", nanos as f64 / 1e3),
+        1_000_000..=999_999_999 => format!("{:.1} ms", nanos as f64 / 1e6),
+        _ => format!("{:.2} s", nanos as f64 / 1e9),
+    }
+}
+
+pub(super) fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
+
+fn draw_queries(ui: &mut Ui, queries: &[&QueryInfo]) -> Option<MonitorAction> {
+    let mut action = None;
+    TableBuilder::new(ui)
+        .striped(true)
+        .resizable(true)
+        .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+        .column(Column::auto().at_least(40.0))
+        .column(Column::auto().at_least(60.0))
+        .columns(Column::auto().at_least(60.0).clip(true), 2)
+        .column(Column::auto().at_least(120.0))
+        .columns(Column::auto().at_least(60.0), 3)
+        .column(Column::remainder().at_least(120.0).clip(true))
+        .column(Column::auto().at_least(80.0))
+        .header(20.0, |mut header| {
+            for name in [
+                "ID", "State", "Context", "Client", "Started", "Duration", "Rows", "Sent", "SQL",
+                "",
+            ] {
+                header.col(|ui| {
+                    ui.strong(name);
+                });
+            }
+        })
+        .body(|body| {
+            body.rows(18.0, queries.len(), |mut row| {
+                let query = queries[row.index()];
+                row.col(|ui| {
+                    ui.label(query.id.to_string());
+                });
+                row.col(|ui| {
+                    let state = query.state();
+                    let r = ui.label(RichText::new(state_name(state)).color(state_color(state)));
+                    if !query.error.is_empty() {
+                        r.on_hover_text(&query.error);
+                    }
+                });
+                row.col(|ui| {
+                    ui.label(if query.context.is_empty() {
+                        "Default"
+                    } else {
+                        &query.context
+                    });
+                });
+                row.col(|ui| {
+                    ui.label(&query.client_addr);
+                });
+                row.col(|ui| {
+                    ui.label(format_time(query.started_at_ms as f64));
+                });
+                row.col(|ui| {
+                    ui.label(format!("{} ms", query.duration_ms));
+                });
+                row.col(|ui| {
+                    ui.label(query.rows.to_string());
+                });
+                row.col(|ui| {
+                    ui.label(format_bytes(query.bytes_sent));
+                });
+                row.col(|ui| {
+                    let first_line = query.sql.lines().next().unwrap_or_default();
+                    ui.monospace(first_line).on_hover_ui(|ui| {
+                        ui.monospace(&query.sql);
+                    });
+                });
+                row.col(|ui| {
+                    if ui.small_button("Inspect").clicked() {
+                        action = Some(MonitorAction::Inspect(query.id));
+                    }
+                    if ui
+                        .add_enabled(is_active(query.state()), egui::Button::new("Kill").small())
+                        .clicked()
+                    {
+                        action = Some(MonitorAction::Kill(query.id));
+                    }
+                });
+            });
+        });
+    action
+}
+
+fn draw_detail(ui: &mut Ui, detail: &QueryDetail) {
+    let Some(query) = detail.query.as_ref() else {
+        ui.colored_label(Color32::LIGHT_RED, &detail.error);
+        return;
+    };
+
+    ui.horizontal(|ui| {
+        let state = query.state();
+        ui.label(RichText::new(state_name(state)).color(state_color(state)));
+        ui.label(format!(
+            "query {} from {}, {} rows and {} sent in {} ms",
+            query.id,
+            query.client_addr,
+            query.rows,
+            format_bytes(query.bytes_sent),
+            query.duration_ms
+        ));
+    });
+    if !query.error.is_empty() {
+        ui.colored_label(Color32::LIGHT_RED, &query.error);
+    }
+    ui.separator();
+    egui::ScrollArea::vertical()
+        .max_height(400.0)
+        .auto_shrink([false, true])
+        .show(ui, |ui| {
+            ui.monospace(&query.sql);
+            ui.separator();
+            let Some(root) = detail.operators.as_ref() else {
+                ui.weak("not planned yet");
+                return;
+            };
+            draw_operator(ui, root, total_compute_ns(root), "0");
+            ui.collapsing("Plan", |ui| {
+                ui.monospace(&detail.plan);
+            });
+        });
+}
+
+fn total_compute_ns(operator: &OperatorMetrics) -> u64 {
+    operator.elapsed_compute_ns + operator.children.iter().map(total_compute_ns).sum::<u64>()
+}
+
+/// One node of the plan tree, its bar is its share of the compute time of the whole plan.
+fn draw_operator(ui: &mut Ui, operator: &OperatorMetrics, total_compute_ns: u64, path: &str) {
+    egui::CollapsingHeader::new(RichText::new(&operator.name).monospace())
+        .id_source(path)
+        .default_open(true)
+        .show(ui, |ui| {
+            let share = match total_compute_ns {
+                0 => 0.0,
+                total => operator.elapsed_compute_ns as f32 / total as f32,
+            };
+            let rows = match operator.estimated_rows {
+                Some(estimated) => format!("{} of ~{estimated} rows", operator.output_rows),
+                None => format!("{} rows", operator.output_rows),
+            };
+            ui.add(
+                ProgressBar::new(share)
+                    .desired_width(240.0)
+                    .text(format!("{:.0}% of compute", share * 100.0)),
+            );
+            let mut stats = vec![rows, format_nanos(operator.elapsed_compute_ns)];
+            if operator.mem_used > 0 {
+                stats.push(format!("{} in memory", format_bytes(operator.mem_used)));
+            }
+            if operator.spill_count > 0 {
+                stats.push(format!(
+                    "{} spilled {} times",
+                    format_bytes(operator.spilled_bytes),
+                    operator.spill_count
+                ));
+            }
+            ui.label(stats.join(", "));
+            for (i, child) in operator.children.iter().enumerate() {
+                draw_operator(ui, child, total_compute_ns, &format!("{path}.{i}"));
+            }
+        });
+}
@@ -0,0 +1,153 @@
+use egui::{Color32, TextEdit, Ui};
+
+use crate::console_window::app::TableForm;
+
+use super::ConsoleApp;
+
+const FILE_TYPES: [&str; 5] = ["parquet", "csv", "json", "avro", "arrow"];
+
+impl ConsoleApp {
+    /// Window registering a table over a directory in the current context.
+    pub fn draw_register_table_in_ctx(&mut self, ctx: &egui::Context) {
+        let mut open = *self.get_show_register_table_mut();
+        if !open {
+            return;
+        }
+        let context = self
+            .get_status()
+            .current_context_name
+            .borrow()
+            .clone()
+            .unwrap_or_else(|| "Default".to_string());
+        let mut register = false;
+        egui::Window::new("Register table")
+            .open(&mut open)
+            .resizable(false)
+            .show(ctx, |ui| {
+                let form = self.get_table_form_mut();
+                draw_form(ui, form);
+                ui.separator();
+                ui.label("Partition columns").on_hover_text(
+                    "one per directory level, e.g. dt and region for dt=2024-01-01/region=eu/",
+                );
+                draw_pairs(
+                    ui,
+                    "partition_columns",
+                    &mut form.partition_columns,
+                    |ui, data_type| {
+                        ui.add(
+                            TextEdit::singleline(data_type)
+                                .hint_text("Utf8")
+                                .desired_width(80.0),
+                        )
+                        .on_hover_text("Arrow type, e.g. Date32, Int32 or Utf8");
+                    },
+                );
+                ui.label("Sort order")
+                    .on_hover_text("how the rows of every file are sorted");
+                draw_pairs(ui, "sort_order", &mut form.sort_order, |ui, descending| {
+                    ui.checkbox(descending, "desc");
+                });
+                ui.separator();
+                ui.horizontal(|ui| {
+                    let query = self.get_query_service();
+                    let ready = self.get_status().connected && !query.is_registering();
+                    register = ui
+                        .add_enabled(ready, egui::Button::new("Register"))
+                        .on_hover_text(format!("in context {context}"))
+                        .clicked();
+                    if query.is_registering() {
+                        ui.spinner();
+                    } else if let Some(registered) = query.registered() {
+                        if registered.error.is_empty() {
+                            ui.colored_label(
+                                Color32::LIGHT_GREEN,
+                                format!(
+                                    "registered, {} files in {} partitions",
+                                    registered.files, registered.partitions
+                                ),
+                            );
+                        } else {
+                            ui.colored_label(Color32::LIGHT_RED, &registered.error);
+                        }
+                    }
+                });
+            });
+        *self.get_show_register_table_mut() = open;
+        if register {
+            self.register_table();
+        }
+    }
+}
+
+fn draw_form(ui: &mut Ui, form: &mut TableForm) {
+    egui::Grid::new("register_table")
+        .num_columns(2)
+        .show(ui, |ui| {
+            ui.label("Name");
+            ui.add(TextEdit::singleline(&mut form.name).hint_text("events"));
+            ui.end_row();
+
+            ui.label("Location");
+            ui.add(TextEdit::singleline(&mut form.location).hint_text("/data/events/"));
+            ui.end_row();
+
+            ui.label("File type");
+            egui::ComboBox::from_id_source("register_file_type")
+                .selected_text(form.file_type.as_str())
+                .show_ui(ui, |ui| {
+                    for file_type in FILE_TYPES {
+                        ui.selectable_value(&mut form.file_type, file_type.to_string(), file_type);
+                    }
+                });
+            ui.end_row();
+
+            ui.label("Extension");
+            ui.add(
+                TextEdit::singleline(&mut form.file_extension)
+                    .hint_text(format!(".{}", form.file_type)),
+            )
+            .on_hover_text("only files ending with it are read");
+            ui.end_row();
+
+            if form.file_type == "csv" {
+                ui.label("Header");
+                ui.checkbox(&mut form.has_header, "first line names the columns");
+                ui.end_row();
+            }
+
+            ui.label("Replace");
+            ui.checkbox(&mut form.replace, "a table of the same name");
+            ui.end_row();
+        });
+}
+
+/// Editable rows of a column name and what `draw_value` edits next to it.
+fn draw_pairs<T: Default>(
+    ui: &mut Ui,
+    id: &str,
+    pairs: &mut Vec<(String, T)>,
+    draw_value: impl Fn(&mut Ui, &mut T),
+) {
+    let mut removed = None;
+    egui::Grid::new(id).num_columns(3).show(ui, |ui| {
+        for (i, (name, value)) in pairs.iter_mut().enumerate() {
+            ui.add(
+                TextEdit::singleline(name)
+                    .hint_text("column")
+                    .desired_width(120.0),
+            );
+            draw_value(ui, value);
+            if ui.small_button("🗑").clicked() {
+                removed = Some(i);
+            }
+            ui.end_row();
+        }
+    });
+    if let Some(i) = removed {
+        pairs.remove(i);
+    }
+    if ui.small_button("+").on_hover_text("add a column").clicked() {
+        pairs.push(Default::default());
+    }
+}
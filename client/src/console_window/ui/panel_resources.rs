@@ -0,0 +1,106 @@
+use std::collections::VecDeque;
+
+use egui::{Color32, RichText, Ui};
+use proto_gen::monitor::ResourceSample;
+
+use crate::custom_widgets::sparkline::sparkline;
+
+use super::panel_monitor::format_bytes;
+
+const SPARKLINE_SIZE: egui::Vec2 = egui::vec2(140.0, 32.0);
+
+/// One sparkline per figure of the server health, with its latest value.
+pub(super) fn draw_resources(ui: &mut Ui, samples: &VecDeque<ResourceSample>) {
+    let Some(last) = samples.back() else {
+        ui.weak("waiting for the first resource sample");
+        return;
+    };
+
+    ui.horizontal_wrapped(|ui| {
+        let series = |value: fn(&ResourceSample) -> Option<f64>| {
+            samples
+                .iter()
+                .map(|s| value(s).unwrap_or_default())
+                .collect::<Vec<_>>()
+        };
+        draw_figure(
+            ui,
+            "CPU",
+            last.cpu_percent.map(|p| format!("{p:.1} %")),
+            &series(|s| s.cpu_percent),
+            Color32::LIGHT_GREEN,
+        );
+        draw_figure(
+            ui,
+            "RSS",
+            last.rss_bytes.map(format_bytes),
+            &series(|s| s.rss_bytes.map(|b| b as f64)),
+            Color32::LIGHT_BLUE,
+        );
+        draw_figure(
+            ui,
+            "Open files",
+            last.open_fds.map(|n| n.to_string()),
+            &series(|s| s.open_fds.map(|n| n as f64)),
+            Color32::GOLD,
+        );
+        draw_figure(
+            ui,
+            "Tasks",
+            Some(last.alive_tasks.to_string()),
+            &series(|s| Some(s.alive_tasks as f64)),
+            Color32::LIGHT_YELLOW,
+        )
+        .on_hover_text(format!(
+            "{} alive tasks, {} queued, {} workers",
+            last.alive_tasks, last.queued_tasks, last.workers
+        ));
+        draw_figure(
+            ui,
+            "Clients",
+            Some(last.clients.to_string()),
+            &series(|s| Some(s.clients as f64)),
+            Color32::KHAKI,
+        );
+        for context in last.contexts.iter() {
+            let reserved = samples
+                .iter()
+                .map(|s| {
+                    s.contexts
+                        .iter()
+                        .find(|c| c.name == context.name)
+                        .map(|c| c.reserved_bytes as f64)
+                        .unwrap_or_default()
+                })
+                .collect::<Vec<_>>();
+            draw_figure(
+                ui,
+                &format!("Pool {}", context.name),
+                Some(format_bytes(context.reserved_bytes)),
+                &reserved,
+                Color32::LIGHT_RED,
+            )
+            .on_hover_text("memory reserved in the DataFusion memory pool of the context");
+        }
+    });
+}
+
+/// `value` is unset where the server cannot tell, such as outside of Linux.
+fn draw_figure(
+    ui: &mut Ui,
+    name: &str,
+    value: Option<String>,
+    series: &[f64],
+    color: Color32,
+) -> egui::Response {
+    ui.group(|ui| {
+        ui.vertical(|ui| {
+            ui.horizontal(|ui| {
+                ui.weak(name);
+                ui.label(RichText::new(value.unwrap_or_else(|| "n/a".to_string())).strong());
+            });
+            sparkline(ui, series, SPARKLINE_SIZE, color);
+        });
+    })
+    .response
+}
@@ -0,0 +1,186 @@
+use egui::{Color32, Label, RichText, Sense, TextEdit, Ui};
+use proto_gen::saved::SavedQuery;
+
+use crate::saved::QueryDraft;
+use crate::time::format_time;
+
+use super::ConsoleApp;
+
+enum SavedAction {
+    Load(SavedQuery),
+    Run(SavedQuery),
+    Edit(SavedQuery),
+    Delete(u64),
+}
+
+impl ConsoleApp {
+    pub fn draw_saved_in_ui(&mut self, ui: &mut Ui) {
+        let saved_service = self.clone_saved_service_rc();
+        let (mut save, mut refresh, mut export, mut import) = (false, false, false, false);
+        ui.horizontal(|ui| {
+            save = ui
+                .small_button("💾")
+                .on_hover_text("save the editor content")
+                .clicked();
+            refresh = ui.small_button("⟳").on_hover_text("refresh").clicked();
+            export = ui
+                .small_button("Export")
+                .on_hover_text("download all saved queries as one file")
+                .clicked();
+            import = ui
+                .small_button("Import")
+                .on_hover_text(
+                    "add the queries of an exported file, replacing those of the same name",
+                )
+                .clicked();
+        });
+        if save {
+            self.draft_from_editor();
+        }
+        if refresh {
+            saved_service.borrow_mut().list();
+        }
+        if export {
+            self.export_saved();
+        }
+        if import {
+            self.pick_import_file(ui.ctx());
+        }
+        ui.add(TextEdit::singleline(self.get_saved_search_mut()).hint_text("search"));
+        if let Some(error) = saved_service.borrow().error() {
+            ui.colored_label(Color32::LIGHT_RED, error);
+        }
+        if let Some(error) = self.get_import_error() {
+            ui.colored_label(Color32::LIGHT_RED, error);
+        }
+
+        let queries = self.search_saved();
+        let mut action = None;
+        egui::ScrollArea::vertical()
+            .id_source("saved")
+            .auto_shrink(false)
+            .show(ui, |ui| {
+                if queries.is_empty() {
+                    ui.weak("no saved queries");
+                }
+                for query in queries {
+                    ui.push_id(query.id, |ui| {
+                        if let Some(a) = draw_query(ui, query) {
+                            action = Some(a);
+                        }
+                    });
+                }
+            });
+
+        match action {
+            Some(SavedAction::Load(query)) => self.load_saved(&query),
+            Some(SavedAction::Run(query)) if !self.get_query_service().is_running() => {
+                self.run_saved(&query)
+            }
+            Some(SavedAction::Edit(query)) => {
+                *self.get_query_draft_mut() = Some(QueryDraft::from(&query))
+            }
+            Some(SavedAction::Delete(id)) => saved_service.borrow_mut().delete(id),
+            _ => {}
+        }
+
+        self.draw_query_draft(ui.ctx());
+    }
+
+    /// Window editing the draft, it is sent to the server on save.
+    fn draw_query_draft(&mut self, ctx: &egui::Context) {
+        let (mut save, mut cancel) = (false, false);
+        let Some(draft) = self.get_query_draft_mut().as_mut() else {
+            return;
+        };
+        let title = if draft.id == 0 {
+            "Save query"
+        } else {
+            "Edit saved query"
+        };
+        egui::Window::new(title)
+            .collapsible(false)
+            .resizable(true)
+            .show(ctx, |ui| {
+                egui::Grid::new("query_draft")
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        ui.label("Name");
+                        ui.text_edit_singleline(&mut draft.name);
+                        ui.end_row();
+
+                        ui.label("Description");
+                        ui.text_edit_singleline(&mut draft.description);
+                        ui.end_row();
+
+                        ui.label("Tags");
+                        ui.add(TextEdit::singleline(&mut draft.tags).hint_text("comma separated"));
+                        ui.end_row();
+
+                        ui.label("Context");
+                        ui.add(TextEdit::singleline(&mut draft.context).hint_text("Default"));
+                        ui.end_row();
+                    });
+                ui.add(
+                    TextEdit::multiline(&mut draft.sql)
+                        .code_editor()
+                        .desired_rows(6)
+                        .desired_width(f32::INFINITY),
+                );
+                ui.horizontal(|ui| {
+                    save = ui
+                        .add_enabled(!draft.name.trim().is_empty(), egui::Button::new("Save"))
+                        .clicked();
+                    cancel = ui.button("Cancel").clicked();
+                });
+            });
+        if save {
+            self.save_draft();
+        } else if cancel {
+            *self.get_query_draft_mut() = None;
+        }
+    }
+}
+
+/// One line per query: click loads it into the editor, the buttons run, edit or delete it.
+fn draw_query(ui: &mut Ui, query: SavedQuery) -> Option<SavedAction> {
+    let mut action = None;
+    ui.horizontal(|ui| {
+        if ui.small_button("▶").on_hover_text("run").clicked() {
+            action = Some(SavedAction::Run(query.clone()));
+        }
+        if ui.small_button("✏").on_hover_text("edit").clicked() {
+            action = Some(SavedAction::Edit(query.clone()));
+        }
+        if ui.small_button("🗑").on_hover_text("delete").clicked() {
+            action = Some(SavedAction::Delete(query.id));
+        }
+        let mut text = RichText::new(&query.name);
+        if !query.tags.is_empty() {
+            text = RichText::new(format!("{} [{}]", query.name, query.tags.join(", ")));
+        }
+        let r = ui
+            .add(Label::new(text).truncate(true).sense(Sense::click()))
+            .on_hover_ui(|ui| {
+                if !query.description.is_empty() {
+                    ui.label(&query.description);
+                    ui.separator();
+                }
+                ui.monospace(&query.sql);
+                ui.separator();
+                ui.label(format!(
+                    "in {}, saved {}",
+                    if query.context.is_empty() {
+                        "Default"
+                    } else {
+                        &query.context
+                    },
+                    format_time(query.updated_at_ms as f64)
+                ));
+            });
+        if r.clicked() {
+            action = Some(SavedAction::Load(query.clone()));
+        }
+    });
+    action
+}
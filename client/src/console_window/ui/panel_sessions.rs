@@ -0,0 +1,160 @@
+use std::time::Duration;
+
+use egui::{Color32, Ui};
+use egui_extras::{Column, TableBuilder};
+use proto_gen::admin::{SessionInfo, SessionList};
+
+use crate::time::format_time;
+
+use super::panel_monitor::format_bytes;
+use super::ConsoleApp;
+
+/// The session list is requested again this often while shown.
+const REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+enum SessionAction {
+    Cancel(u64),
+    Disconnect(u64),
+}
+
+impl ConsoleApp {
+    pub fn draw_sessions_in_ui(&mut self, ui: &mut Ui) {
+        let admin_service = self.clone_admin_service_rc();
+        let now = ui.input(|i| i.time);
+        let listed_at = self.get_sessions_listed_at_mut();
+        let due = match *listed_at {
+            Some(at) => now - at >= REFRESH_INTERVAL.as_secs_f64(),
+            None => true,
+        };
+        if due && !admin_service.borrow().is_listing_sessions() {
+            admin_service.borrow_mut().list_sessions();
+            *listed_at = Some(now);
+        }
+        ui.ctx().request_repaint_after(REFRESH_INTERVAL);
+
+        let action = {
+            let admin = admin_service.borrow();
+            let Some(list) = admin.sessions() else {
+                ui.spinner();
+                return;
+            };
+            if !list.error.is_empty() {
+                ui.colored_label(Color32::LIGHT_RED, &list.error);
+                return;
+            }
+            ui.horizontal(|ui| {
+                ui.label(format!("{} sessions", list.sessions.len()));
+                if let Some(error) = admin.action_error() {
+                    ui.separator();
+                    ui.colored_label(Color32::LIGHT_RED, error);
+                }
+            });
+            ui.separator();
+            draw_sessions(ui, list)
+        };
+
+        match action {
+            Some(SessionAction::Cancel(id)) => admin_service.borrow_mut().cancel_query(id),
+            Some(SessionAction::Disconnect(id)) => admin_service.borrow_mut().disconnect(id),
+            None => {}
+        }
+    }
+}
+
+fn draw_sessions(ui: &mut Ui, list: &SessionList) -> Option<SessionAction> {
+    let mut action = None;
+    TableBuilder::new(ui)
+        .striped(true)
+        .resizable(true)
+        .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+        .column(Column::auto().at_least(40.0))
+        .column(Column::auto().at_least(140.0))
+        .column(Column::auto().at_least(120.0))
+        .column(Column::auto().at_least(80.0).clip(true))
+        .columns(Column::auto().at_least(60.0), 2)
+        .column(Column::remainder().at_least(160.0).clip(true))
+        .column(Column::auto().at_least(80.0))
+        .header(20.0, |mut header| {
+            for name in [
+                "ID",
+                "Client",
+                "Connected",
+                "Context",
+                "In",
+                "Out",
+                "Running",
+                "",
+            ] {
+                header.col(|ui| {
+                    ui.strong(name);
+                });
+            }
+        })
+        .body(|mut body| {
+            for session in list.sessions.iter() {
+                // one line per running query, at least one per session
+                let lines = session.queries.len().max(1);
+                body.row(18.0 * lines as f32, |mut row| {
+                    let own = session.id == list.own_id;
+                    row.col(|ui| {
+                        ui.label(session.id.to_string());
+                    });
+                    row.col(|ui| {
+                        draw_client(ui, session, own);
+                    });
+                    row.col(|ui| {
+                        ui.label(format_time(session.connected_at_ms as f64));
+                    });
+                    row.col(|ui| {
+                        ui.label(if session.context.is_empty() {
+                            "Default"
+                        } else {
+                            &session.context
+                        });
+                    });
+                    row.col(|ui| {
+                        ui.label(format_bytes(session.bytes_in));
+                    });
+                    row.col(|ui| {
+                        ui.label(format_bytes(session.bytes_out));
+                    });
+                    row.col(|ui| {
+                        ui.vertical(|ui| {
+                            if session.queries.is_empty() {
+                                ui.weak("idle");
+                            }
+                            for query in session.queries.iter() {
+                                ui.horizontal(|ui| {
+                                    if ui.small_button("Cancel").clicked() {
+                                        action = Some(SessionAction::Cancel(query.id));
+                                    }
+                                    ui.label(format!("#{} {} ms", query.id, query.duration_ms));
+                                    ui.monospace(query.sql.replace('\n', " "))
+                                        .on_hover_text(&query.sql);
+                                });
+                            }
+                        });
+                    });
+                    row.col(|ui| {
+                        let disconnect = ui
+                            .add_enabled(!own, egui::Button::new("Disconnect"))
+                            .on_disabled_hover_text("this is the session of this console");
+                        if disconnect.clicked() {
+                            action = Some(SessionAction::Disconnect(session.id));
+                        }
+                    });
+                });
+            }
+        });
+    action
+}
+
+fn draw_client(ui: &mut Ui, session: &SessionInfo, own: bool) {
+    ui.label(&session.peer_addr);
+    if session.admin {
+        ui.weak("admin");
+    }
+    if own {
+        ui.weak("(you)");
+    }
+}
@@ -0,0 +1,130 @@
+use egui::{Color32, DragValue, TextEdit, Ui};
+
+use crate::custom_widgets::toggle_ui::toggle_ui;
+
+use super::ConsoleApp;
+
+impl ConsoleApp {
+    /// Window editing the settings of the connection, sent to the server on apply.
+    pub fn draw_settings_in_ctx(&mut self, ctx: &egui::Context) {
+        let mut open = *self.get_show_settings_mut();
+        if !open {
+            return;
+        }
+        let mut apply = false;
+        egui::Window::new("Connection settings")
+            .open(&mut open)
+            .resizable(false)
+            .show(ctx, |ui| {
+                egui::Grid::new("settings").num_columns(2).show(ui, |ui| {
+                    ui.label("Pause monitor");
+                    // applied right away like the toggle of the monitor
+                    apply |= toggle_ui(ui, &mut self.get_status().pause_server_yields.borrow_mut())
+                        .on_hover_text("stop the server from pushing the monitor feed")
+                        .changed();
+                    ui.end_row();
+
+                    ui.label("Monitor interval");
+                    ui.add(
+                        DragValue::new(self.get_monitor_interval_ms_mut())
+                            .clamp_range(0..=60_000)
+                            .speed(10)
+                            .suffix(" ms"),
+                    )
+                    .on_hover_text("how often the monitor is refreshed, 0 for the server default");
+                    ui.end_row();
+                });
+                ui.separator();
+                ui.label("Session options")
+                    .on_hover_text("DataFusion options for the statements of this client only");
+                self.draw_session_options(ui);
+                ui.separator();
+                ui.label("Query limits").on_hover_text(
+                    "caps of the statements run from now on, 0 for none, \
+                     the server may enforce lower ones",
+                );
+                self.draw_query_limits(ui);
+                ui.separator();
+                ui.horizontal(|ui| {
+                    apply |= ui
+                        .add_enabled(self.get_status().connected, egui::Button::new("Apply"))
+                        .clicked();
+                    self.draw_config_state(ui);
+                });
+            });
+        *self.get_show_settings_mut() = open;
+        if apply {
+            self.apply_config();
+        }
+    }
+
+    fn draw_session_options(&mut self, ui: &mut Ui) {
+        let options = self.get_session_options_mut();
+        let mut removed = None;
+        egui::Grid::new("session_options")
+            .num_columns(3)
+            .show(ui, |ui| {
+                for (i, (key, value)) in options.iter_mut().enumerate() {
+                    ui.add(
+                        TextEdit::singleline(key)
+                            .hint_text("datafusion.execution.batch_size")
+                            .desired_width(240.0),
+                    );
+                    ui.add(TextEdit::singleline(value).desired_width(80.0));
+                    if ui.small_button("🗑").clicked() {
+                        removed = Some(i);
+                    }
+                    ui.end_row();
+                }
+            });
+        if let Some(i) = removed {
+            options.remove(i);
+        }
+        if ui
+            .small_button("+")
+            .on_hover_text("add an option")
+            .clicked()
+        {
+            options.push(Default::default());
+        }
+    }
+
+    fn draw_query_limits(&mut self, ui: &mut Ui) {
+        let limits = self.get_query_limits_mut();
+        egui::Grid::new("query_limits")
+            .num_columns(2)
+            .show(ui, |ui| {
+                ui.label("Timeout");
+                ui.add(DragValue::new(&mut limits.timeout_s).suffix(" s"));
+                ui.end_row();
+
+                ui.label("Rows");
+                ui.add(DragValue::new(&mut limits.max_rows).speed(100));
+                ui.end_row();
+
+                ui.label("Size");
+                ui.add(DragValue::new(&mut limits.max_mb).suffix(" MB"));
+                ui.end_row();
+            });
+    }
+
+    fn draw_config_state(&self, ui: &mut Ui) {
+        let config_service = self.get_config_service();
+        if config_service.is_waiting() {
+            ui.spinner();
+        } else if let Some(error) = config_service.error() {
+            ui.colored_label(Color32::LIGHT_RED, error);
+        } else if let Some(applied) = config_service.applied() {
+            let paused = if applied.pause_monitor {
+                ", paused"
+            } else {
+                ""
+            };
+            ui.weak(format!(
+                "applied, monitor every {} ms{paused}, {} option(s)",
+                applied.monitor_interval_ms,
+                applied.options.len()
+            ));
+        }
+    }
+}
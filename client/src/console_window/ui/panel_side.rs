@@ -1,4 +1,4 @@
-use egui::RichText;
+use egui::{Color32, RichText};
 use log::info;
 
 use crate::custom_widgets;
@@ -9,7 +9,7 @@ impl ConsoleApp {
     pub fn draw_console_side_panel_in_ui(&mut self, ui: &mut egui::Ui) {
         egui::SidePanel::left("menu_panel")
             .resizable(false)
-            .max_width(180.0)
+            .max_width(240.0)
             .show_inside(ui, |ui| {
                 ui.label(RichText::new("Select context"));
                 ui.vertical_centered_justified(|ui| {
@@ -106,6 +106,19 @@ impl ConsoleApp {
                     //         ui.end_row();
                     //     });
                 });
+                ui.separator();
+                ui.horizontal(|ui| {
+                    let show_saved = self.get_show_saved_mut();
+                    ui.selectable_value(show_saved, false, "History");
+                    if ui.selectable_value(show_saved, true, "Saved").clicked() {
+                        self.clone_saved_service_rc().borrow_mut().list();
+                    }
+                });
+                if *self.get_show_saved_mut() {
+                    self.draw_saved_in_ui(ui);
+                } else {
+                    self.draw_history_in_ui(ui);
+                }
             });
         egui::SidePanel::right("status_panel")
             .resizable(false)
@@ -114,6 +127,68 @@ impl ConsoleApp {
                 ui.vertical_centered(|ui| {
                     ui.heading("Status");
                 });
+                self.draw_permissions_in_ui(ui);
+                self.draw_catalog_issues_in_ui(ui);
             });
     }
+
+    /// Definitions of earlier runs the server failed to create again, e.g. as files are gone.
+    fn draw_catalog_issues_in_ui(&self, ui: &mut egui::Ui) {
+        let query = self.get_query_service();
+        let Some(catalog) = query.catalog() else {
+            return;
+        };
+        if catalog.issues.is_empty() {
+            return;
+        }
+        ui.separator();
+        ui.label(RichText::new("Catalog").strong())
+            .on_hover_text("not restored on startup, DROP ... IF EXISTS forgets a definition");
+        for issue in &catalog.issues {
+            ui.colored_label(
+                Color32::LIGHT_RED,
+                format!("{} {} ({})", issue.kind, issue.name, issue.context),
+            )
+            .on_hover_text(&issue.error);
+        }
+    }
+
+    /// The role of this client and what it may do in the current context.
+    fn draw_permissions_in_ui(&self, ui: &mut egui::Ui) {
+        let auth = self.get_auth_service();
+        let Some(identity) = auth.identity() else {
+            ui.weak("not connected");
+            return;
+        };
+        ui.separator();
+        ui.label(RichText::new("Role").strong());
+        ui.label(&identity.role);
+        let context = self
+            .get_status()
+            .current_context_name
+            .borrow()
+            .clone()
+            .unwrap_or_else(|| "Default".to_string());
+        ui.label(RichText::new("Permissions").strong())
+            .on_hover_text(format!("in context {context}"));
+        let granted = auth.permissions(&context).unwrap_or_default();
+        for (permission, what) in PERMISSIONS {
+            let allowed = granted.iter().any(|p| p == permission);
+            let mark = if allowed { "✔" } else { "✖" };
+            let text = RichText::new(format!("{mark} {permission}"));
+            ui.label(if allowed { text } else { text.weak() })
+                .on_hover_text(what);
+        }
+    }
 }
+
+/// Permission names as sent by the server, and what they allow.
+const PERMISSIONS: [(&str, &str); 4] = [
+    ("read", "run queries"),
+    ("ddl", "create and drop tables, views and schemas"),
+    ("write", "INSERT and COPY TO"),
+    (
+        "admin",
+        "cancel queries, disconnect sessions and change the log filter",
+    ),
+];
@@ -3,7 +3,7 @@ use log::info;
 
 use crate::spawn_local;
 
-use super::{Client, ConsoleApp, get_current_host, Mode};
+use super::{Client, ConsoleApp, get_current_host, Mode, websocket_scheme};
 
 impl ConsoleApp {
     pub fn draw_top_menu_in_ui(self: &mut ConsoleApp, ui: &mut egui::Ui) {
@@ -26,8 +26,6 @@ impl ConsoleApp {
                         // } else {
                         //     Color32::DARK_BLUE
                         // };
-                        let current_host = get_current_host();
-
                         egui::widgets::global_dark_light_mode_switch(ui);
                         ui.separator();
                         ui.label(RichText::new("Client mode:").strong());
@@ -53,27 +51,29 @@ impl ConsoleApp {
                             .ui(ui);
 
                         if ui.button(connect_btn_text).highlight().clicked() {
-                            // connect
-                            let current_addr = self.get_addr();
-                            if current_addr.is_empty() {
-                                self.modify_addr(current_host);
-                            }
-
-                            let addr = self.get_addr();
                             if !self.get_status().connected {
-                                let mut client =
-                                    Client::new(format!("ws://{addr}"), self.clone_status_rc());
-                                client.add_service(self.clone_hello_service_rc());
-                                spawn_local(async move {
-                                    let _ = client.connect().await;
-                                });
-                                info!("try to connect to {addr}");
+                                self.connect();
                             } else {
                                 self.get_status().close_notify.notify_one();
-                                info!("try to disconnect from {}", addr);
+                                info!("try to disconnect from {}", self.get_addr());
                             }
                         };
 
+                        let identity = self.get_auth_service().identity().cloned();
+                        if let Some(identity) = identity.filter(|i| !i.user.is_empty()) {
+                            ui.separator();
+                            ui.label(format!("👤 {} ({})", identity.user, identity.role));
+                            if ui.button("Log out").clicked() {
+                                self.log_out();
+                            }
+                        }
+
+                        ui.separator();
+                        let show_settings = self.get_show_settings_mut();
+                        ui.toggle_value(show_settings, "⚙ Settings");
+                        let show_register_table = self.get_show_register_table_mut();
+                        ui.toggle_value(show_register_table, "📁 Register table");
+
                         // ui.separator();
                         // ui.label("Select Mode: ");
                         // let s_ref = self.get_status();
@@ -88,4 +88,31 @@ impl ConsoleApp {
                 });
             });
     }
+
+    /// Opens a connection to the address in the top bar, the server host if empty.
+    pub(super) fn connect(&mut self) {
+        if self.get_addr().is_empty() {
+            self.modify_addr(get_current_host());
+        }
+        let addr = self.get_addr();
+        self.restore_session();
+
+        let url = format!("{}://{addr}", websocket_scheme());
+        let mut client = Client::new(url, self.clone_status_rc());
+        // the login goes out before anything other services send on connecting
+        client.add_service(self.clone_auth_service_rc());
+        client.add_service(self.clone_hello_service_rc());
+        client.add_service(self.clone_query_service_rc());
+        client.add_service(self.clone_editor_service_rc());
+        client.add_service(self.clone_history_service_rc());
+        client.add_service(self.clone_saved_service_rc());
+        client.add_service(self.clone_monitor_service_rc());
+        client.add_service(self.clone_config_service_rc());
+        client.add_service(self.clone_logs_service_rc());
+        client.add_service(self.clone_admin_service_rc());
+        spawn_local(async move {
+            let _ = client.connect().await;
+        });
+        info!("try to connect to {addr}");
+    }
 }
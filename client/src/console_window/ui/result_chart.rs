@@ -0,0 +1,104 @@
+use egui::{ComboBox, DragValue, Ui};
+use egui_plot::{Bar, BarChart, Legend, Line, Plot, PlotPoints, Points};
+
+use crate::chart::{self, Axis, ChartData, ChartKind, ChartSettings};
+use crate::rpc::StatementResult;
+use crate::time::format_time;
+
+/// Draws the chart of `result`, its `settings` filled with the defaults already.
+pub(super) fn draw_chart(ui: &mut Ui, result: &StatementResult, settings: &mut ChartSettings) {
+    ui.horizontal_wrapped(|ui| {
+        ComboBox::from_id_source("chart_kind")
+            .selected_text(settings.kind.to_string())
+            .show_ui(ui, |ui| {
+                for kind in ChartKind::ALL {
+                    ui.selectable_value(&mut settings.kind, kind, kind.to_string());
+                }
+            });
+        column_combo_box(ui, "x", &mut settings.x, result, false);
+        if settings.kind != ChartKind::Histogram {
+            column_combo_box(ui, "y", &mut settings.y, result, false);
+        }
+        column_combo_box(ui, "series by", &mut settings.series_by, result, true);
+        if settings.kind == ChartKind::Histogram {
+            ui.label("bins");
+            ui.add(DragValue::new(&mut settings.bins).clamp_range(0..=100))
+                .on_hover_text("0 derives the count from the number of rows");
+        }
+    });
+    ui.separator();
+
+    match chart::chart_data(result, settings) {
+        Ok(data) => plot(ui, data, settings.kind),
+        Err(e) => {
+            ui.centered_and_justified(|ui| {
+                ui.weak(e);
+            });
+        }
+    }
+}
+
+fn column_combo_box(
+    ui: &mut Ui,
+    label: &str,
+    selected: &mut Option<String>,
+    result: &StatementResult,
+    optional: bool,
+) {
+    ui.label(label);
+    ComboBox::from_id_source(label)
+        .selected_text(selected.as_deref().unwrap_or("none"))
+        .show_ui(ui, |ui| {
+            if optional {
+                ui.selectable_value(selected, None, "none");
+            }
+            for column in result.columns.iter() {
+                ui.selectable_value(selected, Some(column.name.clone()), &column.name)
+                    .on_hover_text(&column.data_type);
+            }
+        });
+}
+
+fn plot(ui: &mut Ui, data: ChartData, kind: ChartKind) {
+    let mut plot = Plot::new("result_chart").legend(Legend::default());
+    match data.x_axis {
+        Axis::Number => {}
+        Axis::Time => plot = plot.x_axis_formatter(|mark, _, _| format_time(mark.value)),
+        Axis::Category(names) => {
+            plot = plot.x_axis_formatter(move |mark, _, _| {
+                // only label the marks that hit a category
+                let index = mark.value.round();
+                match (mark.value - index).abs() < 1e-6 && index >= 0.0 {
+                    true => names.get(index as usize).cloned().unwrap_or_default(),
+                    false => String::new(),
+                }
+            })
+        }
+    }
+
+    let series_count = data.series.len();
+    plot.show(ui, |plot_ui| {
+        for (i, series) in data.series.into_iter().enumerate() {
+            match kind {
+                ChartKind::Line => {
+                    plot_ui.line(Line::new(PlotPoints::from(series.points)).name(series.name))
+                }
+                ChartKind::Scatter => plot_ui.points(
+                    Points::new(PlotPoints::from(series.points))
+                        .radius(3.0)
+                        .name(series.name),
+                ),
+                ChartKind::Bar | ChartKind::Histogram => {
+                    // series side by side, centered around the x value
+                    let offset = (i as f64 - (series_count - 1) as f64 / 2.0) * data.bar_width;
+                    let bars = series
+                        .points
+                        .iter()
+                        .map(|&[x, y]| Bar::new(x + offset, y).width(data.bar_width))
+                        .collect();
+                    plot_ui.bar_chart(BarChart::new(bars).name(series.name))
+                }
+            }
+        }
+    });
+}
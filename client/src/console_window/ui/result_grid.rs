@@ -0,0 +1,180 @@
+use egui::{Align, Color32, Layout, RichText, Ui};
+use egui_extras::{Column, TableBuilder};
+
+use proto_gen::query::{Limit, TableScan};
+
+use crate::rpc::{StatementResult, StatementState};
+
+use super::result_chart::draw_chart;
+use super::ConsoleApp;
+
+impl ConsoleApp {
+    pub fn draw_result_grid_in_ui(&mut self, ui: &mut Ui) {
+        let query_service = self.clone_query_service_rc();
+        let query = query_service.borrow();
+        let results = query.results();
+        if results.is_empty() {
+            ui.centered_and_justified(|ui| {
+                ui.weak("Press Ctrl+Enter in the editor to run the statement under the cursor");
+            });
+            return;
+        }
+
+        let mut selected = (*self.get_selected_result_mut()).min(results.len() - 1);
+        let show_chart = self.get_show_chart_mut();
+        ui.horizontal_wrapped(|ui| {
+            for (i, result) in results.iter().enumerate() {
+                let label = RichText::new(format!("#{}", i + 1)).color(state_color(&result.state));
+                ui.selectable_value(&mut selected, i, label)
+                    .on_hover_text(&result.sql);
+            }
+            ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                ui.selectable_value(show_chart, true, "Chart");
+                ui.selectable_value(show_chart, false, "Table");
+            });
+        });
+        ui.separator();
+        *self.get_selected_result_mut() = selected;
+
+        let result = &results[selected];
+        draw_state_line(ui, result);
+        draw_scans(ui, &result.scans);
+        if result.columns.is_empty() {
+            return;
+        }
+        let show_chart = *self.get_show_chart_mut();
+        ui.push_id(selected, |ui| {
+            if show_chart {
+                let mut settings = self.chart_settings(&result.sql);
+                settings.fill_defaults(result);
+                let shown = settings.clone();
+                draw_chart(ui, result, &mut settings);
+                if settings != shown {
+                    self.set_chart_settings(&result.sql, settings);
+                }
+            } else {
+                egui::ScrollArea::horizontal().show(ui, |ui| draw_table(ui, result));
+            }
+        });
+    }
+}
+
+fn state_color(state: &StatementState) -> Color32 {
+    match state {
+        StatementState::Pending | StatementState::Skipped => Color32::GRAY,
+        StatementState::Queued { .. } => Color32::LIGHT_YELLOW,
+        StatementState::Running => Color32::LIGHT_BLUE,
+        StatementState::Done { .. } => Color32::LIGHT_GREEN,
+        StatementState::Failed { .. } => Color32::LIGHT_RED,
+        StatementState::Limited { .. } => Color32::GOLD,
+    }
+}
+
+fn draw_state_line(ui: &mut Ui, result: &StatementResult) {
+    ui.horizontal(|ui| match &result.state {
+        StatementState::Pending => {
+            ui.weak("pending");
+        }
+        StatementState::Queued { position } => {
+            ui.spinner();
+            ui.label(format!("queued, position {position}"));
+        }
+        StatementState::Running => {
+            ui.spinner();
+            ui.label(format!("running, {} rows received", result.rows.len()));
+        }
+        StatementState::Done {
+            row_count,
+            duration_ms,
+            cut_short: None,
+        } => {
+            ui.label(format!("{row_count} rows in {duration_ms} ms"));
+        }
+        StatementState::Done {
+            row_count,
+            duration_ms,
+            cut_short: Some(limit),
+        } => {
+            let reached = match limit {
+                Limit::Timeout => "timeout",
+                Limit::ResultRows => "row limit",
+                Limit::ResultBytes => "size limit",
+                _ => "limit",
+            };
+            ui.label(
+                RichText::new(format!(
+                    "showing first {row_count} rows ({reached} reached after {duration_ms} ms)"
+                ))
+                .color(Color32::GOLD),
+            );
+        }
+        StatementState::Failed { duration_ms, error } => {
+            ui.label(
+                RichText::new(format!("failed after {duration_ms} ms: {error}"))
+                    .color(Color32::LIGHT_RED),
+            );
+        }
+        StatementState::Limited { error, .. } => {
+            ui.label(RichText::new(format!("refused: {error}")).color(Color32::GOLD));
+        }
+        StatementState::Skipped => {
+            ui.weak("skipped, an earlier statement failed");
+        }
+    });
+}
+
+/// Files and partitions read of each table over a directory, against the ones below it.
+fn draw_scans(ui: &mut Ui, scans: &[TableScan]) {
+    if scans.is_empty() {
+        return;
+    }
+    ui.horizontal_wrapped(|ui| {
+        for scan in scans {
+            let mut text = format!(
+                "{}: {} of {} files",
+                scan.table, scan.files_scanned, scan.files_total
+            );
+            if scan.partitions_total > 0 {
+                text.push_str(&format!(
+                    ", {} of {} partitions",
+                    scan.partitions_scanned, scan.partitions_total
+                ));
+            }
+            ui.weak(text).on_hover_text(
+                "files read by the statement, partitions ruled out by its filters are skipped",
+            );
+        }
+    });
+}
+
+fn draw_table(ui: &mut Ui, result: &StatementResult) {
+    TableBuilder::new(ui)
+        .striped(true)
+        .resizable(true)
+        .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+        .columns(
+            Column::auto().at_least(60.0).clip(true),
+            result.columns.len(),
+        )
+        .header(20.0, |mut header| {
+            for column in result.columns.iter() {
+                header.col(|ui| {
+                    ui.strong(&column.name).on_hover_text(&column.data_type);
+                });
+            }
+        })
+        .body(|body| {
+            body.rows(18.0, result.rows.len(), |mut row| {
+                let cells = &result.rows[row.index()].cells;
+                for cell in cells.iter() {
+                    row.col(|ui| {
+                        if cell.is_null {
+                            ui.weak(&cell.text);
+                        } else {
+                            ui.label(&cell.text);
+                        }
+                    });
+                }
+            });
+        });
+}
@@ -0,0 +1,429 @@
+//! SQL code editor: a multiline `TextEdit` with syntax highlighting, line numbers, bracket matching,
+//! underlined diagnostics and a completion popup.
+use std::hash::Hash;
+use std::ops::Range;
+
+use egui::text::{CCursor, CCursorRange, LayoutJob};
+use egui::text_edit::TextEditState;
+use egui::{
+    Color32, FontId, Id, Key, Modifiers, Rect, Response, Stroke, TextEdit, TextFormat, TextStyle,
+    Ui,
+};
+
+use crate::sql::{self, TokenKind};
+
+/// An entry of the completion popup.
+pub struct Suggestion {
+    pub text: String,
+    /// Shown dimmed next to the text, e.g. the kind of the suggestion.
+    pub hint: String,
+}
+
+/// A range of the text to underline, `message` is shown when hovering it.
+pub struct Marker {
+    pub chars: Range<usize>,
+    pub message: String,
+    pub color: Color32,
+}
+
+pub struct CodeEditor<'a> {
+    text: &'a mut String,
+    id: Id,
+    desired_rows: usize,
+    suggestions: &'a [Suggestion],
+    open_suggestions: bool,
+    markers: &'a [Marker],
+}
+
+pub struct CodeEditorOutput {
+    pub response: Response,
+    /// Selected characters, an empty range if there is only a cursor.
+    pub selection: Option<Range<usize>>,
+    /// Ctrl+Enter (Cmd+Enter on mac) was pressed while the editor had focus.
+    pub run_requested: bool,
+    /// Ctrl+Space was pressed or a `.` typed, suggestions for the cursor position should be fetched.
+    pub complete_requested: bool,
+}
+
+impl<'a> CodeEditor<'a> {
+    pub fn new(text: &'a mut String, id_source: impl Hash) -> Self {
+        Self {
+            text,
+            id: Id::new(id_source),
+            desired_rows: 8,
+            suggestions: &[],
+            open_suggestions: false,
+            markers: &[],
+        }
+    }
+
+    pub fn desired_rows(mut self, desired_rows: usize) -> Self {
+        self.desired_rows = desired_rows;
+        self
+    }
+
+    /// Suggestions for the word under the cursor, the popup opens when `open` is set.
+    pub fn suggestions(mut self, suggestions: &'a [Suggestion], open: bool) -> Self {
+        self.suggestions = suggestions;
+        self.open_suggestions = open;
+        self
+    }
+
+    /// Underlines, e.g. of errors; they have to refer to the current text.
+    pub fn markers(mut self, markers: &'a [Marker]) -> Self {
+        self.markers = markers;
+        self
+    }
+
+    pub fn show(self, ui: &mut Ui) -> CodeEditorOutput {
+        let Self {
+            text,
+            id,
+            desired_rows,
+            suggestions,
+            open_suggestions,
+            markers,
+        } = self;
+        let popup = SuggestionPopup::new(id, suggestions);
+        let has_focus = ui.memory(|m| m.has_focus(id));
+
+        // consume the shortcuts before the TextEdit turns them into text
+        let run_requested =
+            has_focus && ui.input_mut(|i| i.consume_key(Modifiers::COMMAND, Key::Enter));
+        let mut complete_requested =
+            has_focus && ui.input_mut(|i| i.consume_key(Modifiers::CTRL, Key::Space));
+        if open_suggestions {
+            popup.open(ui, text);
+        }
+        if has_focus {
+            popup.handle_keys(ui, text);
+        }
+
+        let brackets = selection(ui, id)
+            .filter(|s| s.is_empty())
+            .and_then(|s| sql::matching_bracket(text, sql::char_to_byte(text, s.start)));
+        let underlines = markers
+            .iter()
+            .map(|m| {
+                let start = sql::char_to_byte(text, m.chars.start);
+                (start..sql::char_to_byte(text, m.chars.end), m.color)
+            })
+            .collect::<Vec<_>>();
+        let font_id = TextStyle::Monospace.resolve(ui.style());
+        let mut layouter = |ui: &Ui, src: &str, _wrap_width: f32| {
+            let dark_mode = ui.visuals().dark_mode;
+            let job = highlight(src, font_id.clone(), dark_mode, brackets, &underlines);
+            ui.fonts(|f| f.layout_job(job))
+        };
+
+        let line_count = text.split('\n').count();
+        let digits = line_count.to_string().len();
+        let line_numbers = (1..=line_count)
+            .map(|n| format!("{n:>digits$}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let (response, cursor_rect) = egui::ScrollArea::both()
+            .id_source(id.with("scroll"))
+            .auto_shrink(false)
+            .show(ui, |ui| {
+                ui.horizontal_top(|ui| {
+                    ui.spacing_mut().item_spacing.x = 0.0;
+                    TextEdit::multiline(&mut line_numbers.as_str())
+                        .font(TextStyle::Monospace)
+                        .interactive(false)
+                        .frame(false)
+                        .desired_rows(desired_rows)
+                        .desired_width(digits as f32 * font_id.size * 0.6 + 8.0)
+                        .text_color(ui.visuals().weak_text_color())
+                        .show(ui);
+                    let output = TextEdit::multiline(text)
+                        .id(id)
+                        .code_editor()
+                        .desired_rows(desired_rows)
+                        .desired_width(f32::INFINITY)
+                        .layouter(&mut layouter)
+                        .show(ui);
+                    if let Some(pos) = output.response.hover_pos() {
+                        let index = output
+                            .galley
+                            .cursor_from_pos(pos - output.galley_pos)
+                            .ccursor
+                            .index;
+                        if let Some(marker) = markers.iter().find(|m| m.chars.contains(&index)) {
+                            egui::show_tooltip_at_pointer(ui.ctx(), id.with("marker"), |ui| {
+                                ui.label(&marker.message);
+                            });
+                        }
+                    }
+                    let cursor_rect = output.cursor_range.map(|c| {
+                        output
+                            .galley
+                            .pos_from_cursor(&c.primary)
+                            .translate(output.galley_pos.to_vec2())
+                    });
+                    (output.response, cursor_rect)
+                })
+                .inner
+            })
+            .inner;
+
+        let selection = selection(ui, id);
+        if response.changed() {
+            let typed_dot = selection
+                .as_ref()
+                .filter(|s| s.is_empty() && s.start > 0)
+                .is_some_and(|s| text.chars().nth(s.start - 1) == Some('.'));
+            complete_requested |= typed_dot;
+        }
+        popup.show(ui, text, cursor_rect);
+        if response.lost_focus() {
+            popup.close(ui);
+        }
+
+        CodeEditorOutput {
+            response,
+            selection,
+            run_requested,
+            complete_requested,
+        }
+    }
+}
+
+/// Completion popup state, kept in egui memory like the popup of `DropDownBox`.
+struct SuggestionPopup<'a> {
+    editor_id: Id,
+    popup_id: Id,
+    suggestions: &'a [Suggestion],
+}
+
+impl<'a> SuggestionPopup<'a> {
+    fn new(editor_id: Id, suggestions: &'a [Suggestion]) -> Self {
+        Self {
+            editor_id,
+            popup_id: editor_id.with("suggestions"),
+            suggestions,
+        }
+    }
+
+    fn is_open(&self, ui: &Ui) -> bool {
+        ui.memory(|m| m.is_popup_open(self.popup_id))
+    }
+
+    /// Opens the popup for the word currently under the cursor.
+    fn open(&self, ui: &Ui, text: &str) {
+        let Some(cursor) = selection(ui, self.editor_id).map(|s| s.end) else {
+            return;
+        };
+        let anchor = word_start(text, cursor);
+        ui.data_mut(|d| {
+            d.insert_temp(self.popup_id.with("anchor"), anchor);
+            d.insert_temp(self.popup_id.with("selected"), 0usize);
+        });
+        ui.memory_mut(|m| m.open_popup(self.popup_id));
+    }
+
+    fn close(&self, ui: &Ui) {
+        if self.is_open(ui) {
+            ui.memory_mut(|m| m.close_popup());
+        }
+    }
+
+    /// The word being completed, `None` once the cursor left it.
+    fn word(&self, ui: &Ui, text: &str) -> Option<Range<usize>> {
+        let cursor = selection(ui, self.editor_id)?.end;
+        let anchor = ui.data(|d| d.get_temp::<usize>(self.popup_id.with("anchor")))?;
+        (word_start(text, cursor) == anchor).then_some(anchor..cursor)
+    }
+
+    fn visible(&self, ui: &Ui, text: &str) -> Vec<&'a Suggestion> {
+        let Some(word) = self.word(ui, text) else {
+            return vec![];
+        };
+        let prefix = text
+            .chars()
+            .skip(word.start)
+            .take(word.len())
+            .collect::<String>()
+            .to_lowercase();
+        self.suggestions
+            .iter()
+            .filter(|s| s.text.to_lowercase().starts_with(&prefix))
+            .collect()
+    }
+
+    fn handle_keys(&self, ui: &Ui, text: &mut String) {
+        if !self.is_open(ui) {
+            return;
+        }
+        let visible = self.visible(ui, text);
+        if visible.is_empty() {
+            return self.close(ui);
+        }
+
+        let selected_id = self.popup_id.with("selected");
+        let mut selected = ui
+            .data(|d| d.get_temp::<usize>(selected_id))
+            .unwrap_or_default()
+            .min(visible.len() - 1);
+        let (mut accept, mut close) = (false, false);
+        ui.input_mut(|i| {
+            if i.consume_key(Modifiers::NONE, Key::ArrowDown) {
+                selected = (selected + 1) % visible.len();
+            }
+            if i.consume_key(Modifiers::NONE, Key::ArrowUp) {
+                selected = (selected + visible.len() - 1) % visible.len();
+            }
+            accept = i.consume_key(Modifiers::NONE, Key::Enter)
+                || i.consume_key(Modifiers::NONE, Key::Tab);
+            close = i.consume_key(Modifiers::NONE, Key::Escape);
+        });
+        ui.data_mut(|d| d.insert_temp(selected_id, selected));
+
+        if accept {
+            self.accept(ui, text, visible[selected]);
+        } else if close {
+            self.close(ui);
+        }
+    }
+
+    /// Replaces the word under the cursor with `suggestion`.
+    fn accept(&self, ui: &Ui, text: &mut String, suggestion: &Suggestion) {
+        let Some(word) = self.word(ui, text) else {
+            return self.close(ui);
+        };
+        let start = sql::char_to_byte(text, word.start);
+        let end = sql::char_to_byte(text, word.end);
+        text.replace_range(start..end, &suggestion.text);
+
+        let cursor = CCursor::new(word.start + suggestion.text.chars().count());
+        let mut state = TextEditState::load(ui.ctx(), self.editor_id).unwrap_or_default();
+        state.cursor.set_char_range(Some(CCursorRange::one(cursor)));
+        state.store(ui.ctx(), self.editor_id);
+        ui.memory_mut(|m| m.request_focus(self.editor_id));
+        self.close(ui);
+    }
+
+    fn show(&self, ui: &Ui, text: &mut String, cursor_rect: Option<Rect>) {
+        let Some(cursor_rect) = cursor_rect.filter(|_| self.is_open(ui)) else {
+            return;
+        };
+        let visible = self.visible(ui, text);
+        if visible.is_empty() {
+            return self.close(ui);
+        }
+        let selected = ui
+            .data(|d| d.get_temp::<usize>(self.popup_id.with("selected")))
+            .unwrap_or_default();
+
+        let mut clicked = None;
+        egui::Area::new(self.popup_id)
+            .order(egui::Order::Foreground)
+            .fixed_pos(cursor_rect.left_bottom())
+            .show(ui.ctx(), |ui| {
+                egui::Frame::popup(ui.style()).show(ui, |ui| {
+                    egui::ScrollArea::vertical()
+                        .max_height(200.0)
+                        .show(ui, |ui| {
+                            for (i, suggestion) in visible.iter().enumerate() {
+                                ui.horizontal(|ui| {
+                                    let r = ui.selectable_label(i == selected, &suggestion.text);
+                                    ui.weak(&suggestion.hint);
+                                    if i == selected {
+                                        r.scroll_to_me(None);
+                                    }
+                                    if r.clicked() {
+                                        clicked = Some(*suggestion);
+                                    }
+                                });
+                            }
+                        });
+                });
+            });
+        if let Some(suggestion) = clicked {
+            self.accept(ui, text, suggestion);
+        }
+    }
+}
+
+fn selection(ui: &Ui, id: Id) -> Option<Range<usize>> {
+    let range = TextEditState::load(ui.ctx(), id)?.cursor.char_range()?;
+    let (a, b) = (range.primary.index, range.secondary.index);
+    Some(a.min(b)..a.max(b))
+}
+
+/// Char index where the identifier ending at `cursor` starts.
+fn word_start(text: &str, cursor: usize) -> usize {
+    let before: Vec<char> = text.chars().take(cursor).collect();
+    let word_len = before
+        .iter()
+        .rev()
+        .take_while(|c| c.is_alphanumeric() || **c == '_')
+        .count();
+    before.len() - word_len
+}
+
+fn token_color(kind: TokenKind, dark_mode: bool) -> Option<Color32> {
+    let color = match (kind, dark_mode) {
+        (TokenKind::Keyword, true) => Color32::from_rgb(0xc6, 0x78, 0xdd),
+        (TokenKind::Keyword, false) => Color32::from_rgb(0x8b, 0x1c, 0xab),
+        (TokenKind::Function, true) => Color32::from_rgb(0x61, 0xaf, 0xef),
+        (TokenKind::Function, false) => Color32::from_rgb(0x1a, 0x5f, 0xb4),
+        (TokenKind::Str, true) => Color32::from_rgb(0x98, 0xc3, 0x79),
+        (TokenKind::Str, false) => Color32::from_rgb(0x2e, 0x7d, 0x32),
+        (TokenKind::QuotedIdent, true) => Color32::from_rgb(0xe5, 0xc0, 0x7b),
+        (TokenKind::QuotedIdent, false) => Color32::from_rgb(0x9a, 0x67, 0x00),
+        (TokenKind::Number, true) => Color32::from_rgb(0xd1, 0x9a, 0x66),
+        (TokenKind::Number, false) => Color32::from_rgb(0xb3, 0x4d, 0x00),
+        (TokenKind::Comment, _) => Color32::GRAY,
+        _ => return None,
+    };
+    Some(color)
+}
+
+fn highlight(
+    src: &str,
+    font_id: FontId,
+    dark_mode: bool,
+    brackets: Option<(usize, usize)>,
+    underlines: &[(Range<usize>, Color32)],
+) -> LayoutJob {
+    let default_color = if dark_mode {
+        Color32::from_gray(210)
+    } else {
+        Color32::from_gray(30)
+    };
+    let bracket_bg = if dark_mode {
+        Color32::from_gray(80)
+    } else {
+        Color32::from_gray(200)
+    };
+    // bracket offsets come from the previous frame, only trust them if they still point at brackets
+    let is_matched_bracket = |offset: usize| {
+        brackets.is_some_and(|(a, b)| offset == a || offset == b)
+            && matches!(src.as_bytes().get(offset), Some(b'(' | b')' | b'[' | b']'))
+    };
+
+    let mut job = LayoutJob::default();
+    for token in sql::tokenize(src) {
+        let mut format = TextFormat::simple(
+            font_id.clone(),
+            token_color(token.kind, dark_mode).unwrap_or(default_color),
+        );
+        if token.kind == TokenKind::Comment {
+            format.italics = true;
+        }
+        if token.kind == TokenKind::Bracket && is_matched_bracket(token.range.start) {
+            format.background = bracket_bg;
+        }
+        let underline = underlines
+            .iter()
+            .find(|(range, _)| range.start < token.range.end && token.range.start < range.end);
+        if let Some((_, color)) = underline {
+            format.underline = Stroke::new(1.5, *color);
+        }
+        job.append(&src[token.range], 0.0, format);
+    }
+
+    job
+}
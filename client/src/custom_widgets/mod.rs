@@ -1,3 +1,5 @@
 mod buttons;
+pub mod code_editor;
 pub mod searchable_dropdown;
+pub mod sparkline;
 pub mod toggle_ui;
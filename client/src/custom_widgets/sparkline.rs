@@ -0,0 +1,28 @@
+use egui::{Color32, Response, Sense, Shape, Stroke, Ui, Vec2};
+
+/// A small line chart of `values` without axes, scaled from zero to the largest value.
+pub fn sparkline(ui: &mut Ui, values: &[f64], size: Vec2, color: Color32) -> Response {
+    let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+    if !ui.is_rect_visible(rect) {
+        return response;
+    }
+
+    let painter = ui.painter();
+    painter.rect_filled(rect, 2.0, ui.visuals().extreme_bg_color);
+    if values.len() < 2 {
+        return response;
+    }
+    let max = values.iter().copied().fold(0.0, f64::max);
+    let max = if max > 0.0 { max } else { 1.0 };
+    let step = rect.width() / (values.len() - 1) as f32;
+    let points = values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| {
+            let y = rect.bottom() - (value / max) as f32 * (rect.height() - 2.0) - 1.0;
+            egui::pos2(rect.left() + i as f32 * step, y)
+        })
+        .collect();
+    painter.add(Shape::line(points, Stroke::new(1.5, color)));
+    response
+}
@@ -0,0 +1,70 @@
+//! Saving and opening files through the browser.
+use js_sys::Array;
+use log::error;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Blob, BlobPropertyBag, HtmlAnchorElement, HtmlInputElement, Url};
+
+use crate::spawn_local;
+
+/// Offers `contents` as a download called `file_name`.
+pub fn download(file_name: &str, mime_type: &str, contents: &str) {
+    if let Err(e) = try_download(file_name, mime_type, contents) {
+        error!("failed to download {file_name}: {e:?}");
+    }
+}
+
+fn try_download(file_name: &str, mime_type: &str, contents: &str) -> Result<(), JsValue> {
+    let document = web_sys::window()
+        .and_then(|w| w.document())
+        .ok_or("no document")?;
+    let mut options = BlobPropertyBag::new();
+    options.type_(mime_type);
+    let blob = Blob::new_with_str_sequence_and_options(
+        &Array::of1(&JsValue::from_str(contents)),
+        &options,
+    )?;
+    let url = Url::create_object_url_with_blob(&blob)?;
+    let anchor = document
+        .create_element("a")?
+        .dyn_into::<HtmlAnchorElement>()?;
+    anchor.set_href(&url);
+    anchor.set_download(file_name);
+    anchor.click();
+    Url::revoke_object_url(&url)
+}
+
+/// Lets the user pick a file and calls `on_load` with its text, nothing happens if the dialog
+/// is cancelled.
+pub fn open(accept: &str, on_load: impl FnOnce(String) + 'static) {
+    if let Err(e) = try_open(accept, on_load) {
+        error!("failed to open a file: {e:?}");
+    }
+}
+
+fn try_open(accept: &str, on_load: impl FnOnce(String) + 'static) -> Result<(), JsValue> {
+    let document = web_sys::window()
+        .and_then(|w| w.document())
+        .ok_or("no document")?;
+    let input = document
+        .create_element("input")?
+        .dyn_into::<HtmlInputElement>()?;
+    input.set_type("file");
+    input.set_accept(accept);
+
+    let picked = input.clone();
+    let on_change = wasm_bindgen::closure::Closure::once_into_js(move || {
+        let Some(file) = picked.files().and_then(|files| files.get(0)) else {
+            return;
+        };
+        spawn_local(async move {
+            match JsFuture::from(file.text()).await {
+                Ok(text) => on_load(text.as_string().unwrap_or_default()),
+                Err(e) => error!("failed to read {}: {e:?}", file.name()),
+            }
+        });
+    });
+    input.set_onchange(Some(on_change.unchecked_ref()));
+    input.click();
+    Ok(())
+}
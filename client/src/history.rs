@@ -0,0 +1,57 @@
+//! Statements executed by this client, kept in eframe storage.
+use std::collections::VecDeque;
+
+/// Older entries are dropped beyond this.
+const MAX_ENTRIES: usize = 1000;
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct HistoryEntry {
+    pub sql: String,
+    pub context: Option<String>,
+    /// milliseconds since the epoch
+    pub started_at_ms: u64,
+    pub duration_ms: u64,
+    pub row_count: u64,
+    pub error: Option<String>,
+}
+
+impl From<proto_gen::query::HistoryEntry> for HistoryEntry {
+    fn from(e: proto_gen::query::HistoryEntry) -> Self {
+        Self {
+            sql: e.sql,
+            context: Some(e.context),
+            started_at_ms: e.started_at_ms,
+            duration_ms: e.duration_ms,
+            row_count: e.row_count,
+            error: (!e.error.is_empty()).then_some(e.error),
+        }
+    }
+}
+
+/// Newest last.
+#[derive(Clone, Default, serde::Deserialize, serde::Serialize)]
+pub struct History {
+    entries: VecDeque<HistoryEntry>,
+}
+
+impl History {
+    pub fn record(&mut self, entry: HistoryEntry) {
+        if self.entries.len() == MAX_ENTRIES {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry);
+    }
+
+    /// Newest first, entries whose sql contains `search` ignoring case.
+    pub fn search<'a>(&'a self, search: &str) -> impl Iterator<Item = &'a HistoryEntry> {
+        let search = search.to_lowercase();
+        self.entries
+            .iter()
+            .rev()
+            .filter(move |e| e.sql.to_lowercase().contains(&search))
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
@@ -0,0 +1,59 @@
+//! What the log viewer of the monitor asks the server for, kept in eframe storage.
+use proto_gen::logs::{Field, Level, LogEvent, LogFilter};
+
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub struct LogView {
+    /// a `Level` of the protocol
+    pub min_level: i32,
+    /// target prefix
+    pub target: String,
+    /// `name=value` pairs separated by commas, each must match a span field
+    pub span_fields: String,
+    /// shown events must contain it, ignoring case
+    #[serde(skip)]
+    pub search: String,
+    /// keep scrolled to the newest event
+    pub follow: bool,
+}
+
+impl Default for LogView {
+    fn default() -> Self {
+        Self {
+            min_level: Level::Info.into(),
+            target: "".to_string(),
+            span_fields: "".to_string(),
+            search: "".to_string(),
+            follow: true,
+        }
+    }
+}
+
+impl LogView {
+    /// The part of the view filtered by the server.
+    pub fn filter(&self) -> LogFilter {
+        LogFilter {
+            min_level: self.min_level,
+            target: self.target.trim().to_string(),
+            span_fields: self
+                .span_fields
+                .split(',')
+                .filter_map(|pair| pair.split_once('='))
+                .map(|(name, value)| Field {
+                    name: name.trim().to_string(),
+                    value: value.trim().to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    pub fn matches(&self, event: &LogEvent) -> bool {
+        if self.search.is_empty() {
+            return true;
+        }
+        let search = self.search.to_lowercase();
+        [&event.message, &event.target]
+            .into_iter()
+            .chain(event.fields.iter().map(|f| &f.value))
+            .any(|text| text.to_lowercase().contains(&search))
+    }
+}
@@ -7,12 +7,19 @@ pub use wasm_bindgen_futures::spawn_local;
 
 use crate::console_window::set_current_host;
 
+mod chart;
 pub mod client;
 mod console_window;
 mod custom_widgets;
+mod file_io;
+mod history;
+mod logs;
 mod rpc;
+mod saved;
+mod sql;
 mod status;
 mod template;
+mod time;
 
 fn main() {
     eframe::WebLogger::init(log::LevelFilter::Debug).ok();
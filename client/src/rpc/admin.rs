@@ -0,0 +1,216 @@
+use std::rc::Rc;
+
+use log::error;
+use prost::Message;
+use tokio::sync::mpsc::Sender;
+
+use proto_gen::admin::{
+    admin_service::Service, AdminService, AuditEntries, CancelQuery, Disconnect, GetLogFilter,
+    ListSessions, QueryAudit, SessionList, SetLogFilter,
+};
+
+use crate::RefCell;
+
+use super::{send, RpcCaller};
+
+/// Settings and sessions of the server itself, shared by every client.
+#[derive(Default)]
+pub struct AdminRpc {
+    sender: Option<Rc<RefCell<Sender<Vec<u8>>>>>,
+    request_id: u64,
+    /// the requests waiting for a reply, by kind
+    log_filter_request: Option<u64>,
+    sessions_request: Option<u64>,
+    action_request: Option<u64>,
+    audit_request: Option<u64>,
+    /// the log filter in effect on the server, unknown until asked
+    log_filter: Option<String>,
+    /// why the last log filter was rejected
+    log_filter_error: Option<String>,
+    sessions: Option<SessionList>,
+    /// why the last cancel or disconnect failed
+    action_error: Option<String>,
+    /// result of the last audit log search
+    audit: Option<AuditEntries>,
+}
+
+impl AdminRpc {
+    pub fn get_log_filter(&mut self) {
+        let request_id = self.next_request_id();
+        self.log_filter_request = self.request(
+            Service::GetLogFilterMsg(GetLogFilter { request_id }),
+            "get log filter",
+        );
+    }
+
+    /// Replaces the server log filter, `directive` is in the syntax of `RUST_LOG`.
+    pub fn set_log_filter(&mut self, directive: String) {
+        let request_id = self.next_request_id();
+        self.log_filter_request = self.request(
+            Service::SetLogFilterMsg(SetLogFilter {
+                request_id,
+                directive,
+            }),
+            "set log filter",
+        );
+    }
+
+    pub fn list_sessions(&mut self) {
+        let request_id = self.next_request_id();
+        self.sessions_request = self.request(
+            Service::ListSessionsMsg(ListSessions { request_id }),
+            "list sessions",
+        );
+    }
+
+    pub fn cancel_query(&mut self, query_id: u64) {
+        let request_id = self.next_request_id();
+        self.action_error = None;
+        self.action_request = self.request(
+            Service::CancelQueryMsg(CancelQuery {
+                request_id,
+                query_id,
+            }),
+            "cancel query",
+        );
+    }
+
+    pub fn disconnect(&mut self, session_id: u64) {
+        let request_id = self.next_request_id();
+        self.action_error = None;
+        self.action_request = self.request(
+            Service::DisconnectMsg(Disconnect {
+                request_id,
+                session_id,
+            }),
+            "disconnect session",
+        );
+    }
+
+    /// Searches the audit log, the request id of `query` is replaced.
+    pub fn query_audit(&mut self, query: QueryAudit) {
+        let request_id = self.next_request_id();
+        self.audit_request = self.request(
+            Service::QueryAuditMsg(QueryAudit {
+                request_id,
+                ..query
+            }),
+            "query audit log",
+        );
+    }
+
+    fn next_request_id(&mut self) -> u64 {
+        self.request_id += 1;
+        self.request_id
+    }
+
+    /// Sends `service`, returns its request id unless not connected.
+    fn request(&self, service: Service, what: &'static str) -> Option<u64> {
+        let Some(s) = self.sender.as_ref() else {
+            error!("not connected, cannot {what}");
+            return None;
+        };
+        let m = AdminService {
+            service: Some(service),
+        }
+        .encode_to_vec();
+        send(s, m, what);
+        Some(self.request_id)
+    }
+
+    pub fn is_waiting(&self) -> bool {
+        self.log_filter_request.is_some()
+            || self.sessions_request.is_some()
+            || self.action_request.is_some()
+            || self.audit_request.is_some()
+    }
+
+    pub fn is_log_filter_pending(&self) -> bool {
+        self.log_filter_request.is_some()
+    }
+
+    pub fn log_filter(&self) -> Option<&str> {
+        self.log_filter.as_deref()
+    }
+
+    pub fn log_filter_error(&self) -> Option<&str> {
+        self.log_filter_error.as_deref()
+    }
+
+    pub fn is_listing_sessions(&self) -> bool {
+        self.sessions_request.is_some()
+    }
+
+    pub fn sessions(&self) -> Option<&SessionList> {
+        self.sessions.as_ref()
+    }
+
+    pub fn action_error(&self) -> Option<&str> {
+        self.action_error.as_deref()
+    }
+
+    pub fn is_searching_audit(&self) -> bool {
+        self.audit_request.is_some()
+    }
+
+    pub fn audit(&self) -> Option<&AuditEntries> {
+        self.audit.as_ref()
+    }
+}
+
+impl RpcCaller for AdminRpc {
+    fn set_sender(&mut self, sender: Sender<Vec<u8>>) {
+        self.sender.replace(Rc::new(RefCell::new(sender)));
+        // another server may be behind the new connection
+        self.log_filter_request = None;
+        self.sessions_request = None;
+        self.action_request = None;
+        self.audit_request = None;
+        self.log_filter = None;
+        self.log_filter_error = None;
+        self.sessions = None;
+        self.action_error = None;
+        self.audit = None;
+    }
+
+    fn handle(&mut self, bytes: &[u8]) -> bool {
+        let Ok(AdminService {
+            service: Some(service),
+        }) = AdminService::decode(bytes)
+        else {
+            return false;
+        };
+
+        match service {
+            Service::LogFilterMsg(m) => {
+                if Some(m.request_id) == self.log_filter_request {
+                    self.log_filter = Some(m.directive);
+                    self.log_filter_error = (!m.error.is_empty()).then_some(m.error);
+                    self.log_filter_request = None;
+                }
+            }
+            Service::SessionsMsg(m) => {
+                if Some(m.request_id) == self.sessions_request {
+                    self.sessions = Some(m);
+                    self.sessions_request = None;
+                }
+            }
+            Service::ResultMsg(m) => {
+                if Some(m.request_id) == self.action_request {
+                    self.action_error = (!m.error.is_empty()).then_some(m.error);
+                    self.action_request = None;
+                }
+            }
+            Service::AuditMsg(m) => {
+                if Some(m.request_id) == self.audit_request {
+                    self.audit = Some(m);
+                    self.audit_request = None;
+                }
+            }
+            _ => {
+                error!("unexpected admin request from server, ignoring...");
+            }
+        }
+        true
+    }
+}
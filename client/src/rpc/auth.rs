@@ -0,0 +1,137 @@
+use std::rc::Rc;
+
+use log::{error, info};
+use prost::Message;
+use tokio::sync::mpsc::Sender;
+
+use proto_gen::auth::{auth_service::Service, AuthService, Login, LoginResult};
+
+use crate::RefCell;
+
+use super::{send, RpcCaller};
+
+/// Logs in on every connection, servers without authentication accept any login.
+#[derive(Default)]
+pub struct AuthRpc {
+    sender: Option<Rc<RefCell<Sender<Vec<u8>>>>>,
+    /// sent first on every connection, the session token once one was issued
+    credentials: Option<Login>,
+    /// a login was sent and not answered yet
+    pending: bool,
+    /// who the server knows this client as
+    identity: Option<LoginResult>,
+    /// why the last login was refused
+    error: Option<String>,
+    /// a session token to keep for the next start, `Some(None)` to forget it
+    token_change: Option<Option<String>>,
+}
+
+impl AuthRpc {
+    /// Logs in with `login` on the next connection.
+    pub fn set_credentials(&mut self, login: Login) {
+        self.credentials = Some(login);
+        self.error = None;
+    }
+
+    /// Resumes the session of an earlier start unless other credentials are set.
+    pub fn restore_token(&mut self, token: &str) {
+        if self.credentials.is_none() {
+            self.credentials = Some(Login {
+                token: token.to_string(),
+                ..Default::default()
+            });
+        }
+    }
+
+    /// Forgets the credentials, the connection has to be closed by the caller.
+    pub fn log_out(&mut self) {
+        self.credentials = None;
+        self.identity = None;
+        self.token_change = Some(None);
+    }
+
+    pub fn is_waiting(&self) -> bool {
+        self.pending
+    }
+
+    pub fn identity(&self) -> Option<&LoginResult> {
+        self.identity.as_ref()
+    }
+
+    /// What this client may do in `context`, unknown until the server said who it is.
+    pub fn permissions(&self, context: &str) -> Option<&[String]> {
+        let identity = self.identity.as_ref()?;
+        let permissions = match identity.contexts.get(context) {
+            Some(own) => &own.permissions,
+            None => &identity.permissions,
+        };
+        Some(permissions)
+    }
+
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    pub fn clear_error(&mut self) {
+        self.error = None;
+    }
+
+    pub fn take_token_change(&mut self) -> Option<Option<String>> {
+        self.token_change.take()
+    }
+}
+
+impl RpcCaller for AuthRpc {
+    fn set_sender(&mut self, sender: Sender<Vec<u8>>) {
+        let sender = Rc::new(RefCell::new(sender));
+        self.identity = None;
+        self.pending = false;
+        // queued before any other service sends, the server expects the login first
+        if let Some(login) = self.credentials.clone() {
+            let m = AuthService {
+                service: Some(Service::LoginMsg(login)),
+            }
+            .encode_to_vec();
+            send(&sender, m, "log in");
+            self.pending = true;
+        }
+        self.sender.replace(sender);
+    }
+
+    fn handle(&mut self, bytes: &[u8]) -> bool {
+        let Ok(AuthService {
+            service: Some(service),
+        }) = AuthService::decode(bytes)
+        else {
+            return false;
+        };
+
+        match service {
+            Service::LoginResultMsg(m) => {
+                self.pending = false;
+                if m.ok {
+                    info!("logged in as {} ({})", m.user, m.role);
+                    if !m.session_token.is_empty() {
+                        self.credentials = Some(Login {
+                            token: m.session_token.clone(),
+                            ..Default::default()
+                        });
+                        self.token_change = Some(Some(m.session_token.clone()));
+                    }
+                    self.error = None;
+                    self.identity = Some(m);
+                } else {
+                    // a refused session token is not worth retrying either
+                    self.credentials = None;
+                    self.identity = None;
+                    self.error = Some(m.error);
+                    self.token_change = Some(None);
+                }
+            }
+            _ => {
+                error!("unexpected auth request from server, ignoring...");
+            }
+        }
+        true
+    }
+}
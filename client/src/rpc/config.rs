@@ -0,0 +1,87 @@
+use std::rc::Rc;
+
+use log::error;
+use prost::Message;
+use tokio::sync::mpsc::Sender;
+
+use proto_gen::config::{config_service::Service, Config, ConfigService, SetConfig};
+
+use crate::RefCell;
+
+use super::{send, RpcCaller};
+
+/// Settings of this connection, applied live by the server.
+#[derive(Default)]
+pub struct ConfigRpc {
+    sender: Option<Rc<RefCell<Sender<Vec<u8>>>>>,
+    request_id: u64,
+    waiting: bool,
+    /// what the server acknowledged on the current connection
+    applied: Option<Config>,
+    /// why the last config was rejected
+    error: Option<String>,
+}
+
+impl ConfigRpc {
+    pub fn set_config(&mut self, config: Config) {
+        let Some(s) = self.sender.as_ref() else {
+            error!("not connected, cannot set config");
+            return;
+        };
+        self.request_id += 1;
+        self.waiting = true;
+        let m = ConfigService {
+            service: Some(Service::SetConfigMsg(SetConfig {
+                request_id: self.request_id,
+                config: Some(config),
+            })),
+        }
+        .encode_to_vec();
+        send(s, m, "set config");
+    }
+
+    pub fn is_waiting(&self) -> bool {
+        self.waiting
+    }
+
+    pub fn applied(&self) -> Option<&Config> {
+        self.applied.as_ref()
+    }
+
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
+
+impl RpcCaller for ConfigRpc {
+    fn set_sender(&mut self, sender: Sender<Vec<u8>>) {
+        self.sender.replace(Rc::new(RefCell::new(sender)));
+        // a new connection starts with the server defaults
+        self.applied = None;
+        self.error = None;
+        self.waiting = false;
+    }
+
+    fn handle(&mut self, bytes: &[u8]) -> bool {
+        let Ok(ConfigService {
+            service: Some(service),
+        }) = ConfigService::decode(bytes)
+        else {
+            return false;
+        };
+
+        match service {
+            Service::ConfigAckMsg(m) => {
+                if m.request_id == self.request_id {
+                    self.applied = m.config;
+                    self.error = (!m.error.is_empty()).then_some(m.error);
+                    self.waiting = false;
+                }
+            }
+            _ => {
+                error!("unexpected config request from server, ignoring...");
+            }
+        }
+        true
+    }
+}
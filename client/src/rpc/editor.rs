@@ -0,0 +1,123 @@
+use std::rc::Rc;
+
+use log::error;
+use prost::Message;
+use tokio::sync::mpsc::Sender;
+
+use proto_gen::query::{
+    query_service::Service, Candidate, CompleteSql, Diagnostic, QueryService, ValidateSql,
+};
+
+use crate::RefCell;
+
+use super::{send, RpcCaller};
+
+/// Editor assistance: completion candidates for the text around the cursor and diagnostics
+/// of the whole text.
+#[derive(Default)]
+pub struct EditorRpc {
+    sender: Option<Rc<RefCell<Sender<Vec<u8>>>>>,
+    completion_request_id: u64,
+    candidates: Vec<Candidate>,
+    fresh_candidates: bool,
+    waiting_candidates: bool,
+    validation_request_id: u64,
+    diagnostics: Vec<Diagnostic>,
+    waiting_diagnostics: bool,
+}
+
+impl EditorRpc {
+    /// Asks for completions at `cursor`, a byte offset into `sql`.
+    pub fn complete(&mut self, context: Option<String>, sql: String, cursor: usize) {
+        let Some(s) = self.sender.as_ref() else {
+            error!("not connected, cannot complete");
+            return;
+        };
+        self.completion_request_id += 1;
+        let m = QueryService {
+            service: Some(Service::CompleteMsg(CompleteSql {
+                request_id: self.completion_request_id,
+                context: context.unwrap_or_default(),
+                sql,
+                cursor: cursor as u32,
+            })),
+        }
+        .encode_to_vec();
+        send(s, m, "request completions");
+        self.waiting_candidates = true;
+    }
+
+    /// Asks for parser and planner diagnostics of `sql`, nothing gets executed.
+    pub fn validate(&mut self, context: Option<String>, sql: String) {
+        let Some(s) = self.sender.as_ref() else {
+            return;
+        };
+        self.validation_request_id += 1;
+        let m = QueryService {
+            service: Some(Service::ValidateMsg(ValidateSql {
+                request_id: self.validation_request_id,
+                context: context.unwrap_or_default(),
+                sql,
+            })),
+        }
+        .encode_to_vec();
+        send(s, m, "request validation");
+        self.waiting_diagnostics = true;
+    }
+
+    pub fn is_waiting(&self) -> bool {
+        self.waiting_candidates || self.waiting_diagnostics
+    }
+
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+
+    /// Diagnostics refer to the text they were requested for, drop them once it changed.
+    pub fn clear_diagnostics(&mut self) {
+        self.diagnostics.clear();
+    }
+
+    pub fn candidates(&self) -> &[Candidate] {
+        &self.candidates
+    }
+
+    /// True once after new candidates arrived, the editor opens its popup then.
+    pub fn take_fresh_candidates(&mut self) -> bool {
+        std::mem::take(&mut self.fresh_candidates)
+    }
+}
+
+impl RpcCaller for EditorRpc {
+    fn set_sender(&mut self, sender: Sender<Vec<u8>>) {
+        self.sender.replace(Rc::new(RefCell::new(sender)));
+    }
+
+    fn handle(&mut self, bytes: &[u8]) -> bool {
+        let Ok(QueryService {
+            service: Some(service),
+        }) = QueryService::decode(bytes)
+        else {
+            return false;
+        };
+
+        match service {
+            Service::CompletionsMsg(m) => {
+                // a newer request is in flight, its reply will follow
+                if m.request_id == self.completion_request_id {
+                    self.candidates = m.candidates;
+                    self.fresh_candidates = true;
+                    self.waiting_candidates = false;
+                }
+            }
+            Service::DiagnosticsMsg(m) => {
+                if m.request_id == self.validation_request_id {
+                    self.diagnostics = m.diagnostics;
+                    self.waiting_diagnostics = false;
+                }
+            }
+            _ => return false,
+        }
+        true
+    }
+}
@@ -0,0 +1,84 @@
+use std::rc::Rc;
+
+use log::error;
+use prost::Message;
+use tokio::sync::mpsc::Sender;
+
+use proto_gen::query::{query_service::Service, GetHistory, QueryService};
+
+use crate::history::HistoryEntry;
+use crate::RefCell;
+
+use super::{send, RpcCaller};
+
+/// Statements executed in a context by any client, as recorded by the server.
+#[derive(Default)]
+pub struct HistoryRpc {
+    sender: Option<Rc<RefCell<Sender<Vec<u8>>>>>,
+    request_id: u64,
+    loading: bool,
+    entries: Vec<HistoryEntry>,
+    /// why the server refused the last fetch
+    error: Option<String>,
+}
+
+impl HistoryRpc {
+    pub fn fetch(&mut self, context: Option<String>, search: String, limit: u32) {
+        let Some(s) = self.sender.as_ref() else {
+            error!("not connected, cannot fetch history");
+            return;
+        };
+        self.request_id += 1;
+        self.loading = true;
+        let m = QueryService {
+            service: Some(Service::GetHistoryMsg(GetHistory {
+                request_id: self.request_id,
+                context: context.unwrap_or_default(),
+                search,
+                limit,
+            })),
+        }
+        .encode_to_vec();
+        send(s, m, "fetch history");
+    }
+
+    pub fn is_loading(&self) -> bool {
+        self.loading
+    }
+
+    /// Newest first.
+    pub fn entries(&self) -> &[HistoryEntry] {
+        &self.entries
+    }
+
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
+
+impl RpcCaller for HistoryRpc {
+    fn set_sender(&mut self, sender: Sender<Vec<u8>>) {
+        self.sender.replace(Rc::new(RefCell::new(sender)));
+    }
+
+    fn handle(&mut self, bytes: &[u8]) -> bool {
+        let Ok(QueryService {
+            service: Some(service),
+        }) = QueryService::decode(bytes)
+        else {
+            return false;
+        };
+
+        match service {
+            Service::HistoryMsg(m) => {
+                if m.request_id == self.request_id {
+                    self.entries = m.entries.into_iter().map(HistoryEntry::from).collect();
+                    self.error = (!m.error.is_empty()).then_some(m.error);
+                    self.loading = false;
+                }
+            }
+            _ => return false,
+        }
+        true
+    }
+}
@@ -0,0 +1,129 @@
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+use log::error;
+use prost::Message;
+use tokio::sync::mpsc::Sender;
+
+use proto_gen::logs::{
+    log_service::Service, LogEvent, LogFilter, LogService, SubscribeLogs, UnsubscribeLogs,
+};
+
+use crate::RefCell;
+
+use super::{send, RpcCaller};
+
+/// Older events are dropped beyond this.
+const MAX_EVENTS: usize = 5000;
+/// Buffered events the server sends first when subscribing.
+const BACKLOG: u32 = 200;
+
+/// Tracing events of the server as they are recorded.
+#[derive(Default)]
+pub struct LogsRpc {
+    sender: Option<Rc<RefCell<Sender<Vec<u8>>>>>,
+    /// the filter of the subscription on the current connection
+    subscribed: Option<LogFilter>,
+    /// oldest first
+    events: VecDeque<LogEvent>,
+    /// events the server could not send because this client fell behind
+    dropped: u64,
+    /// why the server refused the subscription
+    error: Option<String>,
+}
+
+impl LogsRpc {
+    /// Replaces the events with those matching `filter`, subscribing again replaces the filter.
+    pub fn subscribe(&mut self, filter: LogFilter) {
+        self.events.clear();
+        self.dropped = 0;
+        self.error = None;
+        self.request(
+            Service::SubscribeMsg(SubscribeLogs {
+                filter: Some(filter.clone()),
+                backlog: BACKLOG,
+            }),
+            "subscribe to logs",
+        );
+        self.subscribed = self.sender.is_some().then_some(filter);
+    }
+
+    pub fn unsubscribe(&mut self) {
+        self.request(
+            Service::UnsubscribeMsg(UnsubscribeLogs {}),
+            "unsubscribe from logs",
+        );
+        self.subscribed = None;
+    }
+
+    pub fn subscribed_filter(&self) -> Option<&LogFilter> {
+        self.subscribed.as_ref()
+    }
+
+    pub fn is_subscribed(&self) -> bool {
+        self.subscribed.is_some()
+    }
+
+    pub fn events(&self) -> &VecDeque<LogEvent> {
+        &self.events
+    }
+
+    pub fn dropped(&self) -> u64 {
+        self.dropped
+    }
+
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    pub fn clear(&mut self) {
+        self.events.clear();
+        self.dropped = 0;
+    }
+
+    fn request(&self, service: Service, what: &'static str) {
+        let Some(s) = self.sender.as_ref() else {
+            error!("not connected, cannot {what}");
+            return;
+        };
+        let m = LogService {
+            service: Some(service),
+        }
+        .encode_to_vec();
+        send(s, m, what);
+    }
+}
+
+impl RpcCaller for LogsRpc {
+    fn set_sender(&mut self, sender: Sender<Vec<u8>>) {
+        self.sender.replace(Rc::new(RefCell::new(sender)));
+        // a new connection has no subscription yet
+        self.subscribed = None;
+    }
+
+    fn handle(&mut self, bytes: &[u8]) -> bool {
+        let Ok(LogService {
+            service: Some(service),
+        }) = LogService::decode(bytes)
+        else {
+            return false;
+        };
+
+        match service {
+            Service::BatchMsg(m) => {
+                if !m.error.is_empty() {
+                    self.error = Some(m.error);
+                    return true;
+                }
+                self.events.extend(m.events);
+                let excess = self.events.len().saturating_sub(MAX_EVENTS);
+                self.events.drain(..excess);
+                self.dropped += m.dropped;
+            }
+            _ => {
+                error!("unexpected logs request from server, ignoring...");
+            }
+        }
+        true
+    }
+}
@@ -1,6 +1,6 @@
 use std::rc::Rc;
 
-use log::error;
+use log::{error, info};
 use prost::Message;
 use tokio::sync::mpsc::Sender;
 
@@ -8,6 +8,26 @@ use proto_gen::control::{hello_service::Service, HelloReply, HelloService};
 
 use crate::{spawn_local, RefCell};
 
+pub use admin::AdminRpc;
+pub use auth::AuthRpc;
+pub use config::ConfigRpc;
+pub use editor::EditorRpc;
+pub use history::HistoryRpc;
+pub use logs::LogsRpc;
+pub use monitor::MonitorRpc;
+pub use query::{QueryRpc, StatementResult, StatementState};
+pub use saved::SavedRpc;
+
+mod admin;
+mod auth;
+mod config;
+mod editor;
+mod history;
+mod logs;
+mod monitor;
+mod query;
+mod saved;
+
 pub trait RpcCaller {
     // fn add_client(&self, client: &Client);
     fn set_sender(&mut self, sender: Sender<Vec<u8>>);
@@ -32,13 +52,7 @@ impl HelloRpc {
                 })),
             }
             .encode_to_vec();
-            let ss = s.clone();
-            spawn_local(async move {
-                let _ = ss.borrow().send(m).await.map_err(|e| {
-                    let msg = format!("failed to say hello, send err: {e:?}");
-                    error!("{msg}");
-                });
-            });
+            send(s, m, "say hello");
         }
     }
 }
@@ -49,7 +63,13 @@ impl RpcCaller for HelloRpc {
     }
 
     fn handle(&mut self, bytes: &[u8]) -> bool {
-        let m = HelloService::decode(bytes);
+        let Ok(HelloService {
+            service: Some(service),
+        }) = HelloService::decode(bytes)
+        else {
+            return false;
+        };
+        info!("hello service recv: {service:?}");
         true
     }
 
@@ -57,3 +77,14 @@ impl RpcCaller for HelloRpc {
     //     client.add_caller()
     // }
 }
+
+/// Queues `msg` on the connection, `what` names the call in the error log.
+pub(crate) fn send(sender: &Rc<RefCell<Sender<Vec<u8>>>>, msg: Vec<u8>, what: &'static str) {
+    let ss = sender.clone();
+    spawn_local(async move {
+        let _ = ss.borrow().send(msg).await.map_err(|e| {
+            let msg = format!("failed to {what}, send err: {e:?}");
+            error!("{msg}");
+        });
+    });
+}
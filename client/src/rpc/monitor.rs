@@ -0,0 +1,161 @@
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+use log::error;
+use prost::Message;
+use tokio::sync::mpsc::Sender;
+
+use proto_gen::monitor::{
+    monitor_service::Service, InspectQuery, KillQuery, MonitorService, QueryDetail, QueryInfo,
+    ResourceSample, StopInspect, Subscribe, Unsubscribe,
+};
+
+use crate::RefCell;
+
+use super::{send, RpcCaller};
+
+/// Resource samples kept for the sparklines, the server samples once a second.
+const MAX_SAMPLES: usize = 300;
+
+/// Live feed of the queries run by every session on the server.
+#[derive(Default)]
+pub struct MonitorRpc {
+    sender: Option<Rc<RefCell<Sender<Vec<u8>>>>>,
+    subscribed: bool,
+    request_id: u64,
+    /// oldest first
+    queries: Vec<QueryInfo>,
+    /// oldest first
+    resources: VecDeque<ResourceSample>,
+    detail: Option<QueryDetail>,
+    /// why the server refused the feed
+    feed_error: Option<String>,
+    /// why the last kill failed
+    kill_error: Option<String>,
+}
+
+impl MonitorRpc {
+    pub fn subscribe(&mut self) {
+        // the server starts with the samples it kept
+        self.resources.clear();
+        self.feed_error = None;
+        self.request(Service::SubscribeMsg(Subscribe {}), "subscribe to monitor");
+        self.subscribed = self.sender.is_some();
+    }
+
+    pub fn unsubscribe(&mut self) {
+        self.request(
+            Service::UnsubscribeMsg(Unsubscribe {}),
+            "unsubscribe from monitor",
+        );
+        self.subscribed = false;
+    }
+
+    /// Whether the feed was requested on the current connection.
+    pub fn is_subscribed(&self) -> bool {
+        self.subscribed
+    }
+
+    pub fn queries(&self) -> &[QueryInfo] {
+        &self.queries
+    }
+
+    pub fn resources(&self) -> &VecDeque<ResourceSample> {
+        &self.resources
+    }
+
+    /// The server keeps sending the detail of the query until it ends or `close_detail`.
+    pub fn inspect(&mut self, id: u64) {
+        self.request_id += 1;
+        let request_id = self.request_id;
+        self.request(
+            Service::InspectMsg(InspectQuery { request_id, id }),
+            "inspect query",
+        );
+    }
+
+    pub fn detail(&self) -> Option<&QueryDetail> {
+        self.detail.as_ref()
+    }
+
+    pub fn close_detail(&mut self) {
+        self.detail = None;
+        self.request(
+            Service::StopInspectMsg(StopInspect {}),
+            "stop inspecting query",
+        );
+    }
+
+    pub fn kill(&mut self, id: u64) {
+        self.request_id += 1;
+        let request_id = self.request_id;
+        self.kill_error = None;
+        self.request(Service::KillMsg(KillQuery { request_id, id }), "kill query");
+    }
+
+    pub fn feed_error(&self) -> Option<&str> {
+        self.feed_error.as_deref()
+    }
+
+    pub fn kill_error(&self) -> Option<&str> {
+        self.kill_error.as_deref()
+    }
+
+    fn request(&self, service: Service, what: &'static str) {
+        let Some(s) = self.sender.as_ref() else {
+            error!("not connected, cannot {what}");
+            return;
+        };
+        let m = MonitorService {
+            service: Some(service),
+        }
+        .encode_to_vec();
+        send(s, m, what);
+    }
+}
+
+impl RpcCaller for MonitorRpc {
+    fn set_sender(&mut self, sender: Sender<Vec<u8>>) {
+        self.sender.replace(Rc::new(RefCell::new(sender)));
+        // a new connection has no subscription yet
+        self.subscribed = false;
+    }
+
+    fn handle(&mut self, bytes: &[u8]) -> bool {
+        let Ok(MonitorService {
+            service: Some(service),
+        }) = MonitorService::decode(bytes)
+        else {
+            return false;
+        };
+
+        match service {
+            Service::FeedMsg(m) => {
+                if m.error.is_empty() {
+                    self.queries = m.queries;
+                } else {
+                    self.feed_error = Some(m.error);
+                }
+            }
+            Service::ResourcesMsg(m) => {
+                self.resources.extend(m.samples);
+                let excess = self.resources.len().saturating_sub(MAX_SAMPLES);
+                self.resources.drain(..excess);
+            }
+            Service::DetailMsg(m) => {
+                if m.request_id == self.request_id {
+                    self.detail = Some(m);
+                }
+            }
+            Service::KillResultMsg(m) => {
+                if !m.error.is_empty() {
+                    self.kill_error = Some(m.error);
+                }
+            }
+            _ => {
+                error!("unexpected monitor request from server, ignoring...");
+            }
+        }
+        true
+    }
+}
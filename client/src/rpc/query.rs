@@ -0,0 +1,277 @@
+use std::rc::Rc;
+
+use log::{error, info};
+use prost::Message;
+use tokio::sync::mpsc::Sender;
+
+use proto_gen::query::{
+    query_service::Service, CatalogStatus, Column, ExecuteSql, Limit, QueryService, RegisterTable,
+    Row, StatementLimits, TableRegistered, TableScan,
+};
+
+use crate::history::HistoryEntry;
+use crate::RefCell;
+
+use super::{send, RpcCaller};
+
+#[derive(Clone, PartialEq)]
+pub enum StatementState {
+    Pending,
+    /// Waiting in the admission queue of the server, 1 runs next.
+    Queued {
+        position: u32,
+    },
+    Running,
+    Done {
+        row_count: u64,
+        duration_ms: u64,
+        /// the cap that cut the result short, the rows are the first of the result then
+        cut_short: Option<Limit>,
+    },
+    Failed {
+        duration_ms: u64,
+        error: String,
+    },
+    /// Refused by a quota or limit of the server.
+    Limited {
+        limit: Limit,
+        error: String,
+    },
+    /// Not run because an earlier statement of the script failed.
+    Skipped,
+}
+
+pub struct StatementResult {
+    pub sql: String,
+    pub columns: Vec<Column>,
+    pub rows: Vec<Row>,
+    /// of the tables over directories read
+    pub scans: Vec<TableScan>,
+    pub state: StatementState,
+}
+
+#[derive(Default)]
+pub struct QueryRpc {
+    sender: Option<Rc<RefCell<Sender<Vec<u8>>>>>,
+    request_id: u64,
+    running: bool,
+    context: Option<String>,
+    results: Vec<StatementResult>,
+    /// finished statements not yet taken into the history
+    finished: Vec<HistoryEntry>,
+    /// as last told by the server
+    catalog: Option<CatalogStatus>,
+    /// contexts of the server not yet taken into the context list
+    new_contexts: Vec<String>,
+    /// apart from `request_id`, a registration must not drop the results of statements
+    register_id: u64,
+    registering: bool,
+    registered: Option<TableRegistered>,
+}
+
+impl QueryRpc {
+    /// Runs `statements` in order, replacing the results of the previous request, each capped
+    /// by `limits`.
+    pub fn execute(
+        &mut self,
+        context: Option<String>,
+        statements: Vec<String>,
+        limits: StatementLimits,
+    ) {
+        let Some(s) = self.sender.as_ref() else {
+            error!("not connected, cannot execute statements");
+            return;
+        };
+        if statements.is_empty() {
+            return;
+        }
+
+        self.request_id += 1;
+        self.running = true;
+        self.context = context.clone();
+        self.results = statements
+            .iter()
+            .map(|sql| StatementResult {
+                sql: sql.clone(),
+                columns: vec![],
+                rows: vec![],
+                scans: vec![],
+                state: StatementState::Pending,
+            })
+            .collect();
+        self.results[0].state = StatementState::Running;
+
+        let m = QueryService {
+            service: Some(Service::ExecuteMsg(ExecuteSql {
+                request_id: self.request_id,
+                context: context.unwrap_or_default(),
+                statements,
+                limits: Some(limits),
+            })),
+        }
+        .encode_to_vec();
+        send(s, m, "execute statements");
+    }
+
+    /// Registers a table over the files below a directory, `req` gets a fresh request id.
+    pub fn register_table(&mut self, mut req: RegisterTable) {
+        let Some(s) = self.sender.as_ref() else {
+            error!("not connected, cannot register table");
+            return;
+        };
+        self.register_id += 1;
+        req.request_id = self.register_id;
+        self.registering = true;
+        self.registered = None;
+        let m = QueryService {
+            service: Some(Service::RegisterTableMsg(req)),
+        }
+        .encode_to_vec();
+        send(s, m, "register table");
+    }
+
+    pub fn is_registering(&self) -> bool {
+        self.registering
+    }
+
+    /// The reply to the last registration.
+    pub fn registered(&self) -> Option<&TableRegistered> {
+        self.registered.as_ref()
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+
+    pub fn results(&self) -> &[StatementResult] {
+        &self.results
+    }
+
+    pub fn take_finished(&mut self) -> Vec<HistoryEntry> {
+        std::mem::take(&mut self.finished)
+    }
+
+    pub fn catalog(&self) -> Option<&CatalogStatus> {
+        self.catalog.as_ref()
+    }
+
+    pub fn take_new_contexts(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.new_contexts)
+    }
+
+    fn result_mut(&mut self, request_id: u64, index: u32) -> Option<&mut StatementResult> {
+        if request_id != self.request_id {
+            info!("dropping reply of stale request {request_id}");
+            return None;
+        }
+        self.results.get_mut(index as usize)
+    }
+}
+
+impl RpcCaller for QueryRpc {
+    fn set_sender(&mut self, sender: Sender<Vec<u8>>) {
+        self.sender.replace(Rc::new(RefCell::new(sender)));
+        // told again by the server of this connection
+        self.catalog = None;
+        self.registering = false;
+    }
+
+    fn handle(&mut self, bytes: &[u8]) -> bool {
+        let Ok(QueryService {
+            service: Some(service),
+        }) = QueryService::decode(bytes)
+        else {
+            return false;
+        };
+
+        match service {
+            Service::ResultHeaderMsg(m) => {
+                if let Some(r) = self.result_mut(m.request_id, m.statement_index) {
+                    r.columns = m.columns;
+                    r.scans = m.scans;
+                }
+            }
+            Service::ResultRowsMsg(m) => {
+                if let Some(r) = self.result_mut(m.request_id, m.statement_index) {
+                    r.rows.extend(m.rows);
+                }
+            }
+            Service::QueuedMsg(m) => {
+                if let Some(r) = self.result_mut(m.request_id, m.statement_index) {
+                    r.state = StatementState::Queued {
+                        position: m.position,
+                    };
+                }
+            }
+            Service::StatementDoneMsg(m) => {
+                let index = m.statement_index;
+                let context = self.context.clone();
+                let mut finished = None;
+                if let Some(r) = self.result_mut(m.request_id, index) {
+                    let entry = HistoryEntry {
+                        sql: r.sql.clone(),
+                        context,
+                        started_at_ms: m.started_at_ms,
+                        duration_ms: m.duration_ms,
+                        row_count: m.row_count,
+                        error: (!m.error.is_empty()).then(|| m.error.clone()),
+                    };
+                    let limit = m.limit();
+                    r.state = if m.error.is_empty() {
+                        StatementState::Done {
+                            row_count: m.row_count,
+                            duration_ms: m.duration_ms,
+                            cut_short: (limit != Limit::Unspecified).then_some(limit),
+                        }
+                    } else if limit != Limit::Unspecified {
+                        StatementState::Limited {
+                            limit,
+                            error: m.error,
+                        }
+                    } else {
+                        StatementState::Failed {
+                            duration_ms: m.duration_ms,
+                            error: m.error,
+                        }
+                    };
+                    finished = Some(entry);
+                }
+                self.finished.extend(finished);
+                if let Some(next) = self.result_mut(m.request_id, index + 1) {
+                    next.state = StatementState::Running;
+                }
+            }
+            Service::ExecuteDoneMsg(m) => {
+                if m.request_id == self.request_id {
+                    self.running = false;
+                    self.results
+                        .iter_mut()
+                        .filter(|r| {
+                            matches!(
+                                r.state,
+                                StatementState::Pending
+                                    | StatementState::Queued { .. }
+                                    | StatementState::Running
+                            )
+                        })
+                        .for_each(|r| r.state = StatementState::Skipped);
+                }
+            }
+            Service::TableRegisteredMsg(m) => {
+                if m.request_id == self.register_id {
+                    self.registering = false;
+                    self.registered = Some(m);
+                }
+            }
+            Service::CatalogMsg(m) => {
+                self.new_contexts.extend(m.contexts.iter().cloned());
+                self.catalog = Some(m);
+            }
+            Service::ExecuteMsg(_) => {
+                error!("unexpected execute msg from server, ignoring...");
+            }
+            _ => return false,
+        }
+        true
+    }
+}
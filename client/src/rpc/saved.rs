@@ -0,0 +1,127 @@
+use std::rc::Rc;
+
+use log::error;
+use prost::Message;
+use tokio::sync::mpsc::Sender;
+
+use proto_gen::saved::{
+    saved_service::Service, DeleteQuery, ImportQueries, ListQueries, SaveQuery, SavedQuery,
+    SavedService,
+};
+
+use crate::RefCell;
+
+use super::{send, RpcCaller};
+
+/// Named queries stored on the server, shared by every client.
+#[derive(Default)]
+pub struct SavedRpc {
+    sender: Option<Rc<RefCell<Sender<Vec<u8>>>>>,
+    request_id: u64,
+    loading: bool,
+    /// sorted by name
+    queries: Vec<SavedQuery>,
+    /// why the last change was rejected
+    error: Option<String>,
+}
+
+impl SavedRpc {
+    pub fn list(&mut self) {
+        let request_id = self.next_request_id();
+        self.request(
+            Service::ListMsg(ListQueries { request_id }),
+            "list saved queries",
+        );
+    }
+
+    /// Creates `query` if its id is 0, updates the saved one otherwise.
+    pub fn save(&mut self, query: SavedQuery) {
+        let request_id = self.next_request_id();
+        self.request(
+            Service::SaveMsg(SaveQuery {
+                request_id,
+                query: Some(query),
+            }),
+            "save query",
+        );
+    }
+
+    pub fn delete(&mut self, id: u64) {
+        let request_id = self.next_request_id();
+        self.request(
+            Service::DeleteMsg(DeleteQuery { request_id, id }),
+            "delete saved query",
+        );
+    }
+
+    pub fn import(&mut self, queries: Vec<SavedQuery>) {
+        let request_id = self.next_request_id();
+        self.request(
+            Service::ImportMsg(ImportQueries {
+                request_id,
+                queries,
+            }),
+            "import saved queries",
+        );
+    }
+
+    pub fn is_loading(&self) -> bool {
+        self.loading
+    }
+
+    pub fn queries(&self) -> &[SavedQuery] {
+        &self.queries
+    }
+
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    fn next_request_id(&mut self) -> u64 {
+        self.request_id += 1;
+        self.request_id
+    }
+
+    fn request(&mut self, service: Service, what: &'static str) {
+        let Some(s) = self.sender.as_ref() else {
+            error!("not connected, cannot {what}");
+            return;
+        };
+        self.loading = true;
+        let m = SavedService {
+            service: Some(service),
+        }
+        .encode_to_vec();
+        send(s, m, what);
+    }
+}
+
+impl RpcCaller for SavedRpc {
+    fn set_sender(&mut self, sender: Sender<Vec<u8>>) {
+        self.sender.replace(Rc::new(RefCell::new(sender)));
+    }
+
+    fn handle(&mut self, bytes: &[u8]) -> bool {
+        let Ok(SavedService {
+            service: Some(service),
+        }) = SavedService::decode(bytes)
+        else {
+            return false;
+        };
+
+        match service {
+            Service::QueryListMsg(m) => {
+                // every reply carries the full list, only the latest one is current
+                if m.request_id == self.request_id {
+                    self.queries = m.queries;
+                    self.error = (!m.error.is_empty()).then_some(m.error);
+                    self.loading = false;
+                }
+            }
+            _ => {
+                error!("unexpected saved queries request from server, ignoring...");
+            }
+        }
+        true
+    }
+}
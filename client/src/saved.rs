@@ -0,0 +1,108 @@
+//! Export and import of saved queries as a single JSON file.
+use proto_gen::saved::SavedQuery;
+
+use crate::chart::ChartSettings;
+
+pub const EXPORT_FILE_NAME: &str = "saved_queries.json";
+
+/// A saved query as written to the export file, without server assigned fields.
+#[derive(serde::Deserialize, serde::Serialize)]
+struct ExportedQuery {
+    name: String,
+    sql: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default)]
+    context: String,
+    #[serde(default)]
+    chart: Option<ChartSettings>,
+}
+
+pub fn export(queries: &[SavedQuery]) -> Result<String, String> {
+    let exported = queries
+        .iter()
+        .map(|q| ExportedQuery {
+            name: q.name.clone(),
+            sql: q.sql.clone(),
+            description: q.description.clone(),
+            tags: q.tags.clone(),
+            context: q.context.clone(),
+            chart: q.chart.clone().map(ChartSettings::from),
+        })
+        .collect::<Vec<_>>();
+    serde_json::to_string_pretty(&exported).map_err(|e| format!("failed to export: {e}"))
+}
+
+pub fn import(json: &str) -> Result<Vec<SavedQuery>, String> {
+    let exported: Vec<ExportedQuery> =
+        serde_json::from_str(json).map_err(|e| format!("not an export of saved queries: {e}"))?;
+    Ok(exported
+        .into_iter()
+        .map(|q| SavedQuery {
+            name: q.name,
+            sql: q.sql,
+            description: q.description,
+            tags: q.tags,
+            context: q.context,
+            chart: q.chart.map(Into::into),
+            ..Default::default()
+        })
+        .collect())
+}
+
+/// A saved query being edited, tags are typed comma separated.
+#[derive(Clone, Default)]
+pub struct QueryDraft {
+    /// 0 for a new query
+    pub id: u64,
+    pub name: String,
+    pub sql: String,
+    pub description: String,
+    pub tags: String,
+    pub context: String,
+}
+
+impl From<&SavedQuery> for QueryDraft {
+    fn from(q: &SavedQuery) -> Self {
+        Self {
+            id: q.id,
+            name: q.name.clone(),
+            sql: q.sql.clone(),
+            description: q.description.clone(),
+            tags: q.tags.join(", "),
+            context: q.context.clone(),
+        }
+    }
+}
+
+impl QueryDraft {
+    pub fn into_query(self, chart: Option<ChartSettings>) -> SavedQuery {
+        SavedQuery {
+            id: self.id,
+            name: self.name,
+            sql: self.sql,
+            description: self.description,
+            tags: self
+                .tags
+                .split(',')
+                .map(str::trim)
+                .filter(|t| !t.is_empty())
+                .map(str::to_string)
+                .collect(),
+            context: self.context,
+            chart: chart.map(Into::into),
+            updated_at_ms: 0,
+        }
+    }
+}
+
+/// Whether `query` contains `search` in its name, description, tags or sql, ignoring case.
+pub fn matches(query: &SavedQuery, search: &str) -> bool {
+    let search = search.to_lowercase();
+    [&query.name, &query.description, &query.sql]
+        .into_iter()
+        .chain(query.tags.iter())
+        .any(|text| text.to_lowercase().contains(&search))
+}
@@ -0,0 +1,249 @@
+//! Lightweight SQL lexing for the editor: highlighting, statement splitting and bracket matching.
+//! All offsets are byte offsets into the source text.
+use std::ops::Range;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TokenKind {
+    Whitespace,
+    Comment,
+    Keyword,
+    Function,
+    Ident,
+    QuotedIdent,
+    Str,
+    Number,
+    Bracket,
+    Semicolon,
+    Operator,
+}
+
+#[derive(Debug, Clone)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub range: Range<usize>,
+}
+
+impl Token {
+    pub fn is_trivia(&self) -> bool {
+        matches!(self.kind, TokenKind::Whitespace | TokenKind::Comment)
+    }
+}
+
+#[rustfmt::skip]
+pub const KEYWORDS: &[&str] = &[
+    "ADD", "ALL", "ALTER", "AND", "ANY", "ARRAY", "AS", "ASC", "BETWEEN", "BIGINT", "BOOLEAN",
+    "BY", "CASE", "CAST", "COPY", "CREATE", "CROSS", "CSV", "CURRENT", "DATABASE", "DATE",
+    "DECIMAL", "DEFAULT", "DELETE", "DESC", "DESCRIBE", "DISTINCT", "DOUBLE", "DROP", "ELSE",
+    "END", "EXCEPT", "EXISTS", "EXPLAIN", "EXTERNAL", "FALSE", "FILTER", "FIRST", "FLOAT",
+    "FOLLOWING", "FOR", "FORMAT", "FROM", "FULL", "GROUP", "HAVING", "IF", "ILIKE", "IN", "INNER",
+    "INSERT", "INT", "INTEGER", "INTERSECT", "INTERVAL", "INTO", "IS", "JOIN", "JSON", "LAST",
+    "LEFT", "LIKE", "LIMIT", "LOCATION", "NATURAL", "NOT", "NULL", "NULLS", "OFFSET", "ON", "OR",
+    "ORDER", "OUTER", "OVER", "PARQUET", "PARTITION", "PARTITIONED", "PRECEDING", "RANGE",
+    "REPLACE", "RIGHT", "ROW", "ROWS", "SCHEMA", "SELECT", "SET", "SHOW", "SMALLINT", "STORED",
+    "TABLE", "TABLES", "THEN", "TIMESTAMP", "TO", "TRUE", "TRY_CAST", "UNBOUNDED", "UNION",
+    "UNNEST", "UPDATE", "USING", "VALUES", "VARCHAR", "VIEW", "WHEN", "WHERE", "WINDOW", "WITH",
+];
+
+pub fn is_keyword(word: &str) -> bool {
+    KEYWORDS
+        .binary_search(&word.to_ascii_uppercase().as_str())
+        .is_ok()
+}
+
+pub fn tokenize(src: &str) -> Vec<Token> {
+    let bytes = src.as_bytes();
+    let mut tokens = vec![];
+    let mut pos = 0;
+
+    // scans forward while `f` holds, returns the first position where it doesn't
+    let scan = |from: usize, f: &dyn Fn(char) -> bool| {
+        src[from..]
+            .char_indices()
+            .find(|(_, c)| !f(*c))
+            .map(|(i, _)| from + i)
+            .unwrap_or(src.len())
+    };
+
+    while pos < src.len() {
+        let c = src[pos..].chars().next().unwrap_or_default();
+        let next = bytes.get(pos + 1).copied().unwrap_or_default();
+        let (kind, end) = match c {
+            c if c.is_whitespace() => (TokenKind::Whitespace, scan(pos, &|c| c.is_whitespace())),
+            '-' if next == b'-' => (
+                TokenKind::Comment,
+                src[pos..].find('\n').map(|i| pos + i).unwrap_or(src.len()),
+            ),
+            '/' if next == b'*' => (
+                TokenKind::Comment,
+                src[pos + 2..]
+                    .find("*/")
+                    .map(|i| pos + 2 + i + 2)
+                    .unwrap_or(src.len()),
+            ),
+            '\'' | '"' => {
+                let mut end = pos + 1;
+                loop {
+                    match src[end..].find(c) {
+                        // a doubled quote is an escaped quote
+                        Some(i) if bytes.get(end + i + 1) == Some(&(c as u8)) => end += i + 2,
+                        Some(i) => break end += i + 1,
+                        None => break end = src.len(),
+                    }
+                }
+                let kind = if c == '\'' {
+                    TokenKind::Str
+                } else {
+                    TokenKind::QuotedIdent
+                };
+                (kind, end)
+            }
+            c if c.is_ascii_digit() || (c == '.' && next.is_ascii_digit()) => (
+                TokenKind::Number,
+                scan(pos, &|c| c.is_ascii_alphanumeric() || c == '.' || c == '_'),
+            ),
+            c if c.is_alphabetic() || c == '_' => {
+                let end = scan(pos, &|c| c.is_alphanumeric() || c == '_');
+                let word = &src[pos..end];
+                let followed_by_paren = src[end..].trim_start().starts_with('(');
+                let kind = if is_keyword(word) {
+                    TokenKind::Keyword
+                } else if followed_by_paren {
+                    TokenKind::Function
+                } else {
+                    TokenKind::Ident
+                };
+                (kind, end)
+            }
+            '(' | ')' | '[' | ']' => (TokenKind::Bracket, pos + 1),
+            ';' => (TokenKind::Semicolon, pos + 1),
+            c => (TokenKind::Operator, pos + c.len_utf8()),
+        };
+        tokens.push(Token {
+            kind,
+            range: pos..end,
+        });
+        pos = end;
+    }
+
+    tokens
+}
+
+/// A statement and the extent of source text it owns, up to and including its `;`.
+#[derive(Debug, Clone)]
+pub struct Segment {
+    pub extent: Range<usize>,
+    /// Trimmed statement text, `None` if the segment only holds whitespace and comments.
+    pub statement: Option<Range<usize>>,
+}
+
+pub fn segments(src: &str) -> Vec<Segment> {
+    let mut segments = vec![];
+    let mut extent_start = 0;
+    let mut statement: Option<Range<usize>> = None;
+
+    for token in tokenize(src) {
+        if token.kind == TokenKind::Semicolon {
+            segments.push(Segment {
+                extent: extent_start..token.range.end,
+                statement: statement.take(),
+            });
+            extent_start = token.range.end;
+        } else if !token.is_trivia() {
+            let start = statement
+                .as_ref()
+                .map(|s| s.start)
+                .unwrap_or(token.range.start);
+            statement = Some(start..token.range.end);
+        }
+    }
+    segments.push(Segment {
+        extent: extent_start..src.len(),
+        statement,
+    });
+
+    segments
+}
+
+/// All non-empty statements of `src`, in order.
+pub fn split_statements(src: &str) -> Vec<Range<usize>> {
+    segments(src)
+        .into_iter()
+        .filter_map(|s| s.statement)
+        .collect()
+}
+
+/// The statement under `offset`. When the cursor sits in a blank tail, e.g. right after the
+/// last `;`, the closest preceding statement is used.
+pub fn statement_at(src: &str, offset: usize) -> Option<Range<usize>> {
+    let segments = segments(src);
+    let index = segments
+        .iter()
+        .position(|s| offset <= s.extent.end)
+        .unwrap_or(segments.len().saturating_sub(1));
+    segments[..=index]
+        .iter()
+        .rev()
+        .find_map(|s| s.statement.clone())
+        .or_else(|| segments[index..].iter().find_map(|s| s.statement.clone()))
+}
+
+/// Finds the bracket next to `offset` (the one before the cursor wins) and its counterpart.
+/// Brackets inside strings and comments are ignored.
+pub fn matching_bracket(src: &str, offset: usize) -> Option<(usize, usize)> {
+    let tokens: Vec<Token> = tokenize(src)
+        .into_iter()
+        .filter(|t| t.kind == TokenKind::Bracket)
+        .collect();
+    let index = tokens
+        .iter()
+        .position(|t| t.range.end == offset)
+        .or_else(|| tokens.iter().position(|t| t.range.start == offset))?;
+
+    let bracket = |i: usize| src.as_bytes()[tokens[i].range.start];
+    let (open, close) = match bracket(index) {
+        b'(' | b')' => (b'(', b')'),
+        _ => (b'[', b']'),
+    };
+    let forward = bracket(index) == open;
+
+    let mut depth = 0;
+    let mut i = index;
+    loop {
+        match bracket(i) {
+            b if b == open => depth += if forward { 1 } else { -1 },
+            b if b == close => depth += if forward { -1 } else { 1 },
+            _ => {}
+        }
+        if depth == 0 {
+            return Some((tokens[index].range.start, tokens[i].range.start));
+        }
+        if forward {
+            i += 1;
+            if i == tokens.len() {
+                return None;
+            }
+        } else {
+            i = i.checked_sub(1)?;
+        }
+    }
+}
+
+pub fn char_to_byte(src: &str, char_index: usize) -> usize {
+    src.char_indices()
+        .nth(char_index)
+        .map(|(i, _)| i)
+        .unwrap_or(src.len())
+}
+
+/// Char index of a 1-based line and column (counted in chars), clamped to the end of the line.
+pub fn line_column_to_char(src: &str, line: usize, column: usize) -> usize {
+    let mut index = 0;
+    for (n, text) in src.split('\n').enumerate() {
+        let len = text.chars().count();
+        if n + 1 == line {
+            return index + column.saturating_sub(1).min(len);
+        }
+        index += len + 1;
+    }
+    src.chars().count()
+}
@@ -8,6 +8,8 @@ use crate::RefCell;
 pub struct VolatileStatus {
     pub close_notify: Notify,
     pub connected: bool,
+    /// why the server closed the connection for want of a login
+    pub login_required: Option<String>,
     pub edit_ctx_name: RefCell<String>,
     pub mode: RefCell<Mode>,
     // server
@@ -37,6 +39,7 @@ impl Default for VolatileStatus {
         Self {
             close_notify: Notify::new(),
             connected: false,
+            login_required: None,
             mode: RefCell::new(Mode::Console),
             edit_ctx_name: RefCell::new("".to_string()),
             // server
@@ -1,4 +1,16 @@
 // @generated
+#[cfg(feature = "admin")]
+// @@protoc_insertion_point(attribute:admin)
+pub mod admin {
+    include!("admin.rs");
+    // @@protoc_insertion_point(admin)
+}
+#[cfg(feature = "auth")]
+// @@protoc_insertion_point(attribute:auth)
+pub mod auth {
+    include!("auth.rs");
+    // @@protoc_insertion_point(auth)
+}
 #[cfg(feature = "config")]
 // @@protoc_insertion_point(attribute:config)
 pub mod config {
@@ -10,4 +22,28 @@ pub mod config {
 pub mod control {
     include!("control.rs");
     // @@protoc_insertion_point(control)
+}
+#[cfg(feature = "logs")]
+// @@protoc_insertion_point(attribute:logs)
+pub mod logs {
+    include!("logs.rs");
+    // @@protoc_insertion_point(logs)
+}
+#[cfg(feature = "monitor")]
+// @@protoc_insertion_point(attribute:monitor)
+pub mod monitor {
+    include!("monitor.rs");
+    // @@protoc_insertion_point(monitor)
+}
+#[cfg(feature = "query")]
+// @@protoc_insertion_point(attribute:query)
+pub mod query {
+    include!("query.rs");
+    // @@protoc_insertion_point(query)
+}
+#[cfg(feature = "saved")]
+// @@protoc_insertion_point(attribute:saved)
+pub mod saved {
+    include!("saved.rs");
+    // @@protoc_insertion_point(saved)
 }
\ No newline at end of file
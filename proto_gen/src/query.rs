@@ -0,0 +1,1491 @@
+// @generated
+// oneof tags of the *Service wrappers must not overlap between packages:
+// every websocket frame is tried against each wrapper in turn, and only the
+// one it was built from may decode into a non-empty `service`.
+
+/// caps of every statement of a request, 0 for none, lower ones of the server apply as well
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StatementLimits {
+    /// wall clock time the statement may run once admitted
+    #[prost(uint64, tag="1")]
+    pub timeout_ms: u64,
+    #[prost(uint64, tag="2")]
+    pub max_rows: u64,
+    /// encoded result rows streamed to the client
+    #[prost(uint64, tag="3")]
+    pub max_bytes: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExecuteSql {
+    #[prost(uint64, tag="1")]
+    pub request_id: u64,
+    #[prost(string, tag="2")]
+    pub context: ::prost::alloc::string::String,
+    /// statements run in order, execution stops at the first failing one
+    #[prost(string, repeated, tag="3")]
+    pub statements: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(message, optional, tag="4")]
+    pub limits: ::core::option::Option<StatementLimits>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Column {
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub data_type: ::prost::alloc::string::String,
+    #[prost(bool, tag="3")]
+    pub nullable: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Cell {
+    #[prost(string, tag="1")]
+    pub text: ::prost::alloc::string::String,
+    #[prost(bool, tag="2")]
+    pub is_null: bool,
+    /// set for numeric columns so the client doesn't need to parse `text`,
+    /// temporal values are given as milliseconds since the epoch
+    #[prost(double, optional, tag="3")]
+    pub number: ::core::option::Option<f64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Row {
+    #[prost(message, repeated, tag="1")]
+    pub cells: ::prost::alloc::vec::Vec<Cell>,
+}
+/// the files of a table over a directory a statement reads, out of the ones below it
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TableScan {
+    #[prost(string, tag="1")]
+    pub table: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub files_scanned: u64,
+    #[prost(uint64, tag="3")]
+    pub files_total: u64,
+    /// directories named after partition values, 0 for a table without partition columns
+    #[prost(uint64, tag="4")]
+    pub partitions_scanned: u64,
+    #[prost(uint64, tag="5")]
+    pub partitions_total: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResultHeader {
+    #[prost(uint64, tag="1")]
+    pub request_id: u64,
+    #[prost(uint32, tag="2")]
+    pub statement_index: u32,
+    #[prost(message, repeated, tag="3")]
+    pub columns: ::prost::alloc::vec::Vec<Column>,
+    /// known once planned, partitions whose values the filters rule out aren't read
+    #[prost(message, repeated, tag="4")]
+    pub scans: ::prost::alloc::vec::Vec<TableScan>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResultRows {
+    #[prost(uint64, tag="1")]
+    pub request_id: u64,
+    #[prost(uint32, tag="2")]
+    pub statement_index: u32,
+    #[prost(message, repeated, tag="3")]
+    pub rows: ::prost::alloc::vec::Vec<Row>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StatementDone {
+    #[prost(uint64, tag="1")]
+    pub request_id: u64,
+    #[prost(uint32, tag="2")]
+    pub statement_index: u32,
+    #[prost(uint64, tag="3")]
+    pub row_count: u64,
+    #[prost(uint64, tag="4")]
+    pub duration_ms: u64,
+    /// empty on success
+    #[prost(string, tag="5")]
+    pub error: ::prost::alloc::string::String,
+    /// wall clock time of the server, milliseconds since the epoch
+    #[prost(uint64, tag="6")]
+    pub started_at_ms: u64,
+    /// set when a limit refused the statement, `error` says which one was reached, or cut its
+    /// result short: `error` is empty then and the rows sent are the first of the result
+    #[prost(enumeration="Limit", tag="7")]
+    pub limit: i32,
+}
+/// the statement waits for the server to run fewer queries, sent whenever its position changes
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Queued {
+    #[prost(uint64, tag="1")]
+    pub request_id: u64,
+    #[prost(uint32, tag="2")]
+    pub statement_index: u32,
+    /// 1 runs next
+    #[prost(uint32, tag="3")]
+    pub position: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExecuteDone {
+    #[prost(uint64, tag="1")]
+    pub request_id: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CompleteSql {
+    #[prost(uint64, tag="1")]
+    pub request_id: u64,
+    #[prost(string, tag="2")]
+    pub context: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub sql: ::prost::alloc::string::String,
+    /// byte offset of the cursor in `sql`
+    #[prost(uint32, tag="4")]
+    pub cursor: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Candidate {
+    #[prost(string, tag="1")]
+    pub text: ::prost::alloc::string::String,
+    #[prost(enumeration="CandidateKind", tag="2")]
+    pub kind: i32,
+    /// e.g. the data type of a column or the schema of a table
+    #[prost(string, tag="3")]
+    pub detail: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Completions {
+    #[prost(uint64, tag="1")]
+    pub request_id: u64,
+    /// the word being completed, candidates replace it
+    #[prost(string, tag="2")]
+    pub prefix: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag="3")]
+    pub candidates: ::prost::alloc::vec::Vec<Candidate>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidateSql {
+    #[prost(uint64, tag="1")]
+    pub request_id: u64,
+    #[prost(string, tag="2")]
+    pub context: ::prost::alloc::string::String,
+    /// may hold several statements, each one is parsed and planned but never executed
+    #[prost(string, tag="3")]
+    pub sql: ::prost::alloc::string::String,
+}
+/// 1-based lines and columns (in characters) of `sql`, the end is exclusive
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Span {
+    #[prost(uint32, tag="1")]
+    pub start_line: u32,
+    #[prost(uint32, tag="2")]
+    pub start_column: u32,
+    #[prost(uint32, tag="3")]
+    pub end_line: u32,
+    #[prost(uint32, tag="4")]
+    pub end_column: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Diagnostic {
+    #[prost(enumeration="Severity", tag="1")]
+    pub severity: i32,
+    #[prost(string, tag="2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="3")]
+    pub span: ::core::option::Option<Span>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Diagnostics {
+    #[prost(uint64, tag="1")]
+    pub request_id: u64,
+    #[prost(message, repeated, tag="2")]
+    pub diagnostics: ::prost::alloc::vec::Vec<Diagnostic>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HistoryEntry {
+    #[prost(string, tag="1")]
+    pub sql: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub context: ::prost::alloc::string::String,
+    #[prost(uint64, tag="3")]
+    pub started_at_ms: u64,
+    #[prost(uint64, tag="4")]
+    pub duration_ms: u64,
+    #[prost(uint64, tag="5")]
+    pub row_count: u64,
+    /// empty on success
+    #[prost(string, tag="6")]
+    pub error: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetHistory {
+    #[prost(uint64, tag="1")]
+    pub request_id: u64,
+    #[prost(string, tag="2")]
+    pub context: ::prost::alloc::string::String,
+    /// case-insensitive part of the sql, empty matches every entry
+    #[prost(string, tag="3")]
+    pub search: ::prost::alloc::string::String,
+    #[prost(uint32, tag="4")]
+    pub limit: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct History {
+    #[prost(uint64, tag="1")]
+    pub request_id: u64,
+    /// newest first
+    #[prost(message, repeated, tag="2")]
+    pub entries: ::prost::alloc::vec::Vec<HistoryEntry>,
+    /// why the history was refused, empty on success
+    #[prost(string, tag="3")]
+    pub error: ::prost::alloc::string::String,
+}
+/// a catalog definition of an earlier run the server failed to create again
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CatalogIssue {
+    #[prost(string, tag="1")]
+    pub context: ::prost::alloc::string::String,
+    /// schema, external table or view
+    #[prost(string, tag="2")]
+    pub kind: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub error: ::prost::alloc::string::String,
+}
+/// sent after connecting and whenever it changes
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CatalogStatus {
+    /// contexts clients made definitions in, kept across restarts
+    #[prost(string, repeated, tag="1")]
+    pub contexts: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(message, repeated, tag="2")]
+    pub issues: ::prost::alloc::vec::Vec<CatalogIssue>,
+}
+/// a column named after a directory level, e.g. dt in dt=2024-01-01/region=eu/
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartitionColumn {
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+    /// an Arrow type, e.g. Date32, Int32 or Utf8
+    #[prost(string, tag="2")]
+    pub data_type: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SortColumn {
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(bool, tag="2")]
+    pub descending: bool,
+    #[prost(bool, tag="3")]
+    pub nulls_first: bool,
+}
+/// registers a table over the files below a directory, kept across restarts like DDL
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RegisterTable {
+    #[prost(uint64, tag="1")]
+    pub request_id: u64,
+    #[prost(string, tag="2")]
+    pub context: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub name: ::prost::alloc::string::String,
+    /// a directory, as a path or file URL
+    #[prost(string, tag="4")]
+    pub location: ::prost::alloc::string::String,
+    /// parquet, csv, json, avro or arrow
+    #[prost(string, tag="5")]
+    pub file_type: ::prost::alloc::string::String,
+    /// in the order of the directory levels
+    #[prost(message, repeated, tag="6")]
+    pub partition_columns: ::prost::alloc::vec::Vec<PartitionColumn>,
+    /// only files ending with it are read, "." and the file type when empty
+    #[prost(string, tag="7")]
+    pub file_extension: ::prost::alloc::string::String,
+    /// how the rows of every file are sorted, spares sorting them again
+    #[prost(message, repeated, tag="8")]
+    pub sort_order: ::prost::alloc::vec::Vec<SortColumn>,
+    /// csv files start with a header line
+    #[prost(bool, tag="9")]
+    pub has_header: bool,
+    /// replaces a table of the same name instead of failing
+    #[prost(bool, tag="10")]
+    pub replace: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TableRegistered {
+    #[prost(uint64, tag="1")]
+    pub request_id: u64,
+    /// empty on success
+    #[prost(string, tag="2")]
+    pub error: ::prost::alloc::string::String,
+    /// found below the location
+    #[prost(uint64, tag="3")]
+    pub files: u64,
+    #[prost(uint64, tag="4")]
+    pub partitions: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryService {
+    #[prost(oneof="query_service::Service", tags="100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 111, 112, 113, 114")]
+    pub service: ::core::option::Option<query_service::Service>,
+}
+/// Nested message and enum types in `QueryService`.
+pub mod query_service {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Service {
+        #[prost(message, tag="100")]
+        ExecuteMsg(super::ExecuteSql),
+        #[prost(message, tag="101")]
+        ResultHeaderMsg(super::ResultHeader),
+        #[prost(message, tag="102")]
+        ResultRowsMsg(super::ResultRows),
+        #[prost(message, tag="103")]
+        StatementDoneMsg(super::StatementDone),
+        #[prost(message, tag="104")]
+        ExecuteDoneMsg(super::ExecuteDone),
+        #[prost(message, tag="105")]
+        CompleteMsg(super::CompleteSql),
+        #[prost(message, tag="106")]
+        CompletionsMsg(super::Completions),
+        #[prost(message, tag="107")]
+        ValidateMsg(super::ValidateSql),
+        #[prost(message, tag="108")]
+        DiagnosticsMsg(super::Diagnostics),
+        #[prost(message, tag="109")]
+        GetHistoryMsg(super::GetHistory),
+        #[prost(message, tag="110")]
+        HistoryMsg(super::History),
+        #[prost(message, tag="111")]
+        QueuedMsg(super::Queued),
+        #[prost(message, tag="112")]
+        CatalogMsg(super::CatalogStatus),
+        #[prost(message, tag="113")]
+        RegisterTableMsg(super::RegisterTable),
+        #[prost(message, tag="114")]
+        TableRegisteredMsg(super::TableRegistered),
+    }
+}
+/// the quota or server limit that stopped a statement
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Limit {
+    Unspecified = 0,
+    /// the client already runs as many queries as it may
+    ConcurrentQueries = 1,
+    /// the client started as many queries in the last minute as it may
+    QueryRate = 2,
+    /// the result grew larger than the client may receive
+    ResultBytes = 3,
+    /// the admission queue of the server is full
+    QueueFull = 4,
+    /// the statement ran as long as it may
+    Timeout = 5,
+    /// the result has more rows than the client may receive
+    ResultRows = 6,
+}
+impl Limit {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Limit::Unspecified => "LIMIT_UNSPECIFIED",
+            Limit::ConcurrentQueries => "LIMIT_CONCURRENT_QUERIES",
+            Limit::QueryRate => "LIMIT_QUERY_RATE",
+            Limit::ResultBytes => "LIMIT_RESULT_BYTES",
+            Limit::QueueFull => "LIMIT_QUEUE_FULL",
+            Limit::Timeout => "LIMIT_TIMEOUT",
+            Limit::ResultRows => "LIMIT_RESULT_ROWS",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "LIMIT_UNSPECIFIED" => Some(Self::Unspecified),
+            "LIMIT_CONCURRENT_QUERIES" => Some(Self::ConcurrentQueries),
+            "LIMIT_QUERY_RATE" => Some(Self::QueryRate),
+            "LIMIT_RESULT_BYTES" => Some(Self::ResultBytes),
+            "LIMIT_QUEUE_FULL" => Some(Self::QueueFull),
+            "LIMIT_TIMEOUT" => Some(Self::Timeout),
+            "LIMIT_RESULT_ROWS" => Some(Self::ResultRows),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum CandidateKind {
+    Unspecified = 0,
+    Keyword = 1,
+    Function = 2,
+    Table = 3,
+    Column = 4,
+}
+impl CandidateKind {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            CandidateKind::Unspecified => "CANDIDATE_KIND_UNSPECIFIED",
+            CandidateKind::Keyword => "CANDIDATE_KIND_KEYWORD",
+            CandidateKind::Function => "CANDIDATE_KIND_FUNCTION",
+            CandidateKind::Table => "CANDIDATE_KIND_TABLE",
+            CandidateKind::Column => "CANDIDATE_KIND_COLUMN",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "CANDIDATE_KIND_UNSPECIFIED" => Some(Self::Unspecified),
+            "CANDIDATE_KIND_KEYWORD" => Some(Self::Keyword),
+            "CANDIDATE_KIND_FUNCTION" => Some(Self::Function),
+            "CANDIDATE_KIND_TABLE" => Some(Self::Table),
+            "CANDIDATE_KIND_COLUMN" => Some(Self::Column),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Severity {
+    Unspecified = 0,
+    Error = 1,
+    Warning = 2,
+}
+impl Severity {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Severity::Unspecified => "SEVERITY_UNSPECIFIED",
+            Severity::Error => "SEVERITY_ERROR",
+            Severity::Warning => "SEVERITY_WARNING",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SEVERITY_UNSPECIFIED" => Some(Self::Unspecified),
+            "SEVERITY_ERROR" => Some(Self::Error),
+            "SEVERITY_WARNING" => Some(Self::Warning),
+            _ => None,
+        }
+    }
+}
+/// Encoded file descriptor set for the `query` package
+pub const FILE_DESCRIPTOR_SET: &[u8] = &[
+    0x0a, 0x8d, 0x7b, 0x0a, 0x11, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2f, 0x71, 0x75, 0x65, 0x72, 0x79,
+    0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x05, 0x71, 0x75, 0x65, 0x72, 0x79, 0x22, 0x68, 0x0a,
+    0x0f, 0x53, 0x74, 0x61, 0x74, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x4c, 0x69, 0x6d, 0x69, 0x74, 0x73,
+    0x12, 0x1d, 0x0a, 0x0a, 0x74, 0x69, 0x6d, 0x65, 0x6f, 0x75, 0x74, 0x5f, 0x6d, 0x73, 0x18, 0x01,
+    0x20, 0x01, 0x28, 0x04, 0x52, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x6f, 0x75, 0x74, 0x4d, 0x73, 0x12,
+    0x19, 0x0a, 0x08, 0x6d, 0x61, 0x78, 0x5f, 0x72, 0x6f, 0x77, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28,
+    0x04, 0x52, 0x07, 0x6d, 0x61, 0x78, 0x52, 0x6f, 0x77, 0x73, 0x12, 0x1b, 0x0a, 0x09, 0x6d, 0x61,
+    0x78, 0x5f, 0x62, 0x79, 0x74, 0x65, 0x73, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x08, 0x6d,
+    0x61, 0x78, 0x42, 0x79, 0x74, 0x65, 0x73, 0x22, 0x95, 0x01, 0x0a, 0x0a, 0x45, 0x78, 0x65, 0x63,
+    0x75, 0x74, 0x65, 0x53, 0x71, 0x6c, 0x12, 0x1d, 0x0a, 0x0a, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73,
+    0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x09, 0x72, 0x65, 0x71, 0x75,
+    0x65, 0x73, 0x74, 0x49, 0x64, 0x12, 0x18, 0x0a, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74,
+    0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x12,
+    0x1e, 0x0a, 0x0a, 0x73, 0x74, 0x61, 0x74, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x73, 0x18, 0x03, 0x20,
+    0x03, 0x28, 0x09, 0x52, 0x0a, 0x73, 0x74, 0x61, 0x74, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x73, 0x12,
+    0x2e, 0x0a, 0x06, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32,
+    0x16, 0x2e, 0x71, 0x75, 0x65, 0x72, 0x79, 0x2e, 0x53, 0x74, 0x61, 0x74, 0x65, 0x6d, 0x65, 0x6e,
+    0x74, 0x4c, 0x69, 0x6d, 0x69, 0x74, 0x73, 0x52, 0x06, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x73, 0x22,
+    0x55, 0x0a, 0x06, 0x43, 0x6f, 0x6c, 0x75, 0x6d, 0x6e, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d,
+    0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x1b, 0x0a,
+    0x09, 0x64, 0x61, 0x74, 0x61, 0x5f, 0x74, 0x79, 0x70, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09,
+    0x52, 0x08, 0x64, 0x61, 0x74, 0x61, 0x54, 0x79, 0x70, 0x65, 0x12, 0x1a, 0x0a, 0x08, 0x6e, 0x75,
+    0x6c, 0x6c, 0x61, 0x62, 0x6c, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x08, 0x52, 0x08, 0x6e, 0x75,
+    0x6c, 0x6c, 0x61, 0x62, 0x6c, 0x65, 0x22, 0x5b, 0x0a, 0x04, 0x43, 0x65, 0x6c, 0x6c, 0x12, 0x12,
+    0x0a, 0x04, 0x74, 0x65, 0x78, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x74, 0x65,
+    0x78, 0x74, 0x12, 0x17, 0x0a, 0x07, 0x69, 0x73, 0x5f, 0x6e, 0x75, 0x6c, 0x6c, 0x18, 0x02, 0x20,
+    0x01, 0x28, 0x08, 0x52, 0x06, 0x69, 0x73, 0x4e, 0x75, 0x6c, 0x6c, 0x12, 0x1b, 0x0a, 0x06, 0x6e,
+    0x75, 0x6d, 0x62, 0x65, 0x72, 0x18, 0x03, 0x20, 0x01, 0x28, 0x01, 0x48, 0x00, 0x52, 0x06, 0x6e,
+    0x75, 0x6d, 0x62, 0x65, 0x72, 0x88, 0x01, 0x01, 0x42, 0x09, 0x0a, 0x07, 0x5f, 0x6e, 0x75, 0x6d,
+    0x62, 0x65, 0x72, 0x22, 0x28, 0x0a, 0x03, 0x52, 0x6f, 0x77, 0x12, 0x21, 0x0a, 0x05, 0x63, 0x65,
+    0x6c, 0x6c, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0b, 0x2e, 0x71, 0x75, 0x65, 0x72,
+    0x79, 0x2e, 0x43, 0x65, 0x6c, 0x6c, 0x52, 0x05, 0x63, 0x65, 0x6c, 0x6c, 0x73, 0x22, 0xc1, 0x01,
+    0x0a, 0x09, 0x54, 0x61, 0x62, 0x6c, 0x65, 0x53, 0x63, 0x61, 0x6e, 0x12, 0x14, 0x0a, 0x05, 0x74,
+    0x61, 0x62, 0x6c, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x74, 0x61, 0x62, 0x6c,
+    0x65, 0x12, 0x23, 0x0a, 0x0d, 0x66, 0x69, 0x6c, 0x65, 0x73, 0x5f, 0x73, 0x63, 0x61, 0x6e, 0x6e,
+    0x65, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0c, 0x66, 0x69, 0x6c, 0x65, 0x73, 0x53,
+    0x63, 0x61, 0x6e, 0x6e, 0x65, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x66, 0x69, 0x6c, 0x65, 0x73, 0x5f,
+    0x74, 0x6f, 0x74, 0x61, 0x6c, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0a, 0x66, 0x69, 0x6c,
+    0x65, 0x73, 0x54, 0x6f, 0x74, 0x61, 0x6c, 0x12, 0x2d, 0x0a, 0x12, 0x70, 0x61, 0x72, 0x74, 0x69,
+    0x74, 0x69, 0x6f, 0x6e, 0x73, 0x5f, 0x73, 0x63, 0x61, 0x6e, 0x6e, 0x65, 0x64, 0x18, 0x04, 0x20,
+    0x01, 0x28, 0x04, 0x52, 0x11, 0x70, 0x61, 0x72, 0x74, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x53,
+    0x63, 0x61, 0x6e, 0x6e, 0x65, 0x64, 0x12, 0x29, 0x0a, 0x10, 0x70, 0x61, 0x72, 0x74, 0x69, 0x74,
+    0x69, 0x6f, 0x6e, 0x73, 0x5f, 0x74, 0x6f, 0x74, 0x61, 0x6c, 0x18, 0x05, 0x20, 0x01, 0x28, 0x04,
+    0x52, 0x0f, 0x70, 0x61, 0x72, 0x74, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x54, 0x6f, 0x74, 0x61,
+    0x6c, 0x22, 0xa7, 0x01, 0x0a, 0x0c, 0x52, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x48, 0x65, 0x61, 0x64,
+    0x65, 0x72, 0x12, 0x1d, 0x0a, 0x0a, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x5f, 0x69, 0x64,
+    0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x09, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x49,
+    0x64, 0x12, 0x27, 0x0a, 0x0f, 0x73, 0x74, 0x61, 0x74, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x5f, 0x69,
+    0x6e, 0x64, 0x65, 0x78, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x0e, 0x73, 0x74, 0x61, 0x74,
+    0x65, 0x6d, 0x65, 0x6e, 0x74, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x12, 0x27, 0x0a, 0x07, 0x63, 0x6f,
+    0x6c, 0x75, 0x6d, 0x6e, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0d, 0x2e, 0x71, 0x75,
+    0x65, 0x72, 0x79, 0x2e, 0x43, 0x6f, 0x6c, 0x75, 0x6d, 0x6e, 0x52, 0x07, 0x63, 0x6f, 0x6c, 0x75,
+    0x6d, 0x6e, 0x73, 0x12, 0x26, 0x0a, 0x05, 0x73, 0x63, 0x61, 0x6e, 0x73, 0x18, 0x04, 0x20, 0x03,
+    0x28, 0x0b, 0x32, 0x10, 0x2e, 0x71, 0x75, 0x65, 0x72, 0x79, 0x2e, 0x54, 0x61, 0x62, 0x6c, 0x65,
+    0x53, 0x63, 0x61, 0x6e, 0x52, 0x05, 0x73, 0x63, 0x61, 0x6e, 0x73, 0x22, 0x74, 0x0a, 0x0a, 0x52,
+    0x65, 0x73, 0x75, 0x6c, 0x74, 0x52, 0x6f, 0x77, 0x73, 0x12, 0x1d, 0x0a, 0x0a, 0x72, 0x65, 0x71,
+    0x75, 0x65, 0x73, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x09, 0x72,
+    0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x49, 0x64, 0x12, 0x27, 0x0a, 0x0f, 0x73, 0x74, 0x61, 0x74,
+    0x65, 0x6d, 0x65, 0x6e, 0x74, 0x5f, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x18, 0x02, 0x20, 0x01, 0x28,
+    0x0d, 0x52, 0x0e, 0x73, 0x74, 0x61, 0x74, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x49, 0x6e, 0x64, 0x65,
+    0x78, 0x12, 0x1e, 0x0a, 0x04, 0x72, 0x6f, 0x77, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28, 0x0b, 0x32,
+    0x0a, 0x2e, 0x71, 0x75, 0x65, 0x72, 0x79, 0x2e, 0x52, 0x6f, 0x77, 0x52, 0x04, 0x72, 0x6f, 0x77,
+    0x73, 0x22, 0xf3, 0x01, 0x0a, 0x0d, 0x53, 0x74, 0x61, 0x74, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x44,
+    0x6f, 0x6e, 0x65, 0x12, 0x1d, 0x0a, 0x0a, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x5f, 0x69,
+    0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x09, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+    0x49, 0x64, 0x12, 0x27, 0x0a, 0x0f, 0x73, 0x74, 0x61, 0x74, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x5f,
+    0x69, 0x6e, 0x64, 0x65, 0x78, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x0e, 0x73, 0x74, 0x61,
+    0x74, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x12, 0x1b, 0x0a, 0x09, 0x72,
+    0x6f, 0x77, 0x5f, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x08,
+    0x72, 0x6f, 0x77, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x12, 0x1f, 0x0a, 0x0b, 0x64, 0x75, 0x72, 0x61,
+    0x74, 0x69, 0x6f, 0x6e, 0x5f, 0x6d, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0a, 0x64,
+    0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x4d, 0x73, 0x12, 0x14, 0x0a, 0x05, 0x65, 0x72, 0x72,
+    0x6f, 0x72, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x12,
+    0x22, 0x0a, 0x0d, 0x73, 0x74, 0x61, 0x72, 0x74, 0x65, 0x64, 0x5f, 0x61, 0x74, 0x5f, 0x6d, 0x73,
+    0x18, 0x06, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x73, 0x74, 0x61, 0x72, 0x74, 0x65, 0x64, 0x41,
+    0x74, 0x4d, 0x73, 0x12, 0x22, 0x0a, 0x05, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x18, 0x07, 0x20, 0x01,
+    0x28, 0x0e, 0x32, 0x0c, 0x2e, 0x71, 0x75, 0x65, 0x72, 0x79, 0x2e, 0x4c, 0x69, 0x6d, 0x69, 0x74,
+    0x52, 0x05, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x22, 0x6c, 0x0a, 0x06, 0x51, 0x75, 0x65, 0x75, 0x65,
+    0x64, 0x12, 0x1d, 0x0a, 0x0a, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x5f, 0x69, 0x64, 0x18,
+    0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x09, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x49, 0x64,
+    0x12, 0x27, 0x0a, 0x0f, 0x73, 0x74, 0x61, 0x74, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x5f, 0x69, 0x6e,
+    0x64, 0x65, 0x78, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x0e, 0x73, 0x74, 0x61, 0x74, 0x65,
+    0x6d, 0x65, 0x6e, 0x74, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x12, 0x1a, 0x0a, 0x08, 0x70, 0x6f, 0x73,
+    0x69, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x08, 0x70, 0x6f, 0x73,
+    0x69, 0x74, 0x69, 0x6f, 0x6e, 0x22, 0x2c, 0x0a, 0x0b, 0x45, 0x78, 0x65, 0x63, 0x75, 0x74, 0x65,
+    0x44, 0x6f, 0x6e, 0x65, 0x12, 0x1d, 0x0a, 0x0a, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x5f,
+    0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x09, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73,
+    0x74, 0x49, 0x64, 0x22, 0x70, 0x0a, 0x0b, 0x43, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x53,
+    0x71, 0x6c, 0x12, 0x1d, 0x0a, 0x0a, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x5f, 0x69, 0x64,
+    0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x09, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x49,
+    0x64, 0x12, 0x18, 0x0a, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x18, 0x02, 0x20, 0x01,
+    0x28, 0x09, 0x52, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x12, 0x10, 0x0a, 0x03, 0x73,
+    0x71, 0x6c, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x73, 0x71, 0x6c, 0x12, 0x16, 0x0a,
+    0x06, 0x63, 0x75, 0x72, 0x73, 0x6f, 0x72, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x06, 0x63,
+    0x75, 0x72, 0x73, 0x6f, 0x72, 0x22, 0x61, 0x0a, 0x09, 0x43, 0x61, 0x6e, 0x64, 0x69, 0x64, 0x61,
+    0x74, 0x65, 0x12, 0x12, 0x0a, 0x04, 0x74, 0x65, 0x78, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09,
+    0x52, 0x04, 0x74, 0x65, 0x78, 0x74, 0x12, 0x28, 0x0a, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x18, 0x02,
+    0x20, 0x01, 0x28, 0x0e, 0x32, 0x14, 0x2e, 0x71, 0x75, 0x65, 0x72, 0x79, 0x2e, 0x43, 0x61, 0x6e,
+    0x64, 0x69, 0x64, 0x61, 0x74, 0x65, 0x4b, 0x69, 0x6e, 0x64, 0x52, 0x04, 0x6b, 0x69, 0x6e, 0x64,
+    0x12, 0x16, 0x0a, 0x06, 0x64, 0x65, 0x74, 0x61, 0x69, 0x6c, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09,
+    0x52, 0x06, 0x64, 0x65, 0x74, 0x61, 0x69, 0x6c, 0x22, 0x76, 0x0a, 0x0b, 0x43, 0x6f, 0x6d, 0x70,
+    0x6c, 0x65, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x12, 0x1d, 0x0a, 0x0a, 0x72, 0x65, 0x71, 0x75, 0x65,
+    0x73, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x09, 0x72, 0x65, 0x71,
+    0x75, 0x65, 0x73, 0x74, 0x49, 0x64, 0x12, 0x16, 0x0a, 0x06, 0x70, 0x72, 0x65, 0x66, 0x69, 0x78,
+    0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x70, 0x72, 0x65, 0x66, 0x69, 0x78, 0x12, 0x30,
+    0x0a, 0x0a, 0x63, 0x61, 0x6e, 0x64, 0x69, 0x64, 0x61, 0x74, 0x65, 0x73, 0x18, 0x03, 0x20, 0x03,
+    0x28, 0x0b, 0x32, 0x10, 0x2e, 0x71, 0x75, 0x65, 0x72, 0x79, 0x2e, 0x43, 0x61, 0x6e, 0x64, 0x69,
+    0x64, 0x61, 0x74, 0x65, 0x52, 0x0a, 0x63, 0x61, 0x6e, 0x64, 0x69, 0x64, 0x61, 0x74, 0x65, 0x73,
+    0x22, 0x58, 0x0a, 0x0b, 0x56, 0x61, 0x6c, 0x69, 0x64, 0x61, 0x74, 0x65, 0x53, 0x71, 0x6c, 0x12,
+    0x1d, 0x0a, 0x0a, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20,
+    0x01, 0x28, 0x04, 0x52, 0x09, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x49, 0x64, 0x12, 0x18,
+    0x0a, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52,
+    0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x12, 0x10, 0x0a, 0x03, 0x73, 0x71, 0x6c, 0x18,
+    0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x73, 0x71, 0x6c, 0x22, 0x82, 0x01, 0x0a, 0x04, 0x53,
+    0x70, 0x61, 0x6e, 0x12, 0x1d, 0x0a, 0x0a, 0x73, 0x74, 0x61, 0x72, 0x74, 0x5f, 0x6c, 0x69, 0x6e,
+    0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x09, 0x73, 0x74, 0x61, 0x72, 0x74, 0x4c, 0x69,
+    0x6e, 0x65, 0x12, 0x21, 0x0a, 0x0c, 0x73, 0x74, 0x61, 0x72, 0x74, 0x5f, 0x63, 0x6f, 0x6c, 0x75,
+    0x6d, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x0b, 0x73, 0x74, 0x61, 0x72, 0x74, 0x43,
+    0x6f, 0x6c, 0x75, 0x6d, 0x6e, 0x12, 0x19, 0x0a, 0x08, 0x65, 0x6e, 0x64, 0x5f, 0x6c, 0x69, 0x6e,
+    0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x07, 0x65, 0x6e, 0x64, 0x4c, 0x69, 0x6e, 0x65,
+    0x12, 0x1d, 0x0a, 0x0a, 0x65, 0x6e, 0x64, 0x5f, 0x63, 0x6f, 0x6c, 0x75, 0x6d, 0x6e, 0x18, 0x04,
+    0x20, 0x01, 0x28, 0x0d, 0x52, 0x09, 0x65, 0x6e, 0x64, 0x43, 0x6f, 0x6c, 0x75, 0x6d, 0x6e, 0x22,
+    0x74, 0x0a, 0x0a, 0x44, 0x69, 0x61, 0x67, 0x6e, 0x6f, 0x73, 0x74, 0x69, 0x63, 0x12, 0x2b, 0x0a,
+    0x08, 0x73, 0x65, 0x76, 0x65, 0x72, 0x69, 0x74, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32,
+    0x0f, 0x2e, 0x71, 0x75, 0x65, 0x72, 0x79, 0x2e, 0x53, 0x65, 0x76, 0x65, 0x72, 0x69, 0x74, 0x79,
+    0x52, 0x08, 0x73, 0x65, 0x76, 0x65, 0x72, 0x69, 0x74, 0x79, 0x12, 0x18, 0x0a, 0x07, 0x6d, 0x65,
+    0x73, 0x73, 0x61, 0x67, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x6d, 0x65, 0x73,
+    0x73, 0x61, 0x67, 0x65, 0x12, 0x1f, 0x0a, 0x04, 0x73, 0x70, 0x61, 0x6e, 0x18, 0x03, 0x20, 0x01,
+    0x28, 0x0b, 0x32, 0x0b, 0x2e, 0x71, 0x75, 0x65, 0x72, 0x79, 0x2e, 0x53, 0x70, 0x61, 0x6e, 0x52,
+    0x04, 0x73, 0x70, 0x61, 0x6e, 0x22, 0x61, 0x0a, 0x0b, 0x44, 0x69, 0x61, 0x67, 0x6e, 0x6f, 0x73,
+    0x74, 0x69, 0x63, 0x73, 0x12, 0x1d, 0x0a, 0x0a, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x5f,
+    0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x09, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73,
+    0x74, 0x49, 0x64, 0x12, 0x33, 0x0a, 0x0b, 0x64, 0x69, 0x61, 0x67, 0x6e, 0x6f, 0x73, 0x74, 0x69,
+    0x63, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x11, 0x2e, 0x71, 0x75, 0x65, 0x72, 0x79,
+    0x2e, 0x44, 0x69, 0x61, 0x67, 0x6e, 0x6f, 0x73, 0x74, 0x69, 0x63, 0x52, 0x0b, 0x64, 0x69, 0x61,
+    0x67, 0x6e, 0x6f, 0x73, 0x74, 0x69, 0x63, 0x73, 0x22, 0xb2, 0x01, 0x0a, 0x0c, 0x48, 0x69, 0x73,
+    0x74, 0x6f, 0x72, 0x79, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x12, 0x10, 0x0a, 0x03, 0x73, 0x71, 0x6c,
+    0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x73, 0x71, 0x6c, 0x12, 0x18, 0x0a, 0x07, 0x63,
+    0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x63, 0x6f,
+    0x6e, 0x74, 0x65, 0x78, 0x74, 0x12, 0x22, 0x0a, 0x0d, 0x73, 0x74, 0x61, 0x72, 0x74, 0x65, 0x64,
+    0x5f, 0x61, 0x74, 0x5f, 0x6d, 0x73, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x73, 0x74,
+    0x61, 0x72, 0x74, 0x65, 0x64, 0x41, 0x74, 0x4d, 0x73, 0x12, 0x1f, 0x0a, 0x0b, 0x64, 0x75, 0x72,
+    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x5f, 0x6d, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0a,
+    0x64, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x4d, 0x73, 0x12, 0x1b, 0x0a, 0x09, 0x72, 0x6f,
+    0x77, 0x5f, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x52, 0x08, 0x72,
+    0x6f, 0x77, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x12, 0x14, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72,
+    0x18, 0x06, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x73, 0x0a,
+    0x0a, 0x47, 0x65, 0x74, 0x48, 0x69, 0x73, 0x74, 0x6f, 0x72, 0x79, 0x12, 0x1d, 0x0a, 0x0a, 0x72,
+    0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52,
+    0x09, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x49, 0x64, 0x12, 0x18, 0x0a, 0x07, 0x63, 0x6f,
+    0x6e, 0x74, 0x65, 0x78, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x63, 0x6f, 0x6e,
+    0x74, 0x65, 0x78, 0x74, 0x12, 0x16, 0x0a, 0x06, 0x73, 0x65, 0x61, 0x72, 0x63, 0x68, 0x18, 0x03,
+    0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x73, 0x65, 0x61, 0x72, 0x63, 0x68, 0x12, 0x14, 0x0a, 0x05,
+    0x6c, 0x69, 0x6d, 0x69, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x05, 0x6c, 0x69, 0x6d,
+    0x69, 0x74, 0x22, 0x6d, 0x0a, 0x07, 0x48, 0x69, 0x73, 0x74, 0x6f, 0x72, 0x79, 0x12, 0x1d, 0x0a,
+    0x0a, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28,
+    0x04, 0x52, 0x09, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x49, 0x64, 0x12, 0x2d, 0x0a, 0x07,
+    0x65, 0x6e, 0x74, 0x72, 0x69, 0x65, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x13, 0x2e,
+    0x71, 0x75, 0x65, 0x72, 0x79, 0x2e, 0x48, 0x69, 0x73, 0x74, 0x6f, 0x72, 0x79, 0x45, 0x6e, 0x74,
+    0x72, 0x79, 0x52, 0x07, 0x65, 0x6e, 0x74, 0x72, 0x69, 0x65, 0x73, 0x12, 0x14, 0x0a, 0x05, 0x65,
+    0x72, 0x72, 0x6f, 0x72, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f,
+    0x72, 0x22, 0x66, 0x0a, 0x0c, 0x43, 0x61, 0x74, 0x61, 0x6c, 0x6f, 0x67, 0x49, 0x73, 0x73, 0x75,
+    0x65, 0x12, 0x18, 0x0a, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x18, 0x01, 0x20, 0x01,
+    0x28, 0x09, 0x52, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x12, 0x12, 0x0a, 0x04, 0x6b,
+    0x69, 0x6e, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x12,
+    0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e,
+    0x61, 0x6d, 0x65, 0x12, 0x14, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x04, 0x20, 0x01,
+    0x28, 0x09, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x58, 0x0a, 0x0d, 0x43, 0x61, 0x74,
+    0x61, 0x6c, 0x6f, 0x67, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x1a, 0x0a, 0x08, 0x63, 0x6f,
+    0x6e, 0x74, 0x65, 0x78, 0x74, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x09, 0x52, 0x08, 0x63, 0x6f,
+    0x6e, 0x74, 0x65, 0x78, 0x74, 0x73, 0x12, 0x2b, 0x0a, 0x06, 0x69, 0x73, 0x73, 0x75, 0x65, 0x73,
+    0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x13, 0x2e, 0x71, 0x75, 0x65, 0x72, 0x79, 0x2e, 0x43,
+    0x61, 0x74, 0x61, 0x6c, 0x6f, 0x67, 0x49, 0x73, 0x73, 0x75, 0x65, 0x52, 0x06, 0x69, 0x73, 0x73,
+    0x75, 0x65, 0x73, 0x22, 0x42, 0x0a, 0x0f, 0x50, 0x61, 0x72, 0x74, 0x69, 0x74, 0x69, 0x6f, 0x6e,
+    0x43, 0x6f, 0x6c, 0x75, 0x6d, 0x6e, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01,
+    0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x1b, 0x0a, 0x09, 0x64, 0x61,
+    0x74, 0x61, 0x5f, 0x74, 0x79, 0x70, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x64,
+    0x61, 0x74, 0x61, 0x54, 0x79, 0x70, 0x65, 0x22, 0x61, 0x0a, 0x0a, 0x53, 0x6f, 0x72, 0x74, 0x43,
+    0x6f, 0x6c, 0x75, 0x6d, 0x6e, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20,
+    0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x1e, 0x0a, 0x0a, 0x64, 0x65, 0x73,
+    0x63, 0x65, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x18, 0x02, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0a, 0x64,
+    0x65, 0x73, 0x63, 0x65, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x12, 0x1f, 0x0a, 0x0b, 0x6e, 0x75, 0x6c,
+    0x6c, 0x73, 0x5f, 0x66, 0x69, 0x72, 0x73, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0a,
+    0x6e, 0x75, 0x6c, 0x6c, 0x73, 0x46, 0x69, 0x72, 0x73, 0x74, 0x22, 0xec, 0x02, 0x0a, 0x0d, 0x52,
+    0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x54, 0x61, 0x62, 0x6c, 0x65, 0x12, 0x1d, 0x0a, 0x0a,
+    0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04,
+    0x52, 0x09, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x49, 0x64, 0x12, 0x18, 0x0a, 0x07, 0x63,
+    0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x63, 0x6f,
+    0x6e, 0x74, 0x65, 0x78, 0x74, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20,
+    0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x1a, 0x0a, 0x08, 0x6c, 0x6f, 0x63,
+    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x6c, 0x6f, 0x63,
+    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x1b, 0x0a, 0x09, 0x66, 0x69, 0x6c, 0x65, 0x5f, 0x74, 0x79,
+    0x70, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x66, 0x69, 0x6c, 0x65, 0x54, 0x79,
+    0x70, 0x65, 0x12, 0x43, 0x0a, 0x11, 0x70, 0x61, 0x72, 0x74, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x5f,
+    0x63, 0x6f, 0x6c, 0x75, 0x6d, 0x6e, 0x73, 0x18, 0x06, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x16, 0x2e,
+    0x71, 0x75, 0x65, 0x72, 0x79, 0x2e, 0x50, 0x61, 0x72, 0x74, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x43,
+    0x6f, 0x6c, 0x75, 0x6d, 0x6e, 0x52, 0x10, 0x70, 0x61, 0x72, 0x74, 0x69, 0x74, 0x69, 0x6f, 0x6e,
+    0x43, 0x6f, 0x6c, 0x75, 0x6d, 0x6e, 0x73, 0x12, 0x25, 0x0a, 0x0e, 0x66, 0x69, 0x6c, 0x65, 0x5f,
+    0x65, 0x78, 0x74, 0x65, 0x6e, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x07, 0x20, 0x01, 0x28, 0x09, 0x52,
+    0x0d, 0x66, 0x69, 0x6c, 0x65, 0x45, 0x78, 0x74, 0x65, 0x6e, 0x73, 0x69, 0x6f, 0x6e, 0x12, 0x30,
+    0x0a, 0x0a, 0x73, 0x6f, 0x72, 0x74, 0x5f, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x18, 0x08, 0x20, 0x03,
+    0x28, 0x0b, 0x32, 0x11, 0x2e, 0x71, 0x75, 0x65, 0x72, 0x79, 0x2e, 0x53, 0x6f, 0x72, 0x74, 0x43,
+    0x6f, 0x6c, 0x75, 0x6d, 0x6e, 0x52, 0x09, 0x73, 0x6f, 0x72, 0x74, 0x4f, 0x72, 0x64, 0x65, 0x72,
+    0x12, 0x1d, 0x0a, 0x0a, 0x68, 0x61, 0x73, 0x5f, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72, 0x18, 0x09,
+    0x20, 0x01, 0x28, 0x08, 0x52, 0x09, 0x68, 0x61, 0x73, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x12,
+    0x18, 0x0a, 0x07, 0x72, 0x65, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x08,
+    0x52, 0x07, 0x72, 0x65, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x22, 0x7c, 0x0a, 0x0f, 0x54, 0x61, 0x62,
+    0x6c, 0x65, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x65, 0x64, 0x12, 0x1d, 0x0a, 0x0a,
+    0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04,
+    0x52, 0x09, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x49, 0x64, 0x12, 0x14, 0x0a, 0x05, 0x65,
+    0x72, 0x72, 0x6f, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f,
+    0x72, 0x12, 0x14, 0x0a, 0x05, 0x66, 0x69, 0x6c, 0x65, 0x73, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04,
+    0x52, 0x05, 0x66, 0x69, 0x6c, 0x65, 0x73, 0x12, 0x1e, 0x0a, 0x0a, 0x70, 0x61, 0x72, 0x74, 0x69,
+    0x74, 0x69, 0x6f, 0x6e, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0a, 0x70, 0x61, 0x72,
+    0x74, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x22, 0x9a, 0x07, 0x0a, 0x0c, 0x51, 0x75, 0x65, 0x72,
+    0x79, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x12, 0x33, 0x0a, 0x0a, 0x65, 0x78, 0x65, 0x63,
+    0x75, 0x74, 0x65, 0x4d, 0x73, 0x67, 0x18, 0x64, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x11, 0x2e, 0x71,
+    0x75, 0x65, 0x72, 0x79, 0x2e, 0x45, 0x78, 0x65, 0x63, 0x75, 0x74, 0x65, 0x53, 0x71, 0x6c, 0x48,
+    0x00, 0x52, 0x0a, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x65, 0x4d, 0x73, 0x67, 0x12, 0x3f, 0x0a,
+    0x0f, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x4d, 0x73, 0x67,
+    0x18, 0x65, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x13, 0x2e, 0x71, 0x75, 0x65, 0x72, 0x79, 0x2e, 0x52,
+    0x65, 0x73, 0x75, 0x6c, 0x74, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x48, 0x00, 0x52, 0x0f, 0x72,
+    0x65, 0x73, 0x75, 0x6c, 0x74, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x4d, 0x73, 0x67, 0x12, 0x39,
+    0x0a, 0x0d, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x52, 0x6f, 0x77, 0x73, 0x4d, 0x73, 0x67, 0x18,
+    0x66, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x11, 0x2e, 0x71, 0x75, 0x65, 0x72, 0x79, 0x2e, 0x52, 0x65,
+    0x73, 0x75, 0x6c, 0x74, 0x52, 0x6f, 0x77, 0x73, 0x48, 0x00, 0x52, 0x0d, 0x72, 0x65, 0x73, 0x75,
+    0x6c, 0x74, 0x52, 0x6f, 0x77, 0x73, 0x4d, 0x73, 0x67, 0x12, 0x42, 0x0a, 0x10, 0x73, 0x74, 0x61,
+    0x74, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x44, 0x6f, 0x6e, 0x65, 0x4d, 0x73, 0x67, 0x18, 0x67, 0x20,
+    0x01, 0x28, 0x0b, 0x32, 0x14, 0x2e, 0x71, 0x75, 0x65, 0x72, 0x79, 0x2e, 0x53, 0x74, 0x61, 0x74,
+    0x65, 0x6d, 0x65, 0x6e, 0x74, 0x44, 0x6f, 0x6e, 0x65, 0x48, 0x00, 0x52, 0x10, 0x73, 0x74, 0x61,
+    0x74, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x44, 0x6f, 0x6e, 0x65, 0x4d, 0x73, 0x67, 0x12, 0x3c, 0x0a,
+    0x0e, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x65, 0x44, 0x6f, 0x6e, 0x65, 0x4d, 0x73, 0x67, 0x18,
+    0x68, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x12, 0x2e, 0x71, 0x75, 0x65, 0x72, 0x79, 0x2e, 0x45, 0x78,
+    0x65, 0x63, 0x75, 0x74, 0x65, 0x44, 0x6f, 0x6e, 0x65, 0x48, 0x00, 0x52, 0x0e, 0x65, 0x78, 0x65,
+    0x63, 0x75, 0x74, 0x65, 0x44, 0x6f, 0x6e, 0x65, 0x4d, 0x73, 0x67, 0x12, 0x36, 0x0a, 0x0b, 0x63,
+    0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x4d, 0x73, 0x67, 0x18, 0x69, 0x20, 0x01, 0x28, 0x0b,
+    0x32, 0x12, 0x2e, 0x71, 0x75, 0x65, 0x72, 0x79, 0x2e, 0x43, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74,
+    0x65, 0x53, 0x71, 0x6c, 0x48, 0x00, 0x52, 0x0b, 0x63, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65,
+    0x4d, 0x73, 0x67, 0x12, 0x3c, 0x0a, 0x0e, 0x63, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x69, 0x6f,
+    0x6e, 0x73, 0x4d, 0x73, 0x67, 0x18, 0x6a, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x12, 0x2e, 0x71, 0x75,
+    0x65, 0x72, 0x79, 0x2e, 0x43, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x48,
+    0x00, 0x52, 0x0e, 0x63, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x4d, 0x73,
+    0x67, 0x12, 0x36, 0x0a, 0x0b, 0x76, 0x61, 0x6c, 0x69, 0x64, 0x61, 0x74, 0x65, 0x4d, 0x73, 0x67,
+    0x18, 0x6b, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x12, 0x2e, 0x71, 0x75, 0x65, 0x72, 0x79, 0x2e, 0x56,
+    0x61, 0x6c, 0x69, 0x64, 0x61, 0x74, 0x65, 0x53, 0x71, 0x6c, 0x48, 0x00, 0x52, 0x0b, 0x76, 0x61,
+    0x6c, 0x69, 0x64, 0x61, 0x74, 0x65, 0x4d, 0x73, 0x67, 0x12, 0x3c, 0x0a, 0x0e, 0x64, 0x69, 0x61,
+    0x67, 0x6e, 0x6f, 0x73, 0x74, 0x69, 0x63, 0x73, 0x4d, 0x73, 0x67, 0x18, 0x6c, 0x20, 0x01, 0x28,
+    0x0b, 0x32, 0x12, 0x2e, 0x71, 0x75, 0x65, 0x72, 0x79, 0x2e, 0x44, 0x69, 0x61, 0x67, 0x6e, 0x6f,
+    0x73, 0x74, 0x69, 0x63, 0x73, 0x48, 0x00, 0x52, 0x0e, 0x64, 0x69, 0x61, 0x67, 0x6e, 0x6f, 0x73,
+    0x74, 0x69, 0x63, 0x73, 0x4d, 0x73, 0x67, 0x12, 0x39, 0x0a, 0x0d, 0x67, 0x65, 0x74, 0x48, 0x69,
+    0x73, 0x74, 0x6f, 0x72, 0x79, 0x4d, 0x73, 0x67, 0x18, 0x6d, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x11,
+    0x2e, 0x71, 0x75, 0x65, 0x72, 0x79, 0x2e, 0x47, 0x65, 0x74, 0x48, 0x69, 0x73, 0x74, 0x6f, 0x72,
+    0x79, 0x48, 0x00, 0x52, 0x0d, 0x67, 0x65, 0x74, 0x48, 0x69, 0x73, 0x74, 0x6f, 0x72, 0x79, 0x4d,
+    0x73, 0x67, 0x12, 0x30, 0x0a, 0x0a, 0x68, 0x69, 0x73, 0x74, 0x6f, 0x72, 0x79, 0x4d, 0x73, 0x67,
+    0x18, 0x6e, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x71, 0x75, 0x65, 0x72, 0x79, 0x2e, 0x48,
+    0x69, 0x73, 0x74, 0x6f, 0x72, 0x79, 0x48, 0x00, 0x52, 0x0a, 0x68, 0x69, 0x73, 0x74, 0x6f, 0x72,
+    0x79, 0x4d, 0x73, 0x67, 0x12, 0x2d, 0x0a, 0x09, 0x71, 0x75, 0x65, 0x75, 0x65, 0x64, 0x4d, 0x73,
+    0x67, 0x18, 0x6f, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0d, 0x2e, 0x71, 0x75, 0x65, 0x72, 0x79, 0x2e,
+    0x51, 0x75, 0x65, 0x75, 0x65, 0x64, 0x48, 0x00, 0x52, 0x09, 0x71, 0x75, 0x65, 0x75, 0x65, 0x64,
+    0x4d, 0x73, 0x67, 0x12, 0x36, 0x0a, 0x0a, 0x63, 0x61, 0x74, 0x61, 0x6c, 0x6f, 0x67, 0x4d, 0x73,
+    0x67, 0x18, 0x70, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x14, 0x2e, 0x71, 0x75, 0x65, 0x72, 0x79, 0x2e,
+    0x43, 0x61, 0x74, 0x61, 0x6c, 0x6f, 0x67, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x48, 0x00, 0x52,
+    0x0a, 0x63, 0x61, 0x74, 0x61, 0x6c, 0x6f, 0x67, 0x4d, 0x73, 0x67, 0x12, 0x42, 0x0a, 0x10, 0x72,
+    0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x54, 0x61, 0x62, 0x6c, 0x65, 0x4d, 0x73, 0x67, 0x18,
+    0x71, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x14, 0x2e, 0x71, 0x75, 0x65, 0x72, 0x79, 0x2e, 0x52, 0x65,
+    0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x54, 0x61, 0x62, 0x6c, 0x65, 0x48, 0x00, 0x52, 0x10, 0x72,
+    0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x54, 0x61, 0x62, 0x6c, 0x65, 0x4d, 0x73, 0x67, 0x12,
+    0x48, 0x0a, 0x12, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72,
+    0x65, 0x64, 0x4d, 0x73, 0x67, 0x18, 0x72, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x16, 0x2e, 0x71, 0x75,
+    0x65, 0x72, 0x79, 0x2e, 0x54, 0x61, 0x62, 0x6c, 0x65, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74, 0x65,
+    0x72, 0x65, 0x64, 0x48, 0x00, 0x52, 0x12, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x52, 0x65, 0x67, 0x69,
+    0x73, 0x74, 0x65, 0x72, 0x65, 0x64, 0x4d, 0x73, 0x67, 0x42, 0x09, 0x0a, 0x07, 0x73, 0x65, 0x72,
+    0x76, 0x69, 0x63, 0x65, 0x2a, 0xaa, 0x01, 0x0a, 0x05, 0x4c, 0x69, 0x6d, 0x69, 0x74, 0x12, 0x15,
+    0x0a, 0x11, 0x4c, 0x49, 0x4d, 0x49, 0x54, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46,
+    0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x1c, 0x0a, 0x18, 0x4c, 0x49, 0x4d, 0x49, 0x54, 0x5f, 0x43,
+    0x4f, 0x4e, 0x43, 0x55, 0x52, 0x52, 0x45, 0x4e, 0x54, 0x5f, 0x51, 0x55, 0x45, 0x52, 0x49, 0x45,
+    0x53, 0x10, 0x01, 0x12, 0x14, 0x0a, 0x10, 0x4c, 0x49, 0x4d, 0x49, 0x54, 0x5f, 0x51, 0x55, 0x45,
+    0x52, 0x59, 0x5f, 0x52, 0x41, 0x54, 0x45, 0x10, 0x02, 0x12, 0x16, 0x0a, 0x12, 0x4c, 0x49, 0x4d,
+    0x49, 0x54, 0x5f, 0x52, 0x45, 0x53, 0x55, 0x4c, 0x54, 0x5f, 0x42, 0x59, 0x54, 0x45, 0x53, 0x10,
+    0x03, 0x12, 0x14, 0x0a, 0x10, 0x4c, 0x49, 0x4d, 0x49, 0x54, 0x5f, 0x51, 0x55, 0x45, 0x55, 0x45,
+    0x5f, 0x46, 0x55, 0x4c, 0x4c, 0x10, 0x04, 0x12, 0x11, 0x0a, 0x0d, 0x4c, 0x49, 0x4d, 0x49, 0x54,
+    0x5f, 0x54, 0x49, 0x4d, 0x45, 0x4f, 0x55, 0x54, 0x10, 0x05, 0x12, 0x15, 0x0a, 0x11, 0x4c, 0x49,
+    0x4d, 0x49, 0x54, 0x5f, 0x52, 0x45, 0x53, 0x55, 0x4c, 0x54, 0x5f, 0x52, 0x4f, 0x57, 0x53, 0x10,
+    0x06, 0x2a, 0x9d, 0x01, 0x0a, 0x0d, 0x43, 0x61, 0x6e, 0x64, 0x69, 0x64, 0x61, 0x74, 0x65, 0x4b,
+    0x69, 0x6e, 0x64, 0x12, 0x1e, 0x0a, 0x1a, 0x43, 0x41, 0x4e, 0x44, 0x49, 0x44, 0x41, 0x54, 0x45,
+    0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45,
+    0x44, 0x10, 0x00, 0x12, 0x1a, 0x0a, 0x16, 0x43, 0x41, 0x4e, 0x44, 0x49, 0x44, 0x41, 0x54, 0x45,
+    0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x4b, 0x45, 0x59, 0x57, 0x4f, 0x52, 0x44, 0x10, 0x01, 0x12,
+    0x1b, 0x0a, 0x17, 0x43, 0x41, 0x4e, 0x44, 0x49, 0x44, 0x41, 0x54, 0x45, 0x5f, 0x4b, 0x49, 0x4e,
+    0x44, 0x5f, 0x46, 0x55, 0x4e, 0x43, 0x54, 0x49, 0x4f, 0x4e, 0x10, 0x02, 0x12, 0x18, 0x0a, 0x14,
+    0x43, 0x41, 0x4e, 0x44, 0x49, 0x44, 0x41, 0x54, 0x45, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x54,
+    0x41, 0x42, 0x4c, 0x45, 0x10, 0x03, 0x12, 0x19, 0x0a, 0x15, 0x43, 0x41, 0x4e, 0x44, 0x49, 0x44,
+    0x41, 0x54, 0x45, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x43, 0x4f, 0x4c, 0x55, 0x4d, 0x4e, 0x10,
+    0x04, 0x2a, 0x4e, 0x0a, 0x08, 0x53, 0x65, 0x76, 0x65, 0x72, 0x69, 0x74, 0x79, 0x12, 0x18, 0x0a,
+    0x14, 0x53, 0x45, 0x56, 0x45, 0x52, 0x49, 0x54, 0x59, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43,
+    0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x12, 0x0a, 0x0e, 0x53, 0x45, 0x56, 0x45, 0x52,
+    0x49, 0x54, 0x59, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x10, 0x01, 0x12, 0x14, 0x0a, 0x10, 0x53,
+    0x45, 0x56, 0x45, 0x52, 0x49, 0x54, 0x59, 0x5f, 0x57, 0x41, 0x52, 0x4e, 0x49, 0x4e, 0x47, 0x10,
+    0x02, 0x4a, 0xe4, 0x55, 0x0a, 0x07, 0x12, 0x05, 0x00, 0x00, 0x93, 0x02, 0x01, 0x0a, 0x08, 0x0a,
+    0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x02, 0x00,
+    0x0e, 0x0a, 0xba, 0x02, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x09, 0x00, 0x0f, 0x01, 0x1a, 0x5a,
+    0x20, 0x63, 0x61, 0x70, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x65, 0x76, 0x65, 0x72, 0x79, 0x20, 0x73,
+    0x74, 0x61, 0x74, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x20, 0x72, 0x65,
+    0x71, 0x75, 0x65, 0x73, 0x74, 0x2c, 0x20, 0x30, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x6e, 0x6f, 0x6e,
+    0x65, 0x2c, 0x20, 0x6c, 0x6f, 0x77, 0x65, 0x72, 0x20, 0x6f, 0x6e, 0x65, 0x73, 0x20, 0x6f, 0x66,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x20, 0x61, 0x70, 0x70, 0x6c,
+    0x79, 0x20, 0x61, 0x73, 0x20, 0x77, 0x65, 0x6c, 0x6c, 0x0a, 0x32, 0xd1, 0x01, 0x20, 0x6f, 0x6e,
+    0x65, 0x6f, 0x66, 0x20, 0x74, 0x61, 0x67, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x2a, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x20, 0x77, 0x72, 0x61, 0x70, 0x70, 0x65, 0x72,
+    0x73, 0x20, 0x6d, 0x75, 0x73, 0x74, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x6f, 0x76, 0x65, 0x72, 0x6c,
+    0x61, 0x70, 0x20, 0x62, 0x65, 0x74, 0x77, 0x65, 0x65, 0x6e, 0x20, 0x70, 0x61, 0x63, 0x6b, 0x61,
+    0x67, 0x65, 0x73, 0x3a, 0x0a, 0x20, 0x65, 0x76, 0x65, 0x72, 0x79, 0x20, 0x77, 0x65, 0x62, 0x73,
+    0x6f, 0x63, 0x6b, 0x65, 0x74, 0x20, 0x66, 0x72, 0x61, 0x6d, 0x65, 0x20, 0x69, 0x73, 0x20, 0x74,
+    0x72, 0x69, 0x65, 0x64, 0x20, 0x61, 0x67, 0x61, 0x69, 0x6e, 0x73, 0x74, 0x20, 0x65, 0x61, 0x63,
+    0x68, 0x20, 0x77, 0x72, 0x61, 0x70, 0x70, 0x65, 0x72, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x75, 0x72,
+    0x6e, 0x2c, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x74, 0x68, 0x65, 0x0a,
+    0x20, 0x6f, 0x6e, 0x65, 0x20, 0x69, 0x74, 0x20, 0x77, 0x61, 0x73, 0x20, 0x62, 0x75, 0x69, 0x6c,
+    0x74, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x6d, 0x61, 0x79, 0x20, 0x64, 0x65, 0x63, 0x6f, 0x64,
+    0x65, 0x20, 0x69, 0x6e, 0x74, 0x6f, 0x20, 0x61, 0x20, 0x6e, 0x6f, 0x6e, 0x2d, 0x65, 0x6d, 0x70,
+    0x74, 0x79, 0x20, 0x60, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x60, 0x2e, 0x0a, 0x0a, 0x0a,
+    0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x09, 0x08, 0x17, 0x0a, 0x42, 0x0a, 0x04, 0x04, 0x00,
+    0x02, 0x00, 0x12, 0x03, 0x0b, 0x02, 0x18, 0x1a, 0x35, 0x20, 0x77, 0x61, 0x6c, 0x6c, 0x20, 0x63,
+    0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x74,
+    0x61, 0x74, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x20, 0x6d, 0x61, 0x79, 0x20, 0x72, 0x75, 0x6e, 0x20,
+    0x6f, 0x6e, 0x63, 0x65, 0x20, 0x61, 0x64, 0x6d, 0x69, 0x74, 0x74, 0x65, 0x64, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x0b, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0b, 0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x00, 0x03, 0x12, 0x03, 0x0b, 0x16, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01,
+    0x12, 0x03, 0x0c, 0x02, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03,
+    0x0c, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0c, 0x09,
+    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x0c, 0x14, 0x15, 0x0a,
+    0x39, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x0e, 0x02, 0x17, 0x1a, 0x2c, 0x20, 0x65,
+    0x6e, 0x63, 0x6f, 0x64, 0x65, 0x64, 0x20, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x20, 0x72, 0x6f,
+    0x77, 0x73, 0x20, 0x73, 0x74, 0x72, 0x65, 0x61, 0x6d, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x63, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x02, 0x05, 0x12, 0x03, 0x0e, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02,
+    0x01, 0x12, 0x03, 0x0e, 0x09, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12,
+    0x03, 0x0e, 0x15, 0x16, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x11, 0x00, 0x17, 0x01,
+    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x11, 0x08, 0x12, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x12, 0x02, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x00, 0x05, 0x12, 0x03, 0x12, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x12, 0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03,
+    0x12, 0x16, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x13, 0x02, 0x15,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x05, 0x12, 0x03, 0x13, 0x02, 0x08, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x13, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x13, 0x13, 0x14, 0x0a, 0x50, 0x0a, 0x04, 0x04, 0x01,
+    0x02, 0x02, 0x12, 0x03, 0x15, 0x02, 0x21, 0x1a, 0x43, 0x20, 0x73, 0x74, 0x61, 0x74, 0x65, 0x6d,
+    0x65, 0x6e, 0x74, 0x73, 0x20, 0x72, 0x75, 0x6e, 0x20, 0x69, 0x6e, 0x20, 0x6f, 0x72, 0x64, 0x65,
+    0x72, 0x2c, 0x20, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x73, 0x74, 0x6f,
+    0x70, 0x73, 0x20, 0x61, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x69, 0x72, 0x73, 0x74, 0x20,
+    0x66, 0x61, 0x69, 0x6c, 0x69, 0x6e, 0x67, 0x20, 0x6f, 0x6e, 0x65, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x02, 0x04, 0x12, 0x03, 0x15, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x02, 0x05, 0x12, 0x03, 0x15, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02,
+    0x01, 0x12, 0x03, 0x15, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x03, 0x12,
+    0x03, 0x15, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x03, 0x12, 0x03, 0x16, 0x02,
+    0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x06, 0x12, 0x03, 0x16, 0x02, 0x11, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x01, 0x12, 0x03, 0x16, 0x12, 0x18, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x03, 0x03, 0x12, 0x03, 0x16, 0x1b, 0x1c, 0x0a, 0x0a, 0x0a, 0x02, 0x04,
+    0x02, 0x12, 0x04, 0x19, 0x00, 0x1d, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03,
+    0x19, 0x08, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x1a, 0x02, 0x12,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x05, 0x12, 0x03, 0x1a, 0x02, 0x08, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1a, 0x09, 0x0d, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x1a, 0x10, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02,
+    0x02, 0x01, 0x12, 0x03, 0x1b, 0x02, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x05,
+    0x12, 0x03, 0x1b, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03,
+    0x1b, 0x09, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x03, 0x12, 0x03, 0x1b, 0x15,
+    0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x02, 0x12, 0x03, 0x1c, 0x02, 0x14, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x05, 0x12, 0x03, 0x1c, 0x02, 0x06, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x02, 0x02, 0x02, 0x01, 0x12, 0x03, 0x1c, 0x07, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
+    0x02, 0x02, 0x03, 0x12, 0x03, 0x1c, 0x12, 0x13, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04,
+    0x1f, 0x00, 0x25, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x1f, 0x08, 0x0c,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x20, 0x02, 0x12, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x00, 0x05, 0x12, 0x03, 0x20, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x20, 0x09, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x00, 0x03, 0x12, 0x03, 0x20, 0x10, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x01, 0x12,
+    0x03, 0x21, 0x02, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x05, 0x12, 0x03, 0x21,
+    0x02, 0x06, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03, 0x21, 0x07, 0x0e,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03, 0x12, 0x03, 0x21, 0x11, 0x12, 0x0a, 0x8e,
+    0x01, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x02, 0x12, 0x03, 0x24, 0x02, 0x1d, 0x1a, 0x80, 0x01, 0x20,
+    0x73, 0x65, 0x74, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x6e, 0x75, 0x6d, 0x65, 0x72, 0x69, 0x63, 0x20,
+    0x63, 0x6f, 0x6c, 0x75, 0x6d, 0x6e, 0x73, 0x20, 0x73, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63,
+    0x6c, 0x69, 0x65, 0x6e, 0x74, 0x20, 0x64, 0x6f, 0x65, 0x73, 0x6e, 0x27, 0x74, 0x20, 0x6e, 0x65,
+    0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x70, 0x61, 0x72, 0x73, 0x65, 0x20, 0x60, 0x74, 0x65, 0x78,
+    0x74, 0x60, 0x2c, 0x0a, 0x20, 0x74, 0x65, 0x6d, 0x70, 0x6f, 0x72, 0x61, 0x6c, 0x20, 0x76, 0x61,
+    0x6c, 0x75, 0x65, 0x73, 0x20, 0x61, 0x72, 0x65, 0x20, 0x67, 0x69, 0x76, 0x65, 0x6e, 0x20, 0x61,
+    0x73, 0x20, 0x6d, 0x69, 0x6c, 0x6c, 0x69, 0x73, 0x65, 0x63, 0x6f, 0x6e, 0x64, 0x73, 0x20, 0x73,
+    0x69, 0x6e, 0x63, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x70, 0x6f, 0x63, 0x68, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x04, 0x12, 0x03, 0x24, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x02, 0x05, 0x12, 0x03, 0x24, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x02, 0x01, 0x12, 0x03, 0x24, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x02, 0x03, 0x12, 0x03, 0x24, 0x1b, 0x1c, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x04, 0x27,
+    0x00, 0x29, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03, 0x27, 0x08, 0x0b, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x28, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x04, 0x02, 0x00, 0x04, 0x12, 0x03, 0x28, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04,
+    0x02, 0x00, 0x06, 0x12, 0x03, 0x28, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00,
+    0x01, 0x12, 0x03, 0x28, 0x10, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x03, 0x12,
+    0x03, 0x28, 0x18, 0x19, 0x0a, 0x5f, 0x0a, 0x02, 0x04, 0x05, 0x12, 0x04, 0x2c, 0x00, 0x33, 0x01,
+    0x1a, 0x53, 0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x69, 0x6c, 0x65, 0x73, 0x20, 0x6f, 0x66, 0x20,
+    0x61, 0x20, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x20, 0x6f, 0x76, 0x65, 0x72, 0x20, 0x61, 0x20, 0x64,
+    0x69, 0x72, 0x65, 0x63, 0x74, 0x6f, 0x72, 0x79, 0x20, 0x61, 0x20, 0x73, 0x74, 0x61, 0x74, 0x65,
+    0x6d, 0x65, 0x6e, 0x74, 0x20, 0x72, 0x65, 0x61, 0x64, 0x73, 0x2c, 0x20, 0x6f, 0x75, 0x74, 0x20,
+    0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x6e, 0x65, 0x73, 0x20, 0x62, 0x65, 0x6c, 0x6f,
+    0x77, 0x20, 0x69, 0x74, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03, 0x2c, 0x08,
+    0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x2d, 0x02, 0x13, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x05, 0x12, 0x03, 0x2d, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x2d, 0x09, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x00, 0x03, 0x12, 0x03, 0x2d, 0x11, 0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x01,
+    0x12, 0x03, 0x2e, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x05, 0x12, 0x03,
+    0x2e, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x01, 0x12, 0x03, 0x2e, 0x09,
+    0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x03, 0x12, 0x03, 0x2e, 0x19, 0x1a, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x02, 0x12, 0x03, 0x2f, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x02, 0x05, 0x12, 0x03, 0x2f, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x02, 0x01, 0x12, 0x03, 0x2f, 0x09, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02,
+    0x03, 0x12, 0x03, 0x2f, 0x17, 0x18, 0x0a, 0x60, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x03, 0x12, 0x03,
+    0x31, 0x02, 0x20, 0x1a, 0x53, 0x20, 0x64, 0x69, 0x72, 0x65, 0x63, 0x74, 0x6f, 0x72, 0x69, 0x65,
+    0x73, 0x20, 0x6e, 0x61, 0x6d, 0x65, 0x64, 0x20, 0x61, 0x66, 0x74, 0x65, 0x72, 0x20, 0x70, 0x61,
+    0x72, 0x74, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x73, 0x2c, 0x20,
+    0x30, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x20, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x20, 0x77, 0x69,
+    0x74, 0x68, 0x6f, 0x75, 0x74, 0x20, 0x70, 0x61, 0x72, 0x74, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x20,
+    0x63, 0x6f, 0x6c, 0x75, 0x6d, 0x6e, 0x73, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03,
+    0x05, 0x12, 0x03, 0x31, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x01, 0x12,
+    0x03, 0x31, 0x09, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x03, 0x12, 0x03, 0x31,
+    0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x04, 0x12, 0x03, 0x32, 0x02, 0x1e, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x05, 0x12, 0x03, 0x32, 0x02, 0x08, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x05, 0x02, 0x04, 0x01, 0x12, 0x03, 0x32, 0x09, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x05, 0x02, 0x04, 0x03, 0x12, 0x03, 0x32, 0x1c, 0x1d, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x06, 0x12,
+    0x04, 0x35, 0x00, 0x3b, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06, 0x01, 0x12, 0x03, 0x35, 0x08,
+    0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x00, 0x12, 0x03, 0x36, 0x02, 0x18, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x05, 0x12, 0x03, 0x36, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x06, 0x02, 0x00, 0x01, 0x12, 0x03, 0x36, 0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
+    0x02, 0x00, 0x03, 0x12, 0x03, 0x36, 0x16, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x01,
+    0x12, 0x03, 0x37, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x05, 0x12, 0x03,
+    0x37, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x01, 0x12, 0x03, 0x37, 0x09,
+    0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x03, 0x12, 0x03, 0x37, 0x1b, 0x1c, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x02, 0x12, 0x03, 0x38, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x06, 0x02, 0x02, 0x04, 0x12, 0x03, 0x38, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
+    0x02, 0x02, 0x06, 0x12, 0x03, 0x38, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02,
+    0x01, 0x12, 0x03, 0x38, 0x12, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x03, 0x12,
+    0x03, 0x38, 0x1c, 0x1d, 0x0a, 0x5b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x03, 0x12, 0x03, 0x3a, 0x02,
+    0x1f, 0x1a, 0x4e, 0x20, 0x6b, 0x6e, 0x6f, 0x77, 0x6e, 0x20, 0x6f, 0x6e, 0x63, 0x65, 0x20, 0x70,
+    0x6c, 0x61, 0x6e, 0x6e, 0x65, 0x64, 0x2c, 0x20, 0x70, 0x61, 0x72, 0x74, 0x69, 0x74, 0x69, 0x6f,
+    0x6e, 0x73, 0x20, 0x77, 0x68, 0x6f, 0x73, 0x65, 0x20, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x73, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x66, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x73, 0x20, 0x72, 0x75, 0x6c, 0x65,
+    0x20, 0x6f, 0x75, 0x74, 0x20, 0x61, 0x72, 0x65, 0x6e, 0x27, 0x74, 0x20, 0x72, 0x65, 0x61, 0x64,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x04, 0x12, 0x03, 0x3a, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x06, 0x12, 0x03, 0x3a, 0x0b, 0x14, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x06, 0x02, 0x03, 0x01, 0x12, 0x03, 0x3a, 0x15, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x06, 0x02, 0x03, 0x03, 0x12, 0x03, 0x3a, 0x1d, 0x1e, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x07, 0x12,
+    0x04, 0x3d, 0x00, 0x41, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x07, 0x01, 0x12, 0x03, 0x3d, 0x08,
+    0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x00, 0x12, 0x03, 0x3e, 0x02, 0x18, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x05, 0x12, 0x03, 0x3e, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x07, 0x02, 0x00, 0x01, 0x12, 0x03, 0x3e, 0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07,
+    0x02, 0x00, 0x03, 0x12, 0x03, 0x3e, 0x16, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x01,
+    0x12, 0x03, 0x3f, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x05, 0x12, 0x03,
+    0x3f, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x01, 0x12, 0x03, 0x3f, 0x09,
+    0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x03, 0x12, 0x03, 0x3f, 0x1b, 0x1c, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x02, 0x12, 0x03, 0x40, 0x02, 0x18, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x07, 0x02, 0x02, 0x04, 0x12, 0x03, 0x40, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07,
+    0x02, 0x02, 0x06, 0x12, 0x03, 0x40, 0x0b, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02,
+    0x01, 0x12, 0x03, 0x40, 0x0f, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x03, 0x12,
+    0x03, 0x40, 0x16, 0x17, 0x0a, 0x40, 0x0a, 0x02, 0x05, 0x00, 0x12, 0x04, 0x44, 0x00, 0x52, 0x01,
+    0x1a, 0x34, 0x20, 0x74, 0x68, 0x65, 0x20, 0x71, 0x75, 0x6f, 0x74, 0x61, 0x20, 0x6f, 0x72, 0x20,
+    0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x20, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x20, 0x74, 0x68, 0x61,
+    0x74, 0x20, 0x73, 0x74, 0x6f, 0x70, 0x70, 0x65, 0x64, 0x20, 0x61, 0x20, 0x73, 0x74, 0x61, 0x74,
+    0x65, 0x6d, 0x65, 0x6e, 0x74, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x00, 0x01, 0x12, 0x03, 0x44,
+    0x05, 0x0a, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x00, 0x12, 0x03, 0x45, 0x02, 0x18, 0x0a,
+    0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x45, 0x02, 0x13, 0x0a, 0x0c, 0x0a,
+    0x05, 0x05, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x45, 0x16, 0x17, 0x0a, 0x40, 0x0a, 0x04, 0x05,
+    0x00, 0x02, 0x01, 0x12, 0x03, 0x47, 0x02, 0x1f, 0x1a, 0x33, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63,
+    0x6c, 0x69, 0x65, 0x6e, 0x74, 0x20, 0x61, 0x6c, 0x72, 0x65, 0x61, 0x64, 0x79, 0x20, 0x72, 0x75,
+    0x6e, 0x73, 0x20, 0x61, 0x73, 0x20, 0x6d, 0x61, 0x6e, 0x79, 0x20, 0x71, 0x75, 0x65, 0x72, 0x69,
+    0x65, 0x73, 0x20, 0x61, 0x73, 0x20, 0x69, 0x74, 0x20, 0x6d, 0x61, 0x79, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x05, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x47, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
+    0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x47, 0x1d, 0x1e, 0x0a, 0x4e, 0x0a, 0x04, 0x05, 0x00, 0x02,
+    0x02, 0x12, 0x03, 0x49, 0x02, 0x17, 0x1a, 0x41, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6c, 0x69,
+    0x65, 0x6e, 0x74, 0x20, 0x73, 0x74, 0x61, 0x72, 0x74, 0x65, 0x64, 0x20, 0x61, 0x73, 0x20, 0x6d,
+    0x61, 0x6e, 0x79, 0x20, 0x71, 0x75, 0x65, 0x72, 0x69, 0x65, 0x73, 0x20, 0x69, 0x6e, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x6c, 0x61, 0x73, 0x74, 0x20, 0x6d, 0x69, 0x6e, 0x75, 0x74, 0x65, 0x20, 0x61,
+    0x73, 0x20, 0x69, 0x74, 0x20, 0x6d, 0x61, 0x79, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02,
+    0x02, 0x01, 0x12, 0x03, 0x49, 0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x02, 0x02,
+    0x12, 0x03, 0x49, 0x15, 0x16, 0x0a, 0x41, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x03, 0x12, 0x03, 0x4b,
+    0x02, 0x19, 0x1a, 0x34, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x20,
+    0x67, 0x72, 0x65, 0x77, 0x20, 0x6c, 0x61, 0x72, 0x67, 0x65, 0x72, 0x20, 0x74, 0x68, 0x61, 0x6e,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x20, 0x6d, 0x61, 0x79, 0x20,
+    0x72, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x03,
+    0x01, 0x12, 0x03, 0x4b, 0x02, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x03, 0x02, 0x12,
+    0x03, 0x4b, 0x17, 0x18, 0x0a, 0x38, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x04, 0x12, 0x03, 0x4d, 0x02,
+    0x17, 0x1a, 0x2b, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x64, 0x6d, 0x69, 0x73, 0x73, 0x69, 0x6f,
+    0x6e, 0x20, 0x71, 0x75, 0x65, 0x75, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73,
+    0x65, 0x72, 0x76, 0x65, 0x72, 0x20, 0x69, 0x73, 0x20, 0x66, 0x75, 0x6c, 0x6c, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x05, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x4d, 0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05,
+    0x05, 0x00, 0x02, 0x04, 0x02, 0x12, 0x03, 0x4d, 0x15, 0x16, 0x0a, 0x32, 0x0a, 0x04, 0x05, 0x00,
+    0x02, 0x05, 0x12, 0x03, 0x4f, 0x02, 0x14, 0x1a, 0x25, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x74,
+    0x61, 0x74, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x20, 0x72, 0x61, 0x6e, 0x20, 0x61, 0x73, 0x20, 0x6c,
+    0x6f, 0x6e, 0x67, 0x20, 0x61, 0x73, 0x20, 0x69, 0x74, 0x20, 0x6d, 0x61, 0x79, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x05, 0x00, 0x02, 0x05, 0x01, 0x12, 0x03, 0x4f, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05,
+    0x05, 0x00, 0x02, 0x05, 0x02, 0x12, 0x03, 0x4f, 0x12, 0x13, 0x0a, 0x43, 0x0a, 0x04, 0x05, 0x00,
+    0x02, 0x06, 0x12, 0x03, 0x51, 0x02, 0x18, 0x1a, 0x36, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65,
+    0x73, 0x75, 0x6c, 0x74, 0x20, 0x68, 0x61, 0x73, 0x20, 0x6d, 0x6f, 0x72, 0x65, 0x20, 0x72, 0x6f,
+    0x77, 0x73, 0x20, 0x74, 0x68, 0x61, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6c, 0x69, 0x65,
+    0x6e, 0x74, 0x20, 0x6d, 0x61, 0x79, 0x20, 0x72, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x06, 0x01, 0x12, 0x03, 0x51, 0x02, 0x13, 0x0a, 0x0c, 0x0a,
+    0x05, 0x05, 0x00, 0x02, 0x06, 0x02, 0x12, 0x03, 0x51, 0x16, 0x17, 0x0a, 0x0a, 0x0a, 0x02, 0x04,
+    0x08, 0x12, 0x04, 0x54, 0x00, 0x60, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x08, 0x01, 0x12, 0x03,
+    0x54, 0x08, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x00, 0x12, 0x03, 0x55, 0x02, 0x18,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x05, 0x12, 0x03, 0x55, 0x02, 0x08, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x01, 0x12, 0x03, 0x55, 0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x08, 0x02, 0x00, 0x03, 0x12, 0x03, 0x55, 0x16, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08,
+    0x02, 0x01, 0x12, 0x03, 0x56, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x05,
+    0x12, 0x03, 0x56, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x01, 0x12, 0x03,
+    0x56, 0x09, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x03, 0x12, 0x03, 0x56, 0x1b,
+    0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x02, 0x12, 0x03, 0x57, 0x02, 0x17, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x05, 0x12, 0x03, 0x57, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x08, 0x02, 0x02, 0x01, 0x12, 0x03, 0x57, 0x09, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08,
+    0x02, 0x02, 0x03, 0x12, 0x03, 0x57, 0x15, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x03,
+    0x12, 0x03, 0x58, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x05, 0x12, 0x03,
+    0x58, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x01, 0x12, 0x03, 0x58, 0x09,
+    0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x03, 0x12, 0x03, 0x58, 0x17, 0x18, 0x0a,
+    0x1f, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x04, 0x12, 0x03, 0x5a, 0x02, 0x13, 0x1a, 0x12, 0x20, 0x65,
+    0x6d, 0x70, 0x74, 0x79, 0x20, 0x6f, 0x6e, 0x20, 0x73, 0x75, 0x63, 0x63, 0x65, 0x73, 0x73, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x05, 0x12, 0x03, 0x5a, 0x02, 0x08, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x01, 0x12, 0x03, 0x5a, 0x09, 0x0e, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x08, 0x02, 0x04, 0x03, 0x12, 0x03, 0x5a, 0x11, 0x12, 0x0a, 0x4a, 0x0a, 0x04, 0x04, 0x08,
+    0x02, 0x05, 0x12, 0x03, 0x5c, 0x02, 0x1b, 0x1a, 0x3d, 0x20, 0x77, 0x61, 0x6c, 0x6c, 0x20, 0x63,
+    0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x2c, 0x20, 0x6d, 0x69, 0x6c, 0x6c, 0x69, 0x73, 0x65,
+    0x63, 0x6f, 0x6e, 0x64, 0x73, 0x20, 0x73, 0x69, 0x6e, 0x63, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x65, 0x70, 0x6f, 0x63, 0x68, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x05, 0x05, 0x12,
+    0x03, 0x5c, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x05, 0x01, 0x12, 0x03, 0x5c,
+    0x09, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x05, 0x03, 0x12, 0x03, 0x5c, 0x19, 0x1a,
+    0x0a, 0xb9, 0x01, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x06, 0x12, 0x03, 0x5f, 0x02, 0x12, 0x1a, 0xab,
+    0x01, 0x20, 0x73, 0x65, 0x74, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x61, 0x20, 0x6c, 0x69, 0x6d,
+    0x69, 0x74, 0x20, 0x72, 0x65, 0x66, 0x75, 0x73, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73,
+    0x74, 0x61, 0x74, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x2c, 0x20, 0x60, 0x65, 0x72, 0x72, 0x6f, 0x72,
+    0x60, 0x20, 0x73, 0x61, 0x79, 0x73, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x6f, 0x6e, 0x65,
+    0x20, 0x77, 0x61, 0x73, 0x20, 0x72, 0x65, 0x61, 0x63, 0x68, 0x65, 0x64, 0x2c, 0x20, 0x6f, 0x72,
+    0x20, 0x63, 0x75, 0x74, 0x20, 0x69, 0x74, 0x73, 0x0a, 0x20, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74,
+    0x20, 0x73, 0x68, 0x6f, 0x72, 0x74, 0x3a, 0x20, 0x60, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x60, 0x20,
+    0x69, 0x73, 0x20, 0x65, 0x6d, 0x70, 0x74, 0x79, 0x20, 0x74, 0x68, 0x65, 0x6e, 0x20, 0x61, 0x6e,
+    0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x6f, 0x77, 0x73, 0x20, 0x73, 0x65, 0x6e, 0x74, 0x20,
+    0x61, 0x72, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x69, 0x72, 0x73, 0x74, 0x20, 0x6f, 0x66,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x08, 0x02, 0x06, 0x06, 0x12, 0x03, 0x5f, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08,
+    0x02, 0x06, 0x01, 0x12, 0x03, 0x5f, 0x08, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x06,
+    0x03, 0x12, 0x03, 0x5f, 0x10, 0x11, 0x0a, 0x69, 0x0a, 0x02, 0x04, 0x09, 0x12, 0x04, 0x63, 0x00,
+    0x68, 0x01, 0x1a, 0x5d, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x74, 0x61, 0x74, 0x65, 0x6d, 0x65,
+    0x6e, 0x74, 0x20, 0x77, 0x61, 0x69, 0x74, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x20, 0x74, 0x6f, 0x20, 0x72, 0x75, 0x6e, 0x20, 0x66,
+    0x65, 0x77, 0x65, 0x72, 0x20, 0x71, 0x75, 0x65, 0x72, 0x69, 0x65, 0x73, 0x2c, 0x20, 0x73, 0x65,
+    0x6e, 0x74, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x65, 0x76, 0x65, 0x72, 0x20, 0x69, 0x74, 0x73, 0x20,
+    0x70, 0x6f, 0x73, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x73,
+    0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x09, 0x01, 0x12, 0x03, 0x63, 0x08, 0x0e, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x09, 0x02, 0x00, 0x12, 0x03, 0x64, 0x02, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09,
+    0x02, 0x00, 0x05, 0x12, 0x03, 0x64, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00,
+    0x01, 0x12, 0x03, 0x64, 0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x03, 0x12,
+    0x03, 0x64, 0x16, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x01, 0x12, 0x03, 0x65, 0x02,
+    0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x05, 0x12, 0x03, 0x65, 0x02, 0x08, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x01, 0x12, 0x03, 0x65, 0x09, 0x18, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x09, 0x02, 0x01, 0x03, 0x12, 0x03, 0x65, 0x1b, 0x1c, 0x0a, 0x1a, 0x0a, 0x04, 0x04,
+    0x09, 0x02, 0x02, 0x12, 0x03, 0x67, 0x02, 0x16, 0x1a, 0x0d, 0x20, 0x31, 0x20, 0x72, 0x75, 0x6e,
+    0x73, 0x20, 0x6e, 0x65, 0x78, 0x74, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x05,
+    0x12, 0x03, 0x67, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x01, 0x12, 0x03,
+    0x67, 0x09, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x03, 0x12, 0x03, 0x67, 0x14,
+    0x15, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0a, 0x12, 0x04, 0x6a, 0x00, 0x6c, 0x01, 0x0a, 0x0a, 0x0a,
+    0x03, 0x04, 0x0a, 0x01, 0x12, 0x03, 0x6a, 0x08, 0x13, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02,
+    0x00, 0x12, 0x03, 0x6b, 0x02, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x05, 0x12,
+    0x03, 0x6b, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x01, 0x12, 0x03, 0x6b,
+    0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x03, 0x12, 0x03, 0x6b, 0x16, 0x17,
+    0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0b, 0x12, 0x04, 0x6e, 0x00, 0x74, 0x01, 0x0a, 0x0a, 0x0a, 0x03,
+    0x04, 0x0b, 0x01, 0x12, 0x03, 0x6e, 0x08, 0x13, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x00,
+    0x12, 0x03, 0x6f, 0x02, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x05, 0x12, 0x03,
+    0x6f, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x01, 0x12, 0x03, 0x6f, 0x09,
+    0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x03, 0x12, 0x03, 0x6f, 0x16, 0x17, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x01, 0x12, 0x03, 0x70, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0b, 0x02, 0x01, 0x05, 0x12, 0x03, 0x70, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b,
+    0x02, 0x01, 0x01, 0x12, 0x03, 0x70, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01,
+    0x03, 0x12, 0x03, 0x70, 0x13, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x02, 0x12, 0x03,
+    0x71, 0x02, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x05, 0x12, 0x03, 0x71, 0x02,
+    0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x01, 0x12, 0x03, 0x71, 0x09, 0x0c, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x03, 0x12, 0x03, 0x71, 0x0f, 0x10, 0x0a, 0x31, 0x0a,
+    0x04, 0x04, 0x0b, 0x02, 0x03, 0x12, 0x03, 0x73, 0x02, 0x14, 0x1a, 0x24, 0x20, 0x62, 0x79, 0x74,
+    0x65, 0x20, 0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x63, 0x75, 0x72, 0x73, 0x6f, 0x72, 0x20, 0x69, 0x6e, 0x20, 0x60, 0x73, 0x71, 0x6c, 0x60, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x03, 0x05, 0x12, 0x03, 0x73, 0x02, 0x08, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0b, 0x02, 0x03, 0x01, 0x12, 0x03, 0x73, 0x09, 0x0f, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0b, 0x02, 0x03, 0x03, 0x12, 0x03, 0x73, 0x12, 0x13, 0x0a, 0x0a, 0x0a, 0x02, 0x05, 0x01,
+    0x12, 0x04, 0x76, 0x00, 0x7c, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x01, 0x01, 0x12, 0x03, 0x76,
+    0x05, 0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x00, 0x12, 0x03, 0x77, 0x02, 0x21, 0x0a,
+    0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x77, 0x02, 0x1c, 0x0a, 0x0c, 0x0a,
+    0x05, 0x05, 0x01, 0x02, 0x00, 0x02, 0x12, 0x03, 0x77, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x05,
+    0x01, 0x02, 0x01, 0x12, 0x03, 0x78, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x01,
+    0x01, 0x12, 0x03, 0x78, 0x02, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x01, 0x02, 0x12,
+    0x03, 0x78, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x02, 0x12, 0x03, 0x79, 0x02,
+    0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x02, 0x01, 0x12, 0x03, 0x79, 0x02, 0x19, 0x0a,
+    0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x02, 0x02, 0x12, 0x03, 0x79, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a,
+    0x04, 0x05, 0x01, 0x02, 0x03, 0x12, 0x03, 0x7a, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01,
+    0x02, 0x03, 0x01, 0x12, 0x03, 0x7a, 0x02, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x03,
+    0x02, 0x12, 0x03, 0x7a, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x04, 0x12, 0x03,
+    0x7b, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x04, 0x01, 0x12, 0x03, 0x7b, 0x02,
+    0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x04, 0x02, 0x12, 0x03, 0x7b, 0x1a, 0x1b, 0x0a,
+    0x0b, 0x0a, 0x02, 0x04, 0x0c, 0x12, 0x05, 0x7e, 0x00, 0x83, 0x01, 0x01, 0x0a, 0x0a, 0x0a, 0x03,
+    0x04, 0x0c, 0x01, 0x12, 0x03, 0x7e, 0x08, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x00,
+    0x12, 0x03, 0x7f, 0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x05, 0x12, 0x03,
+    0x7f, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x01, 0x12, 0x03, 0x7f, 0x09,
+    0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x03, 0x12, 0x03, 0x7f, 0x10, 0x11, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x01, 0x12, 0x04, 0x80, 0x01, 0x02, 0x19, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0c, 0x02, 0x01, 0x06, 0x12, 0x04, 0x80, 0x01, 0x02, 0x0f, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0c, 0x02, 0x01, 0x01, 0x12, 0x04, 0x80, 0x01, 0x10, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0c, 0x02, 0x01, 0x03, 0x12, 0x04, 0x80, 0x01, 0x17, 0x18, 0x0a, 0x47, 0x0a, 0x04, 0x04, 0x0c,
+    0x02, 0x02, 0x12, 0x04, 0x82, 0x01, 0x02, 0x14, 0x1a, 0x39, 0x20, 0x65, 0x2e, 0x67, 0x2e, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x64, 0x61, 0x74, 0x61, 0x20, 0x74, 0x79, 0x70, 0x65, 0x20, 0x6f, 0x66,
+    0x20, 0x61, 0x20, 0x63, 0x6f, 0x6c, 0x75, 0x6d, 0x6e, 0x20, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x73, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x20, 0x74, 0x61, 0x62,
+    0x6c, 0x65, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x02, 0x05, 0x12, 0x04, 0x82, 0x01,
+    0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x02, 0x01, 0x12, 0x04, 0x82, 0x01, 0x09,
+    0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x02, 0x03, 0x12, 0x04, 0x82, 0x01, 0x12, 0x13,
+    0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x0d, 0x12, 0x06, 0x85, 0x01, 0x00, 0x8a, 0x01, 0x01, 0x0a, 0x0b,
+    0x0a, 0x03, 0x04, 0x0d, 0x01, 0x12, 0x04, 0x85, 0x01, 0x08, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x0d, 0x02, 0x00, 0x12, 0x04, 0x86, 0x01, 0x02, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02,
+    0x00, 0x05, 0x12, 0x04, 0x86, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00,
+    0x01, 0x12, 0x04, 0x86, 0x01, 0x09, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x03,
+    0x12, 0x04, 0x86, 0x01, 0x16, 0x17, 0x0a, 0x3f, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x01, 0x12, 0x04,
+    0x88, 0x01, 0x02, 0x14, 0x1a, 0x31, 0x20, 0x74, 0x68, 0x65, 0x20, 0x77, 0x6f, 0x72, 0x64, 0x20,
+    0x62, 0x65, 0x69, 0x6e, 0x67, 0x20, 0x63, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x64, 0x2c,
+    0x20, 0x63, 0x61, 0x6e, 0x64, 0x69, 0x64, 0x61, 0x74, 0x65, 0x73, 0x20, 0x72, 0x65, 0x70, 0x6c,
+    0x61, 0x63, 0x65, 0x20, 0x69, 0x74, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x05,
+    0x12, 0x04, 0x88, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x01, 0x12,
+    0x04, 0x88, 0x01, 0x09, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x03, 0x12, 0x04,
+    0x88, 0x01, 0x12, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x02, 0x12, 0x04, 0x89, 0x01,
+    0x02, 0x24, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02, 0x04, 0x12, 0x04, 0x89, 0x01, 0x02,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02, 0x06, 0x12, 0x04, 0x89, 0x01, 0x0b, 0x14,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02, 0x01, 0x12, 0x04, 0x89, 0x01, 0x15, 0x1f, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02, 0x03, 0x12, 0x04, 0x89, 0x01, 0x22, 0x23, 0x0a, 0x0c,
+    0x0a, 0x02, 0x04, 0x0e, 0x12, 0x06, 0x8c, 0x01, 0x00, 0x91, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03,
+    0x04, 0x0e, 0x01, 0x12, 0x04, 0x8c, 0x01, 0x08, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02,
+    0x00, 0x12, 0x04, 0x8d, 0x01, 0x02, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x05,
+    0x12, 0x04, 0x8d, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x01, 0x12,
+    0x04, 0x8d, 0x01, 0x09, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x03, 0x12, 0x04,
+    0x8d, 0x01, 0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x01, 0x12, 0x04, 0x8e, 0x01,
+    0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x05, 0x12, 0x04, 0x8e, 0x01, 0x02,
+    0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x01, 0x12, 0x04, 0x8e, 0x01, 0x09, 0x10,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x03, 0x12, 0x04, 0x8e, 0x01, 0x13, 0x14, 0x0a,
+    0x5e, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x02, 0x12, 0x04, 0x90, 0x01, 0x02, 0x11, 0x1a, 0x50, 0x20,
+    0x6d, 0x61, 0x79, 0x20, 0x68, 0x6f, 0x6c, 0x64, 0x20, 0x73, 0x65, 0x76, 0x65, 0x72, 0x61, 0x6c,
+    0x20, 0x73, 0x74, 0x61, 0x74, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x73, 0x2c, 0x20, 0x65, 0x61, 0x63,
+    0x68, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x69, 0x73, 0x20, 0x70, 0x61, 0x72, 0x73, 0x65, 0x64, 0x20,
+    0x61, 0x6e, 0x64, 0x20, 0x70, 0x6c, 0x61, 0x6e, 0x6e, 0x65, 0x64, 0x20, 0x62, 0x75, 0x74, 0x20,
+    0x6e, 0x65, 0x76, 0x65, 0x72, 0x20, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x65, 0x64, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x02, 0x05, 0x12, 0x04, 0x90, 0x01, 0x02, 0x08, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0e, 0x02, 0x02, 0x01, 0x12, 0x04, 0x90, 0x01, 0x09, 0x0c, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0e, 0x02, 0x02, 0x03, 0x12, 0x04, 0x90, 0x01, 0x0f, 0x10, 0x0a, 0x0c, 0x0a, 0x02,
+    0x05, 0x02, 0x12, 0x06, 0x93, 0x01, 0x00, 0x97, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x05, 0x02,
+    0x01, 0x12, 0x04, 0x93, 0x01, 0x05, 0x0d, 0x0a, 0x0c, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x00, 0x12,
+    0x04, 0x94, 0x01, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x00, 0x01, 0x12, 0x04,
+    0x94, 0x01, 0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x00, 0x02, 0x12, 0x04, 0x94,
+    0x01, 0x19, 0x1a, 0x0a, 0x0c, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x01, 0x12, 0x04, 0x95, 0x01, 0x02,
+    0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x01, 0x01, 0x12, 0x04, 0x95, 0x01, 0x02, 0x10,
+    0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x01, 0x02, 0x12, 0x04, 0x95, 0x01, 0x13, 0x14, 0x0a,
+    0x0c, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x02, 0x12, 0x04, 0x96, 0x01, 0x02, 0x17, 0x0a, 0x0d, 0x0a,
+    0x05, 0x05, 0x02, 0x02, 0x02, 0x01, 0x12, 0x04, 0x96, 0x01, 0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05,
+    0x05, 0x02, 0x02, 0x02, 0x02, 0x12, 0x04, 0x96, 0x01, 0x15, 0x16, 0x0a, 0x58, 0x0a, 0x02, 0x04,
+    0x0f, 0x12, 0x06, 0x9a, 0x01, 0x00, 0x9f, 0x01, 0x01, 0x1a, 0x4a, 0x20, 0x31, 0x2d, 0x62, 0x61,
+    0x73, 0x65, 0x64, 0x20, 0x6c, 0x69, 0x6e, 0x65, 0x73, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x63, 0x6f,
+    0x6c, 0x75, 0x6d, 0x6e, 0x73, 0x20, 0x28, 0x69, 0x6e, 0x20, 0x63, 0x68, 0x61, 0x72, 0x61, 0x63,
+    0x74, 0x65, 0x72, 0x73, 0x29, 0x20, 0x6f, 0x66, 0x20, 0x60, 0x73, 0x71, 0x6c, 0x60, 0x2c, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x65, 0x6e, 0x64, 0x20, 0x69, 0x73, 0x20, 0x65, 0x78, 0x63, 0x6c, 0x75,
+    0x73, 0x69, 0x76, 0x65, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0f, 0x01, 0x12, 0x04, 0x9a, 0x01,
+    0x08, 0x0c, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x00, 0x12, 0x04, 0x9b, 0x01, 0x02, 0x18,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x05, 0x12, 0x04, 0x9b, 0x01, 0x02, 0x08, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x01, 0x12, 0x04, 0x9b, 0x01, 0x09, 0x13, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x03, 0x12, 0x04, 0x9b, 0x01, 0x16, 0x17, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x0f, 0x02, 0x01, 0x12, 0x04, 0x9c, 0x01, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0f, 0x02, 0x01, 0x05, 0x12, 0x04, 0x9c, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f,
+    0x02, 0x01, 0x01, 0x12, 0x04, 0x9c, 0x01, 0x09, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02,
+    0x01, 0x03, 0x12, 0x04, 0x9c, 0x01, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x02,
+    0x12, 0x04, 0x9d, 0x01, 0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02, 0x05, 0x12,
+    0x04, 0x9d, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02, 0x01, 0x12, 0x04,
+    0x9d, 0x01, 0x09, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02, 0x03, 0x12, 0x04, 0x9d,
+    0x01, 0x14, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x03, 0x12, 0x04, 0x9e, 0x01, 0x02,
+    0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x03, 0x05, 0x12, 0x04, 0x9e, 0x01, 0x02, 0x08,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x03, 0x01, 0x12, 0x04, 0x9e, 0x01, 0x09, 0x13, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x03, 0x03, 0x12, 0x04, 0x9e, 0x01, 0x16, 0x17, 0x0a, 0x0c,
+    0x0a, 0x02, 0x04, 0x10, 0x12, 0x06, 0xa1, 0x01, 0x00, 0xa5, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03,
+    0x04, 0x10, 0x01, 0x12, 0x04, 0xa1, 0x01, 0x08, 0x12, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x10, 0x02,
+    0x00, 0x12, 0x04, 0xa2, 0x01, 0x02, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x06,
+    0x12, 0x04, 0xa2, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x01, 0x12,
+    0x04, 0xa2, 0x01, 0x0b, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x03, 0x12, 0x04,
+    0xa2, 0x01, 0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x01, 0x12, 0x04, 0xa3, 0x01,
+    0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x05, 0x12, 0x04, 0xa3, 0x01, 0x02,
+    0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x01, 0x12, 0x04, 0xa3, 0x01, 0x09, 0x10,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x03, 0x12, 0x04, 0xa3, 0x01, 0x13, 0x14, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x02, 0x12, 0x04, 0xa4, 0x01, 0x02, 0x10, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x10, 0x02, 0x02, 0x06, 0x12, 0x04, 0xa4, 0x01, 0x02, 0x06, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x10, 0x02, 0x02, 0x01, 0x12, 0x04, 0xa4, 0x01, 0x07, 0x0b, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x10, 0x02, 0x02, 0x03, 0x12, 0x04, 0xa4, 0x01, 0x0e, 0x0f, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x11,
+    0x12, 0x06, 0xa7, 0x01, 0x00, 0xaa, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x11, 0x01, 0x12,
+    0x04, 0xa7, 0x01, 0x08, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x00, 0x12, 0x04, 0xa8,
+    0x01, 0x02, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x05, 0x12, 0x04, 0xa8, 0x01,
+    0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x01, 0x12, 0x04, 0xa8, 0x01, 0x09,
+    0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x03, 0x12, 0x04, 0xa8, 0x01, 0x16, 0x17,
+    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x01, 0x12, 0x04, 0xa9, 0x01, 0x02, 0x26, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x11, 0x02, 0x01, 0x04, 0x12, 0x04, 0xa9, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x11, 0x02, 0x01, 0x06, 0x12, 0x04, 0xa9, 0x01, 0x0b, 0x15, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x11, 0x02, 0x01, 0x01, 0x12, 0x04, 0xa9, 0x01, 0x16, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x11, 0x02, 0x01, 0x03, 0x12, 0x04, 0xa9, 0x01, 0x24, 0x25, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x12,
+    0x12, 0x06, 0xac, 0x01, 0x00, 0xb4, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x12, 0x01, 0x12,
+    0x04, 0xac, 0x01, 0x08, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x00, 0x12, 0x04, 0xad,
+    0x01, 0x02, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x05, 0x12, 0x04, 0xad, 0x01,
+    0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x01, 0x12, 0x04, 0xad, 0x01, 0x09,
+    0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x03, 0x12, 0x04, 0xad, 0x01, 0x0f, 0x10,
+    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x01, 0x12, 0x04, 0xae, 0x01, 0x02, 0x15, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x12, 0x02, 0x01, 0x05, 0x12, 0x04, 0xae, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x12, 0x02, 0x01, 0x01, 0x12, 0x04, 0xae, 0x01, 0x09, 0x10, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x12, 0x02, 0x01, 0x03, 0x12, 0x04, 0xae, 0x01, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x12, 0x02, 0x02, 0x12, 0x04, 0xaf, 0x01, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02,
+    0x02, 0x05, 0x12, 0x04, 0xaf, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x02,
+    0x01, 0x12, 0x04, 0xaf, 0x01, 0x09, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x02, 0x03,
+    0x12, 0x04, 0xaf, 0x01, 0x19, 0x1a, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x03, 0x12, 0x04,
+    0xb0, 0x01, 0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x03, 0x05, 0x12, 0x04, 0xb0,
+    0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x03, 0x01, 0x12, 0x04, 0xb0, 0x01,
+    0x09, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x03, 0x03, 0x12, 0x04, 0xb0, 0x01, 0x17,
+    0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x04, 0x12, 0x04, 0xb1, 0x01, 0x02, 0x17, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x04, 0x05, 0x12, 0x04, 0xb1, 0x01, 0x02, 0x08, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x12, 0x02, 0x04, 0x01, 0x12, 0x04, 0xb1, 0x01, 0x09, 0x12, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x12, 0x02, 0x04, 0x03, 0x12, 0x04, 0xb1, 0x01, 0x15, 0x16, 0x0a, 0x20, 0x0a, 0x04,
+    0x04, 0x12, 0x02, 0x05, 0x12, 0x04, 0xb3, 0x01, 0x02, 0x13, 0x1a, 0x12, 0x20, 0x65, 0x6d, 0x70,
+    0x74, 0x79, 0x20, 0x6f, 0x6e, 0x20, 0x73, 0x75, 0x63, 0x63, 0x65, 0x73, 0x73, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x12, 0x02, 0x05, 0x05, 0x12, 0x04, 0xb3, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x12, 0x02, 0x05, 0x01, 0x12, 0x04, 0xb3, 0x01, 0x09, 0x0e, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x12, 0x02, 0x05, 0x03, 0x12, 0x04, 0xb3, 0x01, 0x11, 0x12, 0x0a, 0x0c, 0x0a, 0x02, 0x04,
+    0x13, 0x12, 0x06, 0xb6, 0x01, 0x00, 0xbc, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x13, 0x01,
+    0x12, 0x04, 0xb6, 0x01, 0x08, 0x12, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x00, 0x12, 0x04,
+    0xb7, 0x01, 0x02, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x05, 0x12, 0x04, 0xb7,
+    0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x01, 0x12, 0x04, 0xb7, 0x01,
+    0x09, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x03, 0x12, 0x04, 0xb7, 0x01, 0x16,
+    0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x01, 0x12, 0x04, 0xb8, 0x01, 0x02, 0x15, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x01, 0x05, 0x12, 0x04, 0xb8, 0x01, 0x02, 0x08, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x13, 0x02, 0x01, 0x01, 0x12, 0x04, 0xb8, 0x01, 0x09, 0x10, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x13, 0x02, 0x01, 0x03, 0x12, 0x04, 0xb8, 0x01, 0x13, 0x14, 0x0a, 0x4b, 0x0a, 0x04,
+    0x04, 0x13, 0x02, 0x02, 0x12, 0x04, 0xba, 0x01, 0x02, 0x14, 0x1a, 0x3d, 0x20, 0x63, 0x61, 0x73,
+    0x65, 0x2d, 0x69, 0x6e, 0x73, 0x65, 0x6e, 0x73, 0x69, 0x74, 0x69, 0x76, 0x65, 0x20, 0x70, 0x61,
+    0x72, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x71, 0x6c, 0x2c, 0x20, 0x65,
+    0x6d, 0x70, 0x74, 0x79, 0x20, 0x6d, 0x61, 0x74, 0x63, 0x68, 0x65, 0x73, 0x20, 0x65, 0x76, 0x65,
+    0x72, 0x79, 0x20, 0x65, 0x6e, 0x74, 0x72, 0x79, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02,
+    0x02, 0x05, 0x12, 0x04, 0xba, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x02,
+    0x01, 0x12, 0x04, 0xba, 0x01, 0x09, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x02, 0x03,
+    0x12, 0x04, 0xba, 0x01, 0x12, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x03, 0x12, 0x04,
+    0xbb, 0x01, 0x02, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x03, 0x05, 0x12, 0x04, 0xbb,
+    0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x03, 0x01, 0x12, 0x04, 0xbb, 0x01,
+    0x09, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x03, 0x03, 0x12, 0x04, 0xbb, 0x01, 0x11,
+    0x12, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x14, 0x12, 0x06, 0xbe, 0x01, 0x00, 0xc4, 0x01, 0x01, 0x0a,
+    0x0b, 0x0a, 0x03, 0x04, 0x14, 0x01, 0x12, 0x04, 0xbe, 0x01, 0x08, 0x0f, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x14, 0x02, 0x00, 0x12, 0x04, 0xbf, 0x01, 0x02, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14,
+    0x02, 0x00, 0x05, 0x12, 0x04, 0xbf, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02,
+    0x00, 0x01, 0x12, 0x04, 0xbf, 0x01, 0x09, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00,
+    0x03, 0x12, 0x04, 0xbf, 0x01, 0x16, 0x17, 0x0a, 0x1c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x01, 0x12,
+    0x04, 0xc1, 0x01, 0x02, 0x24, 0x1a, 0x0e, 0x20, 0x6e, 0x65, 0x77, 0x65, 0x73, 0x74, 0x20, 0x66,
+    0x69, 0x72, 0x73, 0x74, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x01, 0x04, 0x12, 0x04,
+    0xc1, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x01, 0x06, 0x12, 0x04, 0xc1,
+    0x01, 0x0b, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x01, 0x01, 0x12, 0x04, 0xc1, 0x01,
+    0x18, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x01, 0x03, 0x12, 0x04, 0xc1, 0x01, 0x22,
+    0x23, 0x0a, 0x3d, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x02, 0x12, 0x04, 0xc3, 0x01, 0x02, 0x13, 0x1a,
+    0x2f, 0x20, 0x77, 0x68, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x68, 0x69, 0x73, 0x74, 0x6f, 0x72,
+    0x79, 0x20, 0x77, 0x61, 0x73, 0x20, 0x72, 0x65, 0x66, 0x75, 0x73, 0x65, 0x64, 0x2c, 0x20, 0x65,
+    0x6d, 0x70, 0x74, 0x79, 0x20, 0x6f, 0x6e, 0x20, 0x73, 0x75, 0x63, 0x63, 0x65, 0x73, 0x73, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x02, 0x05, 0x12, 0x04, 0xc3, 0x01, 0x02, 0x08, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x02, 0x01, 0x12, 0x04, 0xc3, 0x01, 0x09, 0x0e, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x14, 0x02, 0x02, 0x03, 0x12, 0x04, 0xc3, 0x01, 0x11, 0x12, 0x0a, 0x58, 0x0a,
+    0x02, 0x04, 0x15, 0x12, 0x06, 0xc7, 0x01, 0x00, 0xcd, 0x01, 0x01, 0x1a, 0x4a, 0x20, 0x61, 0x20,
+    0x63, 0x61, 0x74, 0x61, 0x6c, 0x6f, 0x67, 0x20, 0x64, 0x65, 0x66, 0x69, 0x6e, 0x69, 0x74, 0x69,
+    0x6f, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x6e, 0x20, 0x65, 0x61, 0x72, 0x6c, 0x69, 0x65, 0x72,
+    0x20, 0x72, 0x75, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x20,
+    0x66, 0x61, 0x69, 0x6c, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65,
+    0x20, 0x61, 0x67, 0x61, 0x69, 0x6e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x15, 0x01, 0x12, 0x04,
+    0xc7, 0x01, 0x08, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x15, 0x02, 0x00, 0x12, 0x04, 0xc8, 0x01,
+    0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x05, 0x12, 0x04, 0xc8, 0x01, 0x02,
+    0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x01, 0x12, 0x04, 0xc8, 0x01, 0x09, 0x10,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x03, 0x12, 0x04, 0xc8, 0x01, 0x13, 0x14, 0x0a,
+    0x2e, 0x0a, 0x04, 0x04, 0x15, 0x02, 0x01, 0x12, 0x04, 0xca, 0x01, 0x02, 0x12, 0x1a, 0x20, 0x20,
+    0x73, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x2c, 0x20, 0x65, 0x78, 0x74, 0x65, 0x72, 0x6e, 0x61, 0x6c,
+    0x20, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x20, 0x6f, 0x72, 0x20, 0x76, 0x69, 0x65, 0x77, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x01, 0x05, 0x12, 0x04, 0xca, 0x01, 0x02, 0x08, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x15, 0x02, 0x01, 0x01, 0x12, 0x04, 0xca, 0x01, 0x09, 0x0d, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x15, 0x02, 0x01, 0x03, 0x12, 0x04, 0xca, 0x01, 0x10, 0x11, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x15, 0x02, 0x02, 0x12, 0x04, 0xcb, 0x01, 0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15,
+    0x02, 0x02, 0x05, 0x12, 0x04, 0xcb, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02,
+    0x02, 0x01, 0x12, 0x04, 0xcb, 0x01, 0x09, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x02,
+    0x03, 0x12, 0x04, 0xcb, 0x01, 0x10, 0x11, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x15, 0x02, 0x03, 0x12,
+    0x04, 0xcc, 0x01, 0x02, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x03, 0x05, 0x12, 0x04,
+    0xcc, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x03, 0x01, 0x12, 0x04, 0xcc,
+    0x01, 0x09, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x03, 0x03, 0x12, 0x04, 0xcc, 0x01,
+    0x11, 0x12, 0x0a, 0x3d, 0x0a, 0x02, 0x04, 0x16, 0x12, 0x06, 0xd0, 0x01, 0x00, 0xd4, 0x01, 0x01,
+    0x1a, 0x2f, 0x20, 0x73, 0x65, 0x6e, 0x74, 0x20, 0x61, 0x66, 0x74, 0x65, 0x72, 0x20, 0x63, 0x6f,
+    0x6e, 0x6e, 0x65, 0x63, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x77, 0x68, 0x65,
+    0x6e, 0x65, 0x76, 0x65, 0x72, 0x20, 0x69, 0x74, 0x20, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x73,
+    0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x16, 0x01, 0x12, 0x04, 0xd0, 0x01, 0x08, 0x15, 0x0a, 0x4a,
+    0x0a, 0x04, 0x04, 0x16, 0x02, 0x00, 0x12, 0x04, 0xd2, 0x01, 0x02, 0x1f, 0x1a, 0x3c, 0x20, 0x63,
+    0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x73, 0x20, 0x63, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x73, 0x20,
+    0x6d, 0x61, 0x64, 0x65, 0x20, 0x64, 0x65, 0x66, 0x69, 0x6e, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x73,
+    0x20, 0x69, 0x6e, 0x2c, 0x20, 0x6b, 0x65, 0x70, 0x74, 0x20, 0x61, 0x63, 0x72, 0x6f, 0x73, 0x73,
+    0x20, 0x72, 0x65, 0x73, 0x74, 0x61, 0x72, 0x74, 0x73, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16,
+    0x02, 0x00, 0x04, 0x12, 0x04, 0xd2, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02,
+    0x00, 0x05, 0x12, 0x04, 0xd2, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x00,
+    0x01, 0x12, 0x04, 0xd2, 0x01, 0x12, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x00, 0x03,
+    0x12, 0x04, 0xd2, 0x01, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x16, 0x02, 0x01, 0x12, 0x04,
+    0xd3, 0x01, 0x02, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x01, 0x04, 0x12, 0x04, 0xd3,
+    0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x01, 0x06, 0x12, 0x04, 0xd3, 0x01,
+    0x0b, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x01, 0x01, 0x12, 0x04, 0xd3, 0x01, 0x18,
+    0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x01, 0x03, 0x12, 0x04, 0xd3, 0x01, 0x21, 0x22,
+    0x0a, 0x5b, 0x0a, 0x02, 0x04, 0x17, 0x12, 0x06, 0xd7, 0x01, 0x00, 0xdb, 0x01, 0x01, 0x1a, 0x4d,
+    0x20, 0x61, 0x20, 0x63, 0x6f, 0x6c, 0x75, 0x6d, 0x6e, 0x20, 0x6e, 0x61, 0x6d, 0x65, 0x64, 0x20,
+    0x61, 0x66, 0x74, 0x65, 0x72, 0x20, 0x61, 0x20, 0x64, 0x69, 0x72, 0x65, 0x63, 0x74, 0x6f, 0x72,
+    0x79, 0x20, 0x6c, 0x65, 0x76, 0x65, 0x6c, 0x2c, 0x20, 0x65, 0x2e, 0x67, 0x2e, 0x20, 0x64, 0x74,
+    0x20, 0x69, 0x6e, 0x20, 0x64, 0x74, 0x3d, 0x32, 0x30, 0x32, 0x34, 0x2d, 0x30, 0x31, 0x2d, 0x30,
+    0x31, 0x2f, 0x72, 0x65, 0x67, 0x69, 0x6f, 0x6e, 0x3d, 0x65, 0x75, 0x2f, 0x0a, 0x0a, 0x0b, 0x0a,
+    0x03, 0x04, 0x17, 0x01, 0x12, 0x04, 0xd7, 0x01, 0x08, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x17,
+    0x02, 0x00, 0x12, 0x04, 0xd8, 0x01, 0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x00,
+    0x05, 0x12, 0x04, 0xd8, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x00, 0x01,
+    0x12, 0x04, 0xd8, 0x01, 0x09, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x00, 0x03, 0x12,
+    0x04, 0xd8, 0x01, 0x10, 0x11, 0x0a, 0x39, 0x0a, 0x04, 0x04, 0x17, 0x02, 0x01, 0x12, 0x04, 0xda,
+    0x01, 0x02, 0x17, 0x1a, 0x2b, 0x20, 0x61, 0x6e, 0x20, 0x41, 0x72, 0x72, 0x6f, 0x77, 0x20, 0x74,
+    0x79, 0x70, 0x65, 0x2c, 0x20, 0x65, 0x2e, 0x67, 0x2e, 0x20, 0x44, 0x61, 0x74, 0x65, 0x33, 0x32,
+    0x2c, 0x20, 0x49, 0x6e, 0x74, 0x33, 0x32, 0x20, 0x6f, 0x72, 0x20, 0x55, 0x74, 0x66, 0x38, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x01, 0x05, 0x12, 0x04, 0xda, 0x01, 0x02, 0x08, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x01, 0x01, 0x12, 0x04, 0xda, 0x01, 0x09, 0x12, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x17, 0x02, 0x01, 0x03, 0x12, 0x04, 0xda, 0x01, 0x15, 0x16, 0x0a, 0x0c, 0x0a,
+    0x02, 0x04, 0x18, 0x12, 0x06, 0xdd, 0x01, 0x00, 0xe1, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04,
+    0x18, 0x01, 0x12, 0x04, 0xdd, 0x01, 0x08, 0x12, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x18, 0x02, 0x00,
+    0x12, 0x04, 0xde, 0x01, 0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x00, 0x05, 0x12,
+    0x04, 0xde, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x00, 0x01, 0x12, 0x04,
+    0xde, 0x01, 0x09, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x00, 0x03, 0x12, 0x04, 0xde,
+    0x01, 0x10, 0x11, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x18, 0x02, 0x01, 0x12, 0x04, 0xdf, 0x01, 0x02,
+    0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x01, 0x05, 0x12, 0x04, 0xdf, 0x01, 0x02, 0x06,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x01, 0x01, 0x12, 0x04, 0xdf, 0x01, 0x07, 0x11, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x01, 0x03, 0x12, 0x04, 0xdf, 0x01, 0x14, 0x15, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x18, 0x02, 0x02, 0x12, 0x04, 0xe0, 0x01, 0x02, 0x17, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x18, 0x02, 0x02, 0x05, 0x12, 0x04, 0xe0, 0x01, 0x02, 0x06, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x18, 0x02, 0x02, 0x01, 0x12, 0x04, 0xe0, 0x01, 0x07, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18,
+    0x02, 0x02, 0x03, 0x12, 0x04, 0xe0, 0x01, 0x15, 0x16, 0x0a, 0x61, 0x0a, 0x02, 0x04, 0x19, 0x12,
+    0x06, 0xe4, 0x01, 0x00, 0xf6, 0x01, 0x01, 0x1a, 0x53, 0x20, 0x72, 0x65, 0x67, 0x69, 0x73, 0x74,
+    0x65, 0x72, 0x73, 0x20, 0x61, 0x20, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x20, 0x6f, 0x76, 0x65, 0x72,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x69, 0x6c, 0x65, 0x73, 0x20, 0x62, 0x65, 0x6c, 0x6f, 0x77,
+    0x20, 0x61, 0x20, 0x64, 0x69, 0x72, 0x65, 0x63, 0x74, 0x6f, 0x72, 0x79, 0x2c, 0x20, 0x6b, 0x65,
+    0x70, 0x74, 0x20, 0x61, 0x63, 0x72, 0x6f, 0x73, 0x73, 0x20, 0x72, 0x65, 0x73, 0x74, 0x61, 0x72,
+    0x74, 0x73, 0x20, 0x6c, 0x69, 0x6b, 0x65, 0x20, 0x44, 0x44, 0x4c, 0x0a, 0x0a, 0x0b, 0x0a, 0x03,
+    0x04, 0x19, 0x01, 0x12, 0x04, 0xe4, 0x01, 0x08, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x19, 0x02,
+    0x00, 0x12, 0x04, 0xe5, 0x01, 0x02, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x00, 0x05,
+    0x12, 0x04, 0xe5, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x00, 0x01, 0x12,
+    0x04, 0xe5, 0x01, 0x09, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x00, 0x03, 0x12, 0x04,
+    0xe5, 0x01, 0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x19, 0x02, 0x01, 0x12, 0x04, 0xe6, 0x01,
+    0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x01, 0x05, 0x12, 0x04, 0xe6, 0x01, 0x02,
+    0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x01, 0x01, 0x12, 0x04, 0xe6, 0x01, 0x09, 0x10,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x01, 0x03, 0x12, 0x04, 0xe6, 0x01, 0x13, 0x14, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x19, 0x02, 0x02, 0x12, 0x04, 0xe7, 0x01, 0x02, 0x12, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x19, 0x02, 0x02, 0x05, 0x12, 0x04, 0xe7, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x19, 0x02, 0x02, 0x01, 0x12, 0x04, 0xe7, 0x01, 0x09, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x19, 0x02, 0x02, 0x03, 0x12, 0x04, 0xe7, 0x01, 0x10, 0x11, 0x0a, 0x32, 0x0a, 0x04, 0x04, 0x19,
+    0x02, 0x03, 0x12, 0x04, 0xe9, 0x01, 0x02, 0x16, 0x1a, 0x24, 0x20, 0x61, 0x20, 0x64, 0x69, 0x72,
+    0x65, 0x63, 0x74, 0x6f, 0x72, 0x79, 0x2c, 0x20, 0x61, 0x73, 0x20, 0x61, 0x20, 0x70, 0x61, 0x74,
+    0x68, 0x20, 0x6f, 0x72, 0x20, 0x66, 0x69, 0x6c, 0x65, 0x20, 0x55, 0x52, 0x4c, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x19, 0x02, 0x03, 0x05, 0x12, 0x04, 0xe9, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x19, 0x02, 0x03, 0x01, 0x12, 0x04, 0xe9, 0x01, 0x09, 0x11, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x19, 0x02, 0x03, 0x03, 0x12, 0x04, 0xe9, 0x01, 0x14, 0x15, 0x0a, 0x31, 0x0a, 0x04, 0x04,
+    0x19, 0x02, 0x04, 0x12, 0x04, 0xeb, 0x01, 0x02, 0x17, 0x1a, 0x23, 0x20, 0x70, 0x61, 0x72, 0x71,
+    0x75, 0x65, 0x74, 0x2c, 0x20, 0x63, 0x73, 0x76, 0x2c, 0x20, 0x6a, 0x73, 0x6f, 0x6e, 0x2c, 0x20,
+    0x61, 0x76, 0x72, 0x6f, 0x20, 0x6f, 0x72, 0x20, 0x61, 0x72, 0x72, 0x6f, 0x77, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x19, 0x02, 0x04, 0x05, 0x12, 0x04, 0xeb, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x19, 0x02, 0x04, 0x01, 0x12, 0x04, 0xeb, 0x01, 0x09, 0x12, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x19, 0x02, 0x04, 0x03, 0x12, 0x04, 0xeb, 0x01, 0x15, 0x16, 0x0a, 0x34, 0x0a, 0x04, 0x04,
+    0x19, 0x02, 0x05, 0x12, 0x04, 0xed, 0x01, 0x02, 0x31, 0x1a, 0x26, 0x20, 0x69, 0x6e, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x64, 0x69, 0x72, 0x65, 0x63, 0x74, 0x6f, 0x72, 0x79, 0x20, 0x6c, 0x65, 0x76, 0x65, 0x6c, 0x73,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x05, 0x04, 0x12, 0x04, 0xed, 0x01, 0x02, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x05, 0x06, 0x12, 0x04, 0xed, 0x01, 0x0b, 0x1a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x05, 0x01, 0x12, 0x04, 0xed, 0x01, 0x1b, 0x2c, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x19, 0x02, 0x05, 0x03, 0x12, 0x04, 0xed, 0x01, 0x2f, 0x30, 0x0a, 0x54, 0x0a,
+    0x04, 0x04, 0x19, 0x02, 0x06, 0x12, 0x04, 0xef, 0x01, 0x02, 0x1c, 0x1a, 0x46, 0x20, 0x6f, 0x6e,
+    0x6c, 0x79, 0x20, 0x66, 0x69, 0x6c, 0x65, 0x73, 0x20, 0x65, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x20,
+    0x77, 0x69, 0x74, 0x68, 0x20, 0x69, 0x74, 0x20, 0x61, 0x72, 0x65, 0x20, 0x72, 0x65, 0x61, 0x64,
+    0x2c, 0x20, 0x22, 0x2e, 0x22, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x69,
+    0x6c, 0x65, 0x20, 0x74, 0x79, 0x70, 0x65, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x65, 0x6d, 0x70,
+    0x74, 0x79, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x06, 0x05, 0x12, 0x04, 0xef, 0x01,
+    0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x06, 0x01, 0x12, 0x04, 0xef, 0x01, 0x09,
+    0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x06, 0x03, 0x12, 0x04, 0xef, 0x01, 0x1a, 0x1b,
+    0x0a, 0x50, 0x0a, 0x04, 0x04, 0x19, 0x02, 0x07, 0x12, 0x04, 0xf1, 0x01, 0x02, 0x25, 0x1a, 0x42,
+    0x20, 0x68, 0x6f, 0x77, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x6f, 0x77, 0x73, 0x20, 0x6f, 0x66,
+    0x20, 0x65, 0x76, 0x65, 0x72, 0x79, 0x20, 0x66, 0x69, 0x6c, 0x65, 0x20, 0x61, 0x72, 0x65, 0x20,
+    0x73, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x2c, 0x20, 0x73, 0x70, 0x61, 0x72, 0x65, 0x73, 0x20, 0x73,
+    0x6f, 0x72, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x6d, 0x20, 0x61, 0x67, 0x61, 0x69,
+    0x6e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x07, 0x04, 0x12, 0x04, 0xf1, 0x01, 0x02,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x07, 0x06, 0x12, 0x04, 0xf1, 0x01, 0x0b, 0x15,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x07, 0x01, 0x12, 0x04, 0xf1, 0x01, 0x16, 0x20, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x07, 0x03, 0x12, 0x04, 0xf1, 0x01, 0x23, 0x24, 0x0a, 0x32,
+    0x0a, 0x04, 0x04, 0x19, 0x02, 0x08, 0x12, 0x04, 0xf3, 0x01, 0x02, 0x16, 0x1a, 0x24, 0x20, 0x63,
+    0x73, 0x76, 0x20, 0x66, 0x69, 0x6c, 0x65, 0x73, 0x20, 0x73, 0x74, 0x61, 0x72, 0x74, 0x20, 0x77,
+    0x69, 0x74, 0x68, 0x20, 0x61, 0x20, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72, 0x20, 0x6c, 0x69, 0x6e,
+    0x65, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x08, 0x05, 0x12, 0x04, 0xf3, 0x01, 0x02,
+    0x06, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x08, 0x01, 0x12, 0x04, 0xf3, 0x01, 0x07, 0x11,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x08, 0x03, 0x12, 0x04, 0xf3, 0x01, 0x14, 0x15, 0x0a,
+    0x44, 0x0a, 0x04, 0x04, 0x19, 0x02, 0x09, 0x12, 0x04, 0xf5, 0x01, 0x02, 0x14, 0x1a, 0x36, 0x20,
+    0x72, 0x65, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x73, 0x20, 0x61, 0x20, 0x74, 0x61, 0x62, 0x6c, 0x65,
+    0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x61, 0x6d, 0x65, 0x20, 0x6e, 0x61, 0x6d,
+    0x65, 0x20, 0x69, 0x6e, 0x73, 0x74, 0x65, 0x61, 0x64, 0x20, 0x6f, 0x66, 0x20, 0x66, 0x61, 0x69,
+    0x6c, 0x69, 0x6e, 0x67, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x09, 0x05, 0x12, 0x04,
+    0xf5, 0x01, 0x02, 0x06, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x09, 0x01, 0x12, 0x04, 0xf5,
+    0x01, 0x07, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x09, 0x03, 0x12, 0x04, 0xf5, 0x01,
+    0x11, 0x13, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x1a, 0x12, 0x06, 0xf8, 0x01, 0x00, 0xff, 0x01, 0x01,
+    0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1a, 0x01, 0x12, 0x04, 0xf8, 0x01, 0x08, 0x17, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x1a, 0x02, 0x00, 0x12, 0x04, 0xf9, 0x01, 0x02, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1a, 0x02, 0x00, 0x05, 0x12, 0x04, 0xf9, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a,
+    0x02, 0x00, 0x01, 0x12, 0x04, 0xf9, 0x01, 0x09, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02,
+    0x00, 0x03, 0x12, 0x04, 0xf9, 0x01, 0x16, 0x17, 0x0a, 0x20, 0x0a, 0x04, 0x04, 0x1a, 0x02, 0x01,
+    0x12, 0x04, 0xfb, 0x01, 0x02, 0x13, 0x1a, 0x12, 0x20, 0x65, 0x6d, 0x70, 0x74, 0x79, 0x20, 0x6f,
+    0x6e, 0x20, 0x73, 0x75, 0x63, 0x63, 0x65, 0x73, 0x73, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a,
+    0x02, 0x01, 0x05, 0x12, 0x04, 0xfb, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02,
+    0x01, 0x01, 0x12, 0x04, 0xfb, 0x01, 0x09, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x01,
+    0x03, 0x12, 0x04, 0xfb, 0x01, 0x11, 0x12, 0x0a, 0x28, 0x0a, 0x04, 0x04, 0x1a, 0x02, 0x02, 0x12,
+    0x04, 0xfd, 0x01, 0x02, 0x13, 0x1a, 0x1a, 0x20, 0x66, 0x6f, 0x75, 0x6e, 0x64, 0x20, 0x62, 0x65,
+    0x6c, 0x6f, 0x77, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x6f, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x02, 0x05, 0x12, 0x04, 0xfd, 0x01, 0x02, 0x08,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x02, 0x01, 0x12, 0x04, 0xfd, 0x01, 0x09, 0x0e, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x02, 0x03, 0x12, 0x04, 0xfd, 0x01, 0x11, 0x12, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x1a, 0x02, 0x03, 0x12, 0x04, 0xfe, 0x01, 0x02, 0x18, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x1a, 0x02, 0x03, 0x05, 0x12, 0x04, 0xfe, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1a, 0x02, 0x03, 0x01, 0x12, 0x04, 0xfe, 0x01, 0x09, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a,
+    0x02, 0x03, 0x03, 0x12, 0x04, 0xfe, 0x01, 0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x1b, 0x12,
+    0x06, 0x81, 0x02, 0x00, 0x93, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1b, 0x01, 0x12, 0x04,
+    0x81, 0x02, 0x08, 0x14, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x1b, 0x08, 0x00, 0x12, 0x06, 0x82, 0x02,
+    0x02, 0x92, 0x02, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x08, 0x00, 0x01, 0x12, 0x04, 0x82,
+    0x02, 0x08, 0x0f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1b, 0x02, 0x00, 0x12, 0x04, 0x83, 0x02, 0x04,
+    0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x00, 0x06, 0x12, 0x04, 0x83, 0x02, 0x04, 0x0e,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x00, 0x01, 0x12, 0x04, 0x83, 0x02, 0x0f, 0x19, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x00, 0x03, 0x12, 0x04, 0x83, 0x02, 0x1c, 0x1f, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x1b, 0x02, 0x01, 0x12, 0x04, 0x84, 0x02, 0x04, 0x27, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x1b, 0x02, 0x01, 0x06, 0x12, 0x04, 0x84, 0x02, 0x04, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1b, 0x02, 0x01, 0x01, 0x12, 0x04, 0x84, 0x02, 0x11, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b,
+    0x02, 0x01, 0x03, 0x12, 0x04, 0x84, 0x02, 0x23, 0x26, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1b, 0x02,
+    0x02, 0x12, 0x04, 0x85, 0x02, 0x04, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x02, 0x06,
+    0x12, 0x04, 0x85, 0x02, 0x04, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x02, 0x01, 0x12,
+    0x04, 0x85, 0x02, 0x0f, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x02, 0x03, 0x12, 0x04,
+    0x85, 0x02, 0x1f, 0x22, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1b, 0x02, 0x03, 0x12, 0x04, 0x86, 0x02,
+    0x04, 0x29, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x03, 0x06, 0x12, 0x04, 0x86, 0x02, 0x04,
+    0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x03, 0x01, 0x12, 0x04, 0x86, 0x02, 0x12, 0x22,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x03, 0x03, 0x12, 0x04, 0x86, 0x02, 0x25, 0x28, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x1b, 0x02, 0x04, 0x12, 0x04, 0x87, 0x02, 0x04, 0x25, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x1b, 0x02, 0x04, 0x06, 0x12, 0x04, 0x87, 0x02, 0x04, 0x0f, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x1b, 0x02, 0x04, 0x01, 0x12, 0x04, 0x87, 0x02, 0x10, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1b, 0x02, 0x04, 0x03, 0x12, 0x04, 0x87, 0x02, 0x21, 0x24, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1b,
+    0x02, 0x05, 0x12, 0x04, 0x88, 0x02, 0x04, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x05,
+    0x06, 0x12, 0x04, 0x88, 0x02, 0x04, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x05, 0x01,
+    0x12, 0x04, 0x88, 0x02, 0x10, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x05, 0x03, 0x12,
+    0x04, 0x88, 0x02, 0x1e, 0x21, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1b, 0x02, 0x06, 0x12, 0x04, 0x89,
+    0x02, 0x04, 0x25, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x06, 0x06, 0x12, 0x04, 0x89, 0x02,
+    0x04, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x06, 0x01, 0x12, 0x04, 0x89, 0x02, 0x10,
+    0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x06, 0x03, 0x12, 0x04, 0x89, 0x02, 0x21, 0x24,
+    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1b, 0x02, 0x07, 0x12, 0x04, 0x8a, 0x02, 0x04, 0x22, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x1b, 0x02, 0x07, 0x06, 0x12, 0x04, 0x8a, 0x02, 0x04, 0x0f, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x1b, 0x02, 0x07, 0x01, 0x12, 0x04, 0x8a, 0x02, 0x10, 0x1b, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x1b, 0x02, 0x07, 0x03, 0x12, 0x04, 0x8a, 0x02, 0x1e, 0x21, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x1b, 0x02, 0x08, 0x12, 0x04, 0x8b, 0x02, 0x04, 0x25, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02,
+    0x08, 0x06, 0x12, 0x04, 0x8b, 0x02, 0x04, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x08,
+    0x01, 0x12, 0x04, 0x8b, 0x02, 0x10, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x08, 0x03,
+    0x12, 0x04, 0x8b, 0x02, 0x21, 0x24, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1b, 0x02, 0x09, 0x12, 0x04,
+    0x8c, 0x02, 0x04, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x09, 0x06, 0x12, 0x04, 0x8c,
+    0x02, 0x04, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x09, 0x01, 0x12, 0x04, 0x8c, 0x02,
+    0x0f, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x09, 0x03, 0x12, 0x04, 0x8c, 0x02, 0x1f,
+    0x22, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1b, 0x02, 0x0a, 0x12, 0x04, 0x8d, 0x02, 0x04, 0x1d, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x0a, 0x06, 0x12, 0x04, 0x8d, 0x02, 0x04, 0x0b, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x1b, 0x02, 0x0a, 0x01, 0x12, 0x04, 0x8d, 0x02, 0x0c, 0x16, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x1b, 0x02, 0x0a, 0x03, 0x12, 0x04, 0x8d, 0x02, 0x19, 0x1c, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x1b, 0x02, 0x0b, 0x12, 0x04, 0x8e, 0x02, 0x04, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b,
+    0x02, 0x0b, 0x06, 0x12, 0x04, 0x8e, 0x02, 0x04, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02,
+    0x0b, 0x01, 0x12, 0x04, 0x8e, 0x02, 0x0b, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x0b,
+    0x03, 0x12, 0x04, 0x8e, 0x02, 0x17, 0x1a, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1b, 0x02, 0x0c, 0x12,
+    0x04, 0x8f, 0x02, 0x04, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x0c, 0x06, 0x12, 0x04,
+    0x8f, 0x02, 0x04, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x0c, 0x01, 0x12, 0x04, 0x8f,
+    0x02, 0x12, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x0c, 0x03, 0x12, 0x04, 0x8f, 0x02,
+    0x1f, 0x22, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1b, 0x02, 0x0d, 0x12, 0x04, 0x90, 0x02, 0x04, 0x29,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x0d, 0x06, 0x12, 0x04, 0x90, 0x02, 0x04, 0x11, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x0d, 0x01, 0x12, 0x04, 0x90, 0x02, 0x12, 0x22, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x1b, 0x02, 0x0d, 0x03, 0x12, 0x04, 0x90, 0x02, 0x25, 0x28, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x1b, 0x02, 0x0e, 0x12, 0x04, 0x91, 0x02, 0x04, 0x2d, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1b, 0x02, 0x0e, 0x06, 0x12, 0x04, 0x91, 0x02, 0x04, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b,
+    0x02, 0x0e, 0x01, 0x12, 0x04, 0x91, 0x02, 0x14, 0x26, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02,
+    0x0e, 0x03, 0x12, 0x04, 0x91, 0x02, 0x29, 0x2c, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
+];
+// @@protoc_insertion_point(module)
\ No newline at end of file
@@ -0,0 +1,212 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use datafusion::logical_expr::{
+    CreateMemoryTable, DdlStatement, DmlStatement, EmptyRelation, LogicalPlan, WriteOp,
+};
+use serde::Deserialize;
+
+use proto_gen::auth::ContextPermissions;
+
+use crate::context::context_name;
+use crate::errors::AppErrors;
+use crate::sessions::Role;
+
+/// What a statement or an RPC needs to be allowed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Permission {
+    /// queries, `EXPLAIN`, `SHOW`
+    Read,
+    /// `CREATE` and `DROP` of tables, views and schemas, `SET`
+    Ddl,
+    /// `INSERT`, `COPY TO` and `CREATE TABLE AS`
+    Write,
+    /// admin RPCs, it comes with the admin role and is the same in every context
+    Admin,
+}
+
+impl Permission {
+    pub fn name(self) -> &'static str {
+        match self {
+            Permission::Read => "read",
+            Permission::Ddl => "ddl",
+            Permission::Write => "write",
+            Permission::Admin => "admin",
+        }
+    }
+}
+
+/// Permissions of the roles in every context.
+#[derive(Default)]
+pub struct AccessControl {
+    /// by context, then role, replacing the defaults of the role there
+    contexts: BTreeMap<String, BTreeMap<Role, BTreeSet<Permission>>>,
+}
+
+impl AccessControl {
+    /// `contexts` are the `permissions` of the config file, by context name and role.
+    pub fn new(
+        contexts: BTreeMap<String, BTreeMap<Role, Vec<Permission>>>,
+    ) -> Result<Self, AppErrors> {
+        let mut access = Self::default();
+        for (context, roles) in contexts {
+            let mut granted = BTreeMap::new();
+            for (role, permissions) in roles {
+                if permissions.contains(&Permission::Admin) {
+                    return Err(format!(
+                        "context {context} grants admin to {}, only the admin role has it",
+                        role.name()
+                    )
+                    .into());
+                }
+                let mut permissions = permissions.into_iter().collect::<BTreeSet<_>>();
+                if role == Role::Admin {
+                    permissions.insert(Permission::Admin);
+                }
+                granted.insert(role, permissions);
+            }
+            access.contexts.insert(context, granted);
+        }
+        Ok(access)
+    }
+
+    pub fn permissions(&self, role: Role, context: &str) -> BTreeSet<Permission> {
+        self.contexts
+            .get(context_name(context))
+            .and_then(|roles| roles.get(&role))
+            .cloned()
+            .unwrap_or_else(|| role.default_permissions())
+    }
+
+    /// Fails unless `role` may run `plan` in `context`, the error names what is missing.
+    pub fn check(&self, role: Role, context: &str, plan: &LogicalPlan) -> Result<(), AppErrors> {
+        let granted = self.permissions(role, context);
+        let mut needed = BTreeSet::new();
+        required(plan, &mut needed);
+        match needed.difference(&granted).next() {
+            None => Ok(()),
+            Some(missing) => Err(denied(role, *missing, context)),
+        }
+    }
+
+    /// Fails unless `role` has `permission` in `context`.
+    pub fn require(
+        &self,
+        role: Role,
+        context: &str,
+        permission: Permission,
+    ) -> Result<(), AppErrors> {
+        if self.permissions(role, context).contains(&permission) {
+            Ok(())
+        } else {
+            Err(denied(role, permission, context))
+        }
+    }
+
+    /// The permissions of `role` sent to its clients: the defaults, and the contexts that
+    /// replace them.
+    pub fn describe(&self, role: Role) -> (Vec<String>, Vec<(String, ContextPermissions)>) {
+        let contexts = self
+            .contexts
+            .iter()
+            .filter_map(|(context, roles)| {
+                let permissions = roles.get(&role)?;
+                Some((
+                    context.clone(),
+                    ContextPermissions {
+                        permissions: names(permissions),
+                    },
+                ))
+            })
+            .collect();
+        (names(&role.default_permissions()), contexts)
+    }
+}
+
+fn denied(role: Role, missing: Permission, context: &str) -> AppErrors {
+    format!(
+        "permission denied: the {} role has no {} permission in context {}",
+        role.name(),
+        missing.name(),
+        context_name(context)
+    )
+    .into()
+}
+
+fn names(permissions: &BTreeSet<Permission>) -> Vec<String> {
+    permissions.iter().map(|p| p.name().to_string()).collect()
+}
+
+/// Adds what running `plan` needs to `needed`, `EXPLAIN` of a write needs the write as well.
+fn required(plan: &LogicalPlan, needed: &mut BTreeSet<Permission>) {
+    needed.insert(Permission::Read);
+    match plan {
+        // `CREATE TABLE AS` plans to a memory table filled from its input, a table of
+        // declared columns only has an empty one
+        LogicalPlan::Ddl(DdlStatement::CreateMemoryTable(CreateMemoryTable { input, .. }))
+            if !matches!(
+                input.as_ref(),
+                LogicalPlan::EmptyRelation(EmptyRelation {
+                    produce_one_row: false,
+                    ..
+                })
+            ) =>
+        {
+            needed.insert(Permission::Ddl);
+            needed.insert(Permission::Write);
+        }
+        LogicalPlan::Ddl(_) | LogicalPlan::Statement(_) => {
+            needed.insert(Permission::Ddl);
+        }
+        LogicalPlan::Dml(DmlStatement {
+            op: WriteOp::Ctas, ..
+        }) => {
+            needed.insert(Permission::Ddl);
+            needed.insert(Permission::Write);
+        }
+        LogicalPlan::Dml(_) | LogicalPlan::Copy(_) => {
+            needed.insert(Permission::Write);
+        }
+        _ => {}
+    }
+    for input in plan.inputs() {
+        required(input, needed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use datafusion::prelude::{SessionConfig, SessionContext};
+
+    use super::*;
+    use Permission::*;
+
+    async fn needed(ctx: &SessionContext, sql: &str) -> Vec<Permission> {
+        let plan = ctx.state().create_logical_plan(sql).await.unwrap();
+        let mut needed = BTreeSet::new();
+        required(&plan, &mut needed);
+        needed.into_iter().collect()
+    }
+
+    #[tokio::test]
+    async fn statements_need_the_permissions_of_what_they_do() {
+        let ctx =
+            SessionContext::new_with_config(SessionConfig::new().with_information_schema(true));
+        ctx.sql("CREATE TABLE t (a INT)").await.unwrap();
+        let cases = [
+            ("SELECT a FROM t", vec![Read]),
+            ("EXPLAIN SELECT a FROM t", vec![Read]),
+            ("SHOW TABLES", vec![Read]),
+            ("INSERT INTO t VALUES (1)", vec![Read, Write]),
+            ("EXPLAIN INSERT INTO t VALUES (1)", vec![Read, Write]),
+            ("CREATE TABLE u (a INT)", vec![Read, Ddl]),
+            ("CREATE TABLE u AS SELECT a FROM t", vec![Read, Ddl, Write]),
+            ("CREATE VIEW v AS SELECT a FROM t", vec![Read, Ddl]),
+            ("DROP TABLE t", vec![Read, Ddl]),
+            ("SET datafusion.execution.batch_size = 10", vec![Read, Ddl]),
+        ];
+        for (sql, expected) in cases {
+            assert_eq!(needed(&ctx, sql).await, expected, "{sql}");
+        }
+    }
+}
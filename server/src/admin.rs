@@ -0,0 +1,130 @@
+use std::sync::Arc;
+
+use prost::Message as _;
+use tokio::sync::mpsc::Sender;
+
+use proto_gen::admin::{
+    admin_service::Service, AdminResult, AdminService, AuditEntries, LogFilterState, SessionList,
+};
+
+use crate::errors::AppErrors;
+use crate::sessions::Session;
+use crate::state::ServerState;
+
+async fn reply(tx: &Sender<Vec<u8>>, service: Service) -> Result<(), AppErrors> {
+    let msg = AdminService {
+        service: Some(service),
+    }
+    .encode_to_vec();
+    tx.send(msg)
+        .await
+        .map_err(|e| format!("failed to queue reply, session closed: {e}"))?;
+    Ok(())
+}
+
+fn error_of(result: Result<(), AppErrors>) -> String {
+    result.err().map(|e| e.to_string()).unwrap_or_default()
+}
+
+/// Serves the requests changing the server itself, `session` is the one asking.
+pub(crate) async fn handle_admin(
+    state: Arc<ServerState>,
+    tx: Sender<Vec<u8>>,
+    session: Arc<Session>,
+    service: Service,
+) -> Result<(), AppErrors> {
+    match service {
+        Service::GetLogFilterMsg(m) => {
+            let reply_msg = match session.require_admin("reading the log filter") {
+                Ok(()) => LogFilterState {
+                    request_id: m.request_id,
+                    directive: state.log_filter.directive(),
+                    error: String::new(),
+                },
+                Err(e) => LogFilterState {
+                    request_id: m.request_id,
+                    directive: String::new(),
+                    error: e.to_string(),
+                },
+            };
+            reply(&tx, Service::LogFilterMsg(reply_msg)).await
+        }
+        Service::SetLogFilterMsg(m) => {
+            let result = session
+                .require_admin("changing the log filter")
+                .and_then(|()| state.log_filter.set(m.directive.trim()));
+            let reply_msg = LogFilterState {
+                request_id: m.request_id,
+                directive: state.log_filter.directive(),
+                error: error_of(result),
+            };
+            reply(&tx, Service::LogFilterMsg(reply_msg)).await
+        }
+        Service::ListSessionsMsg(m) => {
+            let mut reply_msg = SessionList {
+                request_id: m.request_id,
+                own_id: session.id(),
+                ..Default::default()
+            };
+            match session.require_admin("listing sessions") {
+                Ok(()) => {
+                    let mut running = state.queries.running_by_client();
+                    reply_msg.sessions = state
+                        .sessions
+                        .list()
+                        .iter()
+                        .map(|s| {
+                            let queries = running.remove(&s.peer().to_string());
+                            s.info(queries.unwrap_or_default())
+                        })
+                        .collect();
+                }
+                Err(e) => reply_msg.error = e.to_string(),
+            }
+            reply(&tx, Service::SessionsMsg(reply_msg)).await
+        }
+        Service::CancelQueryMsg(m) => {
+            let result = session
+                .require_admin("cancelling a query")
+                .and_then(|()| state.queries.kill(m.query_id));
+            let reply_msg = AdminResult {
+                request_id: m.request_id,
+                error: error_of(result),
+            };
+            reply(&tx, Service::ResultMsg(reply_msg)).await
+        }
+        Service::DisconnectMsg(m) => {
+            let result = session
+                .require_admin("disconnecting a client")
+                .and_then(|()| state.sessions.disconnect(m.session_id));
+            let reply_msg = AdminResult {
+                request_id: m.request_id,
+                error: error_of(result),
+            };
+            reply(&tx, Service::ResultMsg(reply_msg)).await
+        }
+        Service::QueryAuditMsg(m) => {
+            let mut reply_msg = AuditEntries {
+                request_id: m.request_id,
+                ..Default::default()
+            };
+            let result = match session.require_admin("reading the audit log") {
+                Ok(()) => {
+                    let state = state.clone();
+                    // the files are read synchronously
+                    tokio::task::spawn_blocking(move || state.audit.search(&m))
+                        .await
+                        .map_err(|e| AppErrors::from(format!("audit search failed: {e}")))
+                        .and_then(|entries| entries)
+                }
+                Err(e) => Err(e),
+            };
+            match result {
+                Ok(entries) => reply_msg.entries = entries,
+                Err(e) => reply_msg.error = e.to_string(),
+            }
+            reply(&tx, Service::AuditMsg(reply_msg)).await
+        }
+        other => Err(format!("unexpected admin msg from client: {other:?}").into()),
+    }
+}
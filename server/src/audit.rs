@@ -0,0 +1,431 @@
+use std::collections::VecDeque;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead as _, BufReader, BufWriter, Write as _};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use datafusion::logical_expr::LogicalPlan;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tokio::sync::mpsc::error::TryRecvError;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+use tracing::{error, info};
+
+use proto_gen::admin::{AuditEntry, QueryAudit};
+
+use crate::errors::AppErrors;
+
+/// The file being written, rotated files are named after the day they were started.
+const CURRENT_FILE: &str = "audit.jsonl";
+const DEFAULT_MAX_FILE_BYTES: u64 = 64 * 1024 * 1024;
+const DEFAULT_SEARCH_LIMIT: usize = 500;
+const MS_PER_DAY: u64 = 24 * 60 * 60 * 1000;
+
+/// The `audit` section of the config file.
+#[derive(Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AuditConfig {
+    /// `audit` in the state directory when unset
+    dir: Option<PathBuf>,
+    /// the file is rotated once this large, 64 MiB by default
+    max_file_bytes: u64,
+    /// the file is rotated when the UTC day changes as well
+    daily: bool,
+    /// rotated files kept, the oldest are deleted on rotation beyond it; all when unset
+    max_files: Option<usize>,
+    /// rotated files last written longer ago are deleted on rotation, kept when unset
+    max_age_days: Option<u64>,
+}
+
+impl Default for AuditConfig {
+    fn default() -> Self {
+        Self {
+            dir: None,
+            max_file_bytes: DEFAULT_MAX_FILE_BYTES,
+            daily: true,
+            max_files: None,
+            max_age_days: None,
+        }
+    }
+}
+
+/// One executed statement, a line of the log.
+#[derive(Serialize, Deserialize)]
+pub struct AuditRecord {
+    /// start, milliseconds since the epoch
+    pub timestamp_ms: u64,
+    /// the peer host without authentication
+    pub user: String,
+    pub peer_addr: String,
+    pub context: String,
+    /// the statement, or `REGISTER TABLE` for a table registered by a client
+    pub sql: String,
+    /// empty for a registered table
+    pub plan_hash: String,
+    pub outcome: Outcome,
+    pub rows: u64,
+    pub duration_ms: u64,
+    pub error: String,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Outcome {
+    Succeeded,
+    /// a cap cut the result short
+    Partial,
+    Failed,
+    /// from the monitor or by an admin
+    Killed,
+}
+
+impl Outcome {
+    pub fn name(self) -> &'static str {
+        match self {
+            Outcome::Succeeded => "succeeded",
+            Outcome::Partial => "partial",
+            Outcome::Failed => "failed",
+            Outcome::Killed => "killed",
+        }
+    }
+}
+
+impl From<AuditRecord> for AuditEntry {
+    fn from(record: AuditRecord) -> Self {
+        AuditEntry {
+            timestamp_ms: record.timestamp_ms,
+            user: record.user,
+            peer_addr: record.peer_addr,
+            context: record.context,
+            sql: record.sql,
+            plan_hash: record.plan_hash,
+            outcome: record.outcome.name().to_string(),
+            rows: record.rows,
+            duration_ms: record.duration_ms,
+            error: record.error,
+        }
+    }
+}
+
+/// Hex SHA-256 of the plan as displayed, the same statement over the same tables hashes
+/// alike.
+pub fn plan_hash(plan: &LogicalPlan) -> String {
+    Sha256::digest(plan.display_indent().to_string().as_bytes())
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+/// Append-only JSON lines of every statement executed, written by a thread of its own so
+/// statements don't wait for the disk.
+pub struct AuditLog {
+    dir: PathBuf,
+    lines: UnboundedSender<String>,
+}
+
+/// Owns the current file on the writer thread.
+struct Writer {
+    dir: PathBuf,
+    max_file_bytes: u64,
+    daily: bool,
+    max_files: Option<usize>,
+    max_age: Option<Duration>,
+    file: Option<OpenFile>,
+}
+
+struct OpenFile {
+    file: BufWriter<File>,
+    bytes: u64,
+    /// days since the epoch the file was started on
+    day: u64,
+}
+
+impl AuditLog {
+    /// `state_dir` holds the log unless `config` names another directory.
+    pub fn new(config: AuditConfig, state_dir: &Path) -> Result<Self, AppErrors> {
+        let dir = config.dir.unwrap_or_else(|| state_dir.join("audit"));
+        std::fs::create_dir_all(&dir).map_err(|e| {
+            format!(
+                "failed to create the audit directory {}: {e}",
+                dir.display()
+            )
+        })?;
+        info!("auditing statements to {}", dir.display());
+        let writer = Writer {
+            dir: dir.clone(),
+            max_file_bytes: config.max_file_bytes,
+            daily: config.daily,
+            max_files: config.max_files,
+            max_age: config
+                .max_age_days
+                .map(|days| Duration::from_millis(days * MS_PER_DAY)),
+            file: None,
+        };
+        let (lines, queued) = unbounded_channel();
+        std::thread::Builder::new()
+            .name("audit-writer".to_string())
+            .spawn(move || writer.run(queued))
+            .map_err(|e| format!("failed to start the audit writer: {e}"))?;
+        Ok(Self { dir, lines })
+    }
+
+    /// Queues `record` to be appended, failures are logged as the statement already ran.
+    pub fn record(&self, record: &AuditRecord) {
+        let mut line = match serde_json::to_string(record) {
+            Ok(line) => line,
+            Err(e) => {
+                error!("failed to encode audit record: {e}");
+                return;
+            }
+        };
+        line.push('\n');
+        if self.lines.send(line).is_err() {
+            error!("the audit writer stopped, dropping an audit record");
+        }
+    }
+
+    /// The newest records matching `query`, blocking while reading the files line by line.
+    pub fn search(&self, query: &QueryAudit) -> Result<Vec<AuditEntry>, AppErrors> {
+        let limit = match query.limit {
+            0 => DEFAULT_SEARCH_LIMIT,
+            limit => limit as usize,
+        };
+        let search = query.search.to_lowercase();
+        let matches = |r: &AuditRecord| {
+            (query.user.is_empty() || r.user == query.user)
+                && (query.context.is_empty() || r.context == query.context)
+                && (query.outcome.is_empty() || r.outcome.name() == query.outcome)
+                && (query.from_ms == 0 || r.timestamp_ms >= query.from_ms)
+                && (query.to_ms == 0 || r.timestamp_ms <= query.to_ms)
+                && r.sql.to_lowercase().contains(&search)
+        };
+
+        let mut entries = vec![];
+        for (path, _) in files_newest_first(&self.dir)? {
+            let file = match File::open(&path) {
+                Ok(file) => file,
+                // rotated away since listing
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(format!("failed to read {}: {e}", path.display()).into()),
+            };
+            // files are oldest first, keep the last matches that may still be returned
+            let wanted = limit - entries.len();
+            let mut newest = VecDeque::with_capacity(wanted);
+            for line in BufReader::new(file).lines() {
+                let line = line.map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+                let Ok(record) = serde_json::from_str::<AuditRecord>(&line) else {
+                    continue;
+                };
+                if matches(&record) {
+                    if newest.len() == wanted {
+                        newest.pop_front();
+                    }
+                    newest.push_back(record);
+                }
+            }
+            entries.extend(newest.into_iter().rev().map(AuditEntry::from));
+            if entries.len() == limit {
+                break;
+            }
+        }
+        Ok(entries)
+    }
+}
+
+/// The log files of `dir` and when they were last written, the current one first.
+fn files_newest_first(dir: &Path) -> Result<Vec<(PathBuf, Option<SystemTime>)>, AppErrors> {
+    let entries =
+        std::fs::read_dir(dir).map_err(|e| format!("failed to list {}: {e}", dir.display()))?;
+    let mut files = entries
+        .flatten()
+        .filter(|e| {
+            let name = e.file_name().to_string_lossy().to_string();
+            name.starts_with("audit") && name.ends_with(".jsonl")
+        })
+        .map(|e| {
+            let modified = e.metadata().and_then(|m| m.modified()).ok();
+            (e.path(), modified)
+        })
+        .collect::<Vec<_>>();
+    files.sort_by(|a, b| b.1.cmp(&a.1));
+    Ok(files)
+}
+
+impl Writer {
+    /// Appends the queued lines until the log is dropped, flushing whenever it caught up so
+    /// searches find the newest records.
+    fn run(mut self, mut lines: UnboundedReceiver<String>) {
+        loop {
+            let line = match lines.try_recv() {
+                Ok(line) => line,
+                Err(TryRecvError::Empty) => {
+                    self.flush();
+                    match lines.blocking_recv() {
+                        Some(line) => line,
+                        None => break,
+                    }
+                }
+                Err(TryRecvError::Disconnected) => break,
+            };
+            if let Err(e) = self.append(line.as_bytes()) {
+                error!("failed to write the audit log: {e}");
+            }
+        }
+        self.flush();
+    }
+
+    fn append(&mut self, line: &[u8]) -> Result<(), AppErrors> {
+        let today = now_ms() / MS_PER_DAY;
+        let mut open = match self.file.take() {
+            Some(open) => open,
+            None => self.open(today)?,
+        };
+        let full = open.bytes > 0 && open.bytes + line.len() as u64 > self.max_file_bytes;
+        if full || (self.daily && open.day != today) {
+            let day = open.day;
+            // the rotated file is complete once renamed
+            open.file
+                .flush()
+                .map_err(|e| format!("failed to flush {CURRENT_FILE}: {e}"))?;
+            drop(open);
+            self.rotate(day)?;
+            open = self.open(today)?;
+        }
+        // dropped on failure, the next record opens the file again
+        open.file
+            .write_all(line)
+            .map_err(|e| format!("failed to append to {CURRENT_FILE}: {e}"))?;
+        open.bytes += line.len() as u64;
+        self.file = Some(open);
+        Ok(())
+    }
+
+    fn flush(&mut self) {
+        let Some(open) = self.file.as_mut() else {
+            return;
+        };
+        if let Err(e) = open.file.flush() {
+            error!("failed to flush {CURRENT_FILE}: {e}");
+            self.file = None;
+        }
+    }
+
+    /// Opens the current file, one left by an earlier run is continued.
+    fn open(&self, today: u64) -> Result<OpenFile, AppErrors> {
+        let path = self.dir.join(CURRENT_FILE);
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(|e| format!("failed to open {}: {e}", path.display()))?;
+        let metadata = file
+            .metadata()
+            .map_err(|e| format!("failed to stat {}: {e}", path.display()))?;
+        let day = match metadata.len() {
+            0 => today,
+            _ => metadata
+                .created()
+                .or_else(|_| metadata.modified())
+                .ok()
+                .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+                .map(|d| d.as_millis() as u64 / MS_PER_DAY)
+                .unwrap_or(today),
+        };
+        Ok(OpenFile {
+            file: BufWriter::new(file),
+            bytes: metadata.len(),
+            day,
+        })
+    }
+
+    /// Renames the current file after `day`, numbered when rotated more than once that day.
+    fn rotate(&self, day: u64) -> Result<(), AppErrors> {
+        let date = utc_date(day);
+        let target = (0..)
+            .map(|n| match n {
+                0 => self.dir.join(format!("audit-{date}.jsonl")),
+                n => self.dir.join(format!("audit-{date}.{n}.jsonl")),
+            })
+            .find(|path| !path.exists())
+            .unwrap_or_default();
+        std::fs::rename(self.dir.join(CURRENT_FILE), &target)
+            .map_err(|e| format!("failed to rotate the audit log: {e}"))?;
+        info!("audit log rotated to {}", target.display());
+        self.prune();
+        Ok(())
+    }
+
+    /// Deletes the rotated files beyond `max_files` or older than `max_age`, failures are
+    /// logged and retried on the next rotation.
+    fn prune(&self) {
+        if self.max_files.is_none() && self.max_age.is_none() {
+            return;
+        }
+        let rotated = match files_newest_first(&self.dir) {
+            Ok(files) => files
+                .into_iter()
+                .filter(|(path, _)| !path.ends_with(CURRENT_FILE)),
+            Err(e) => {
+                error!("failed to prune the audit log: {e}");
+                return;
+            }
+        };
+        let now = SystemTime::now();
+        for (n, (path, modified)) in rotated.enumerate() {
+            let too_many = self.max_files.is_some_and(|max| n >= max);
+            let too_old = match (self.max_age, modified) {
+                (Some(max_age), Some(modified)) => {
+                    now.duration_since(modified).unwrap_or_default() > max_age
+                }
+                _ => false,
+            };
+            if !too_many && !too_old {
+                continue;
+            }
+            match std::fs::remove_file(&path) {
+                Ok(()) => info!("deleted the old audit log {}", path.display()),
+                Err(e) => error!("failed to delete the old audit log {}: {e}", path.display()),
+            }
+        }
+    }
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+/// `YYYY-MM-DD` of `days` since the epoch, in the proleptic Gregorian calendar.
+fn utc_date(days: u64) -> String {
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn utc_date_of_days_since_epoch() {
+        assert_eq!(utc_date(0), "1970-01-01");
+        assert_eq!(utc_date(59), "1970-03-01");
+        // 2000 is a leap year, 1900 and 2100 are not
+        assert_eq!(utc_date(11_016), "2000-02-29");
+        assert_eq!(utc_date(11_017), "2000-03-01");
+        assert_eq!(utc_date(19_782), "2024-02-29");
+        assert_eq!(utc_date(47_540), "2100-02-28");
+        assert_eq!(utc_date(47_541), "2100-03-01");
+        assert_eq!(utc_date(20_089), "2025-01-01");
+    }
+}
@@ -0,0 +1,381 @@
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::Argon2;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine as _;
+use futures_util::stream::{SplitSink, SplitStream};
+use futures_util::{SinkExt, StreamExt};
+use hmac::{Hmac, Mac};
+use parking_lot::Mutex;
+use prost::Message as _;
+use rand::rngs::OsRng;
+use rand::RngCore;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+use tokio_tungstenite::{tungstenite::Message, WebSocketStream};
+use tracing::{info, warn};
+
+use proto_gen::auth::{auth_service::Service, AuthService, Login, LoginResult};
+
+use crate::access::AccessControl;
+use crate::errors::AppErrors;
+use crate::server::http_header;
+use crate::sessions::Role;
+use crate::tls::ClientStream;
+
+/// A new connection must log in within this time.
+const LOGIN_TIMEOUT: Duration = Duration::from_secs(10);
+const DEFAULT_SESSION_TTL_SECS: u64 = 12 * 3600;
+/// Wrong users and wrong passwords are not told apart.
+const BAD_CREDENTIALS: &str = "unknown user or wrong password";
+const BAD_TOKEN: &str = "invalid or expired token";
+/// Failed logins from one address before it has to wait.
+const MAX_FAILED_LOGINS: u32 = 5;
+/// How long an address that failed too often has to wait, and how long its failures count.
+const LOGIN_BACKOFF: Duration = Duration::from_secs(60);
+
+type HmacSha256 = Hmac<Sha256>;
+type WsSink = SplitSink<WebSocketStream<ClientStream>, Message>;
+type WsSource = SplitStream<WebSocketStream<ClientStream>>;
+
+/// The `auth` section of the config file.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AuthConfig {
+    /// key signing session tokens, a random one on every start ends the sessions on restart
+    #[serde(default)]
+    secret: Option<String>,
+    #[serde(default = "default_session_ttl_secs")]
+    session_ttl_secs: u64,
+    /// static tokens for scripts and scrapers
+    #[serde(default)]
+    tokens: Vec<ApiToken>,
+    #[serde(default)]
+    users: Vec<User>,
+}
+
+fn default_session_ttl_secs() -> u64 {
+    DEFAULT_SESSION_TTL_SECS
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ApiToken {
+    name: String,
+    token: String,
+    role: Role,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct User {
+    name: String,
+    /// argon2 hash in the PHC format, as printed by `server hash-password`
+    password_hash: String,
+    role: Role,
+}
+
+/// Who a session or an HTTP request acts for.
+#[derive(Clone, Debug)]
+pub struct Identity {
+    pub user: String,
+    pub role: Role,
+}
+
+/// Checks credentials against the config and issues signed session tokens.
+pub struct Authenticator {
+    secret: Vec<u8>,
+    session_ttl: Duration,
+    tokens: Vec<ApiToken>,
+    users: Vec<User>,
+    /// verified for unknown users, so they take as long to reject as wrong passwords
+    dummy_hash: String,
+    failures: Mutex<HashMap<IpAddr, Failures>>,
+}
+
+/// Failed logins of an address since `since`.
+struct Failures {
+    count: u32,
+    since: Instant,
+}
+
+impl Authenticator {
+    pub fn new(config: AuthConfig) -> Result<Self, AppErrors> {
+        for user in config.users.iter() {
+            PasswordHash::new(&user.password_hash)
+                .map_err(|e| format!("invalid password hash of user {}: {e}", user.name))?;
+        }
+        let secret = match config.secret {
+            Some(secret) => secret.into_bytes(),
+            None => {
+                warn!("no auth secret configured, session tokens end with the server");
+                let mut secret = vec![0; 32];
+                OsRng.fill_bytes(&mut secret);
+                secret
+            }
+        };
+        info!(
+            "authentication required, {} users and {} API tokens configured",
+            config.users.len(),
+            config.tokens.len()
+        );
+        Ok(Self {
+            secret,
+            session_ttl: Duration::from_secs(config.session_ttl_secs),
+            tokens: config.tokens,
+            users: config.users,
+            dummy_hash: hash_password("")?,
+            failures: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Checks the credentials `peer` logs in with, refusing addresses that failed too often
+    /// lately without checking them.
+    pub async fn login(&self, login: &Login, peer: IpAddr) -> Result<Identity, AppErrors> {
+        if self.throttled(peer) {
+            return Err("too many failed logins, try again later".into());
+        }
+        let result = if login.token.is_empty() {
+            self.verify_password(login).await
+        } else {
+            self.verify_token(&login.token)
+        };
+        if result.is_ok() {
+            self.failures.lock().remove(&peer);
+        } else {
+            self.failed(peer);
+        }
+        result
+    }
+
+    async fn verify_password(&self, login: &Login) -> Result<Identity, AppErrors> {
+        let user = self.users.iter().find(|u| u.name == login.user);
+        let phc = user.map_or(&self.dummy_hash, |u| &u.password_hash).clone();
+        let password = login.password.clone();
+        // argon2 is slow on purpose, it must not hold up the other sessions of the worker
+        let verified = tokio::task::spawn_blocking(move || {
+            let hash = PasswordHash::new(&phc).map_err(|e| e.to_string())?;
+            let verified = Argon2::default()
+                .verify_password(password.as_bytes(), &hash)
+                .is_ok();
+            Ok::<_, String>(verified)
+        })
+        .await
+        .map_err(|e| format!("password check failed: {e}"))??;
+        match user {
+            Some(user) if verified => Ok(Identity {
+                user: user.name.clone(),
+                role: user.role,
+            }),
+            _ => Err(BAD_CREDENTIALS.into()),
+        }
+    }
+
+    fn throttled(&self, peer: IpAddr) -> bool {
+        self.failures.lock().get(&peer).is_some_and(|failures| {
+            failures.count >= MAX_FAILED_LOGINS && failures.since.elapsed() < LOGIN_BACKOFF
+        })
+    }
+
+    /// Counts a failed login of `peer`, forgetting the failures that no longer count.
+    fn failed(&self, peer: IpAddr) {
+        let mut failures = self.failures.lock();
+        failures.retain(|_, failures| failures.since.elapsed() < LOGIN_BACKOFF);
+        let failures = failures.entry(peer).or_insert_with(|| Failures {
+            count: 0,
+            since: Instant::now(),
+        });
+        failures.count += 1;
+    }
+
+    /// Checks an API token or a session token issued by [`Self::issue`].
+    pub fn verify_token(&self, token: &str) -> Result<Identity, AppErrors> {
+        // digests take the same time to compare wherever the tokens differ
+        let digest = Sha256::digest(token.as_bytes());
+        if let Some(api) = self
+            .tokens
+            .iter()
+            .find(|t| Sha256::digest(t.token.as_bytes()) == digest)
+        {
+            return Ok(Identity {
+                user: api.name.clone(),
+                role: api.role,
+            });
+        }
+
+        let (payload, signature) = token.split_once('.').ok_or(BAD_TOKEN)?;
+        let payload = URL_SAFE_NO_PAD.decode(payload).map_err(|_| BAD_TOKEN)?;
+        let signature = URL_SAFE_NO_PAD.decode(signature).map_err(|_| BAD_TOKEN)?;
+        self.mac()
+            .chain_update(&payload)
+            .verify_slice(&signature)
+            .map_err(|_| BAD_TOKEN)?;
+        // only this server signs tokens, the payload is as written by `issue`
+        let payload = String::from_utf8(payload).map_err(|_| BAD_TOKEN)?;
+        let mut parts = payload.splitn(3, ':');
+        let (Some(expires_at_ms), Some(role), Some(user)) =
+            (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(BAD_TOKEN.into());
+        };
+        if expires_at_ms.parse::<u64>().map_err(|_| BAD_TOKEN)? <= now_ms() {
+            return Err("session expired, log in again".into());
+        }
+        Ok(Identity {
+            user: user.to_string(),
+            role: Role::from_name(role).ok_or(BAD_TOKEN)?,
+        })
+    }
+
+    /// A session token for `identity` and when it expires.
+    pub fn issue(&self, identity: &Identity) -> (String, u64) {
+        let expires_at_ms = now_ms() + self.session_ttl.as_millis() as u64;
+        let payload = format!("{expires_at_ms}:{}:{}", identity.role.name(), identity.user);
+        let signature = self
+            .mac()
+            .chain_update(payload.as_bytes())
+            .finalize()
+            .into_bytes();
+        let token = format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(payload),
+            URL_SAFE_NO_PAD.encode(signature)
+        );
+        (token, expires_at_ms)
+    }
+
+    fn mac(&self) -> HmacSha256 {
+        HmacSha256::new_from_slice(&self.secret).expect("HMAC takes keys of any length")
+    }
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+/// The PHC string to put in the config for `password`.
+pub fn hash_password(password: &str) -> Result<String, AppErrors> {
+    let salt = SaltString::generate(&mut OsRng);
+    let hash = Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map_err(|e| format!("failed to hash password: {e}"))?;
+    Ok(hash.to_string())
+}
+
+/// Checks the bearer token among the `headers` of an HTTP request, any request passes without
+/// authentication.
+pub fn authorize_http(auth: Option<&Authenticator>, headers: &[String]) -> Result<(), AppErrors> {
+    let Some(auth) = auth else {
+        return Ok(());
+    };
+    let token = http_header(headers, "authorization")
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .ok_or("missing bearer token")?;
+    auth.verify_token(token.trim()).map(|_| ())
+}
+
+/// Reads the Login that must open a connection and answers it. Connections that don't log in
+/// in time or with invalid credentials are closed as a policy violation and get no identity.
+pub(crate) async fn authenticate(
+    auth: &Authenticator,
+    access: &AccessControl,
+    sink: &mut WsSink,
+    source: &mut WsSource,
+    client_addr: SocketAddr,
+) -> Result<Option<Identity>, AppErrors> {
+    let login = match tokio::time::timeout(LOGIN_TIMEOUT, source.next()).await {
+        Ok(Some(Ok(msg))) if msg.is_binary() => {
+            match AuthService::decode(msg.into_data().as_slice()) {
+                Ok(AuthService {
+                    service: Some(Service::LoginMsg(login)),
+                }) => Some(login),
+                _ => None,
+            }
+        }
+        _ => None,
+    };
+    let Some(login) = login else {
+        warn!("{client_addr} did not log in, closing");
+        close(sink, "authentication required").await?;
+        return Ok(None);
+    };
+
+    let identity = match auth.login(&login, client_addr.ip()).await {
+        Ok(identity) => identity,
+        Err(e) => {
+            warn!("{client_addr} failed to log in: {e}");
+            let result = LoginResult {
+                error: e.to_string(),
+                ..Default::default()
+            };
+            send_result(sink, result).await?;
+            close(sink, "invalid credentials").await?;
+            return Ok(None);
+        }
+    };
+    info!(
+        "{client_addr} logged in as {} ({})",
+        identity.user,
+        identity.role.name()
+    );
+    let (session_token, expires_at_ms) = auth.issue(&identity);
+    let result = LoginResult {
+        session_token,
+        expires_at_ms,
+        ..identity_result(&identity, access)
+    };
+    send_result(sink, result).await?;
+    Ok(Some(identity))
+}
+
+async fn send_result(sink: &mut WsSink, result: LoginResult) -> Result<(), AppErrors> {
+    let msg = AuthService {
+        service: Some(Service::LoginResultMsg(result)),
+    }
+    .encode_to_vec();
+    sink.send(Message::binary(msg))
+        .await
+        .map_err(|e| format!("failed to send login result: {e}"))?;
+    Ok(())
+}
+
+async fn close(sink: &mut WsSink, reason: &str) -> Result<(), AppErrors> {
+    let frame = CloseFrame {
+        code: CloseCode::Policy,
+        reason: reason.to_string().into(),
+    };
+    sink.send(Message::Close(Some(frame)))
+        .await
+        .map_err(|e| format!("failed to close connection: {e}"))?;
+    Ok(())
+}
+
+/// Who `identity` is and what it may do, without a session token.
+fn identity_result(identity: &Identity, access: &AccessControl) -> LoginResult {
+    let (permissions, contexts) = access.describe(identity.role);
+    LoginResult {
+        ok: true,
+        user: identity.user.clone(),
+        role: identity.role.name().to_string(),
+        permissions,
+        contexts: contexts.into_iter().collect(),
+        ..Default::default()
+    }
+}
+
+/// Tells a session who it is, unasked when the server requires no login and as the answer to
+/// a Login sent after the connection was opened, which keeps the identity it has.
+pub(crate) fn identity_message(identity: &Identity, access: &AccessControl) -> Vec<u8> {
+    AuthService {
+        service: Some(Service::LoginResultMsg(identity_result(identity, access))),
+    }
+    .encode_to_vec()
+}
@@ -0,0 +1,445 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use datafusion::logical_expr::{DdlStatement, LogicalPlan};
+use datafusion::prelude::SessionContext;
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc::{unbounded_channel, Sender, UnboundedReceiver, UnboundedSender};
+use tokio::sync::watch;
+use tracing::{error, info, warn};
+
+use proto_gen::query::{query_service::Service, CatalogIssue, CatalogStatus};
+
+use crate::context::context_name;
+use crate::errors::AppErrors;
+use crate::query::reply;
+use crate::state::ServerState;
+use crate::tables::TableSpec;
+
+/// A definition made by DDL, restored by running its statement again, or a table registered
+/// by a client.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum CatalogEntry {
+    Schema {
+        name: String,
+        sql: String,
+    },
+    ExternalTable {
+        name: String,
+        location: String,
+        file_type: String,
+        partition_cols: Vec<String>,
+        options: BTreeMap<String, String>,
+        sql: String,
+    },
+    View {
+        name: String,
+        sql: String,
+    },
+    ListingTable(TableSpec),
+}
+
+impl CatalogEntry {
+    /// The definition `plan` creates, `sql` being its statement.
+    fn of(plan: &DdlStatement, sql: &str) -> Option<Self> {
+        let sql = sql.trim().to_string();
+        let entry = match plan {
+            DdlStatement::CreateCatalogSchema(schema) => CatalogEntry::Schema {
+                name: schema.schema_name.clone(),
+                sql,
+            },
+            DdlStatement::CreateExternalTable(table) => CatalogEntry::ExternalTable {
+                name: table.name.to_string(),
+                location: table.location.clone(),
+                file_type: table.file_type.clone(),
+                partition_cols: table.table_partition_cols.clone(),
+                options: table.options.clone().into_iter().collect(),
+                sql,
+            },
+            DdlStatement::CreateView(view) => CatalogEntry::View {
+                name: view.name.to_string(),
+                sql,
+            },
+            _ => return None,
+        };
+        Some(entry)
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            CatalogEntry::Schema { .. } => "schema",
+            CatalogEntry::ExternalTable { .. } => "external table",
+            CatalogEntry::View { .. } => "view",
+            CatalogEntry::ListingTable(_) => "table",
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            CatalogEntry::Schema { name, .. }
+            | CatalogEntry::ExternalTable { name, .. }
+            | CatalogEntry::View { name, .. } => name,
+            CatalogEntry::ListingTable(spec) => &spec.name,
+        }
+    }
+
+    fn is_table(&self) -> bool {
+        matches!(
+            self,
+            CatalogEntry::ExternalTable { .. } | CatalogEntry::ListingTable(_)
+        )
+    }
+
+    /// Tables share their names whichever way they were made.
+    fn is_same(&self, other: &Self) -> bool {
+        let same_kind = self.kind() == other.kind() || (self.is_table() && other.is_table());
+        same_kind && self.name() == other.name()
+    }
+}
+
+type Contexts = BTreeMap<String, Vec<CatalogEntry>>;
+
+/// The definitions clients made in each context, in the order they were made, written through
+/// to a file of the state directory by a thread of its own.
+pub struct CatalogStore {
+    contexts: RwLock<Contexts>,
+    /// snapshots to write, in the order they were made
+    snapshots: UnboundedSender<Contexts>,
+    /// what sessions are told, the definitions that failed to restore among it
+    status: watch::Sender<CatalogStatus>,
+}
+
+impl CatalogStore {
+    /// Loads the definitions saved in `path`, starting empty if it doesn't exist yet. Fails
+    /// if the writer thread can't be started.
+    pub fn load(path: PathBuf) -> Result<Self, AppErrors> {
+        let mut contexts = match std::fs::read(&path) {
+            Ok(bytes) => match serde_json::from_slice::<Contexts>(&bytes) {
+                Ok(contexts) => contexts,
+                Err(e) => {
+                    error!("ignoring corrupt catalog in {path:?}: {e}");
+                    Contexts::new()
+                }
+            },
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Contexts::new(),
+            Err(e) => {
+                error!("failed to read the catalog from {path:?}: {e}");
+                Contexts::new()
+            }
+        };
+        // earlier runs kept every context a statement ran in
+        contexts.retain(|_, entries| !entries.is_empty());
+        info!(
+            "loaded {} catalog definitions of {} contexts from {path:?}",
+            contexts.values().map(Vec::len).sum::<usize>(),
+            contexts.len()
+        );
+        let (status, _) = watch::channel(CatalogStatus {
+            contexts: contexts.keys().cloned().collect(),
+            issues: vec![],
+        });
+        let (snapshots, queued) = unbounded_channel();
+        std::thread::Builder::new()
+            .name("catalog-writer".to_string())
+            .spawn(move || write_snapshots(&path, queued))
+            .map_err(|e| format!("failed to start the catalog writer: {e}"))?;
+        Ok(Self {
+            contexts: RwLock::new(contexts),
+            snapshots,
+            status,
+        })
+    }
+
+    pub fn subscribe(&self) -> watch::Receiver<CatalogStatus> {
+        self.status.subscribe()
+    }
+
+    /// Keeps track of the definition `plan` made or dropped in `context`, once it executed.
+    /// Contexts are kept while they hold definitions, there is nothing to restore of others.
+    pub fn record(&self, context: &str, sql: &str, plan: &LogicalPlan) {
+        let LogicalPlan::Ddl(ddl) = plan else {
+            return;
+        };
+        let context = context_name(context);
+        let mut contexts = self.contexts.write();
+        let entry = CatalogEntry::of(ddl, sql);
+        if entry.is_none() && !contexts.contains_key(context) {
+            // nothing was defined in the context to drop
+            return;
+        }
+        let entries = contexts.entry(context.to_string()).or_default();
+        let forgotten = match ddl {
+            DdlStatement::DropTable(table) => {
+                let name = table.name.to_string();
+                forget(entries, |e| e.is_table() && e.name() == name)
+            }
+            DdlStatement::DropView(view) => {
+                let name = view.name.to_string();
+                forget(entries, |e| {
+                    matches!(e, CatalogEntry::View { .. }) && e.name() == name
+                })
+            }
+            DdlStatement::DropCatalogSchema(schema) => {
+                let name = schema.name.schema_name().to_string();
+                let prefix = format!("{name}.");
+                forget(entries, |e| {
+                    (matches!(e, CatalogEntry::Schema { .. }) && e.name() == name)
+                        || e.name().starts_with(&prefix)
+                })
+            }
+            _ => {
+                let Some(entry) = entry else {
+                    return;
+                };
+                let exists = entries.iter().any(|e| e.is_same(&entry));
+                let failed = self
+                    .status
+                    .borrow()
+                    .issues
+                    .iter()
+                    .any(|issue| issue.context == context && issue.name == entry.name());
+                if exists && !failed && if_not_exists(ddl) {
+                    // the earlier definition is still in effect
+                    return;
+                }
+                let replaced = forget(entries, |e| e.is_same(&entry));
+                entries.push(entry);
+                replaced
+            }
+        };
+        if entries.is_empty() {
+            contexts.remove(context);
+        }
+        self.updated(context, &contexts, &forgotten);
+    }
+
+    /// Keeps track of a table registered in `context`, replacing one of the same name.
+    pub fn record_table(&self, context: &str, spec: TableSpec) {
+        let context = context_name(context);
+        let mut contexts = self.contexts.write();
+        let entries = contexts.entry(context.to_string()).or_default();
+        let entry = CatalogEntry::ListingTable(spec);
+        let replaced = forget(entries, |e| e.is_same(&entry));
+        entries.push(entry);
+        self.updated(context, &contexts, &replaced);
+    }
+
+    /// Queues `contexts` to be persisted and tells the sessions, restore failures of the
+    /// definitions replaced or dropped no longer matter.
+    fn updated(&self, context: &str, contexts: &Contexts, forgotten: &[CatalogEntry]) {
+        // logged only, the statement already took effect
+        if self.snapshots.send(contexts.clone()).is_err() {
+            error!("the catalog writer stopped, the catalog is not persisted");
+        }
+        self.status.send_modify(|status| {
+            status.contexts = contexts.keys().cloned().collect();
+            status.issues.retain(|issue| {
+                issue.context != context || !forgotten.iter().any(|e| e.name() == issue.name)
+            });
+        });
+    }
+}
+
+/// Writes the queued snapshots to `path` until the store is dropped, only the newest of those
+/// queued meanwhile.
+fn write_snapshots(path: &Path, mut snapshots: UnboundedReceiver<Contexts>) {
+    while let Some(mut contexts) = snapshots.blocking_recv() {
+        while let Ok(newer) = snapshots.try_recv() {
+            contexts = newer;
+        }
+        if let Err(e) = write(path, &contexts) {
+            error!("failed to persist the catalog: {e}");
+        }
+    }
+}
+
+fn write(path: &Path, contexts: &Contexts) -> Result<(), AppErrors> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("failed to create state directory {dir:?}: {e}"))?;
+    }
+    let json = serde_json::to_vec_pretty(contexts)
+        .map_err(|e| format!("failed to encode the catalog: {e}"))?;
+    // write aside and rename, a crash mid-write must not lose the previous file
+    let tmp = path.with_extension("tmp");
+    std::fs::write(&tmp, json)
+        .map_err(|e| format!("failed to write the catalog to {tmp:?}: {e}"))?;
+    std::fs::rename(&tmp, path)
+        .map_err(|e| format!("failed to replace the catalog in {path:?}: {e}"))?;
+    Ok(())
+}
+
+/// Removes the entries matching `f`, returning them.
+fn forget(entries: &mut Vec<CatalogEntry>, f: impl Fn(&CatalogEntry) -> bool) -> Vec<CatalogEntry> {
+    let (forgotten, kept) = std::mem::take(entries).into_iter().partition(f);
+    *entries = kept;
+    forgotten
+}
+
+fn if_not_exists(ddl: &DdlStatement) -> bool {
+    match ddl {
+        DdlStatement::CreateCatalogSchema(schema) => schema.if_not_exists,
+        DdlStatement::CreateExternalTable(table) => table.if_not_exists,
+        _ => false,
+    }
+}
+
+/// Creates the contexts of earlier runs and their definitions again, then marks the server
+/// ready. Definitions that fail, e.g. as their files are gone, are reported to the clients
+/// and kept for the next start, `DROP ... IF EXISTS` forgets them.
+pub async fn restore_catalog(state: Arc<ServerState>) {
+    let contexts = state.catalog.contexts.read().clone();
+    let mut restored = 0;
+    let mut issues = vec![];
+    for (context, entries) in contexts {
+        let ctx = state.contexts.get_or_create(&context);
+        for entry in entries {
+            match restore(&state, &ctx, &entry).await {
+                Ok(()) => restored += 1,
+                Err(e) => {
+                    warn!(
+                        "failed to restore {} {} in context {context}: {e}",
+                        entry.kind(),
+                        entry.name()
+                    );
+                    issues.push(CatalogIssue {
+                        context: context.clone(),
+                        kind: entry.kind().to_string(),
+                        name: entry.name().to_string(),
+                        error: e.to_string(),
+                    });
+                }
+            }
+        }
+    }
+    info!(
+        "restored {restored} catalog definitions, {} failed",
+        issues.len()
+    );
+    state
+        .catalog
+        .status
+        .send_modify(|status| status.issues = issues);
+    state.ready.store(true, Ordering::Relaxed);
+}
+
+/// Files are checked against the data roots again, they may have changed since.
+async fn restore(
+    state: &ServerState,
+    ctx: &SessionContext,
+    entry: &CatalogEntry,
+) -> Result<(), AppErrors> {
+    let sql = match entry {
+        CatalogEntry::Schema { sql, .. }
+        | CatalogEntry::ExternalTable { sql, .. }
+        | CatalogEntry::View { sql, .. } => sql,
+        CatalogEntry::ListingTable(spec) => {
+            state.data_roots.check_location(&spec.location)?;
+            return spec.register(ctx).await;
+        }
+    };
+    let plan = ctx
+        .state()
+        .create_logical_plan(sql)
+        .await
+        .map_err(|e| e.to_string())?;
+    state.data_roots.check_plan(&plan)?;
+    ctx.execute_logical_plan(plan)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Tells the session the contexts known and the definitions that failed to restore, now and
+/// whenever that changes.
+pub(crate) async fn catalog_feed(mut status: watch::Receiver<CatalogStatus>, tx: Sender<Vec<u8>>) {
+    loop {
+        let msg = status.borrow_and_update().clone();
+        if reply(&tx, Service::CatalogMsg(msg)).await.is_err() {
+            return;
+        }
+        tokio::select! {
+            changed = status.changed() => {
+                if changed.is_err() {
+                    return;
+                }
+            }
+            _ = tx.closed() => return,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn view(name: &str) -> CatalogEntry {
+        CatalogEntry::View {
+            name: name.to_string(),
+            sql: format!("CREATE VIEW {name} AS SELECT 1"),
+        }
+    }
+
+    fn external_table(name: &str) -> CatalogEntry {
+        CatalogEntry::ExternalTable {
+            name: name.to_string(),
+            location: "/data/t.csv".to_string(),
+            file_type: "CSV".to_string(),
+            partition_cols: vec![],
+            options: BTreeMap::new(),
+            sql: format!("CREATE EXTERNAL TABLE {name} STORED AS CSV LOCATION '/data/t.csv'"),
+        }
+    }
+
+    fn listing_table(name: &str) -> CatalogEntry {
+        CatalogEntry::ListingTable(TableSpec {
+            name: name.to_string(),
+            location: "/data/t".to_string(),
+            file_type: "parquet".to_string(),
+            partition_columns: vec![],
+            file_extension: String::new(),
+            sort_order: vec![],
+            has_header: false,
+        })
+    }
+
+    #[test]
+    fn tables_are_the_same_whichever_way_they_were_made() {
+        assert!(external_table("t").is_same(&listing_table("t")));
+        assert!(listing_table("t").is_same(&external_table("t")));
+        assert!(view("v").is_same(&view("v")));
+        assert!(!external_table("t").is_same(&listing_table("u")));
+        // a view doesn't replace a table of the same name
+        assert!(!view("t").is_same(&external_table("t")));
+        let schema = CatalogEntry::Schema {
+            name: "t".to_string(),
+            sql: "CREATE SCHEMA t".to_string(),
+        };
+        assert!(!schema.is_same(&listing_table("t")));
+    }
+
+    #[test]
+    fn forget_returns_the_entries_removed_and_keeps_the_order() {
+        let mut entries = vec![
+            view("a"),
+            external_table("b"),
+            view("c"),
+            listing_table("d"),
+        ];
+        let forgotten = forget(&mut entries, |e| matches!(e, CatalogEntry::View { .. }));
+        let names = |entries: &[CatalogEntry]| {
+            entries
+                .iter()
+                .map(|e| e.name().to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(&forgotten), ["a", "c"]);
+        assert_eq!(names(&entries), ["b", "d"]);
+        assert!(forget(&mut entries, |e| e.name() == "x").is_empty());
+        assert_eq!(entries.len(), 2);
+    }
+}
@@ -0,0 +1,281 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use datafusion::prelude::SessionContext;
+use datafusion::sql::sqlparser::dialect::GenericDialect;
+use datafusion::sql::sqlparser::keywords::{Keyword, ALL_KEYWORDS};
+use datafusion::sql::sqlparser::tokenizer::{Token, Tokenizer, Word};
+use tokio::sync::mpsc::Sender;
+use tracing::debug;
+
+use proto_gen::query::{
+    query_service::Service, Candidate, CandidateKind, CompleteSql, Completions,
+};
+
+use crate::access::Permission;
+use crate::errors::AppErrors;
+use crate::query::reply;
+use crate::sessions::Role;
+use crate::state::ServerState;
+
+const MAX_CANDIDATES: usize = 200;
+
+/// Keywords after which a table name is expected.
+const TABLE_KEYWORDS: &[Keyword] = &[
+    Keyword::FROM,
+    Keyword::JOIN,
+    Keyword::INTO,
+    Keyword::TABLE,
+    Keyword::UPDATE,
+    Keyword::DESCRIBE,
+];
+
+/// What the cursor is placed on, derived from the tokens before it.
+struct CursorContext {
+    prefix: String,
+    /// `qualifier` in `qualifier.prefix`
+    qualifier: Option<String>,
+    expects_table: bool,
+}
+
+/// Replies with the candidates at the cursor of `req`, none if `role` may not read the context.
+/// A context that wasn't created yet offers the built-in vocabulary only.
+pub(crate) async fn complete_sql(
+    state: Arc<ServerState>,
+    tx: Sender<Vec<u8>>,
+    role: Role,
+    req: CompleteSql,
+) -> Result<(), AppErrors> {
+    let ctx = state.contexts.get(&req.context).unwrap_or_default();
+    let statement = match state.access.require(role, &req.context, Permission::Read) {
+        Ok(()) => statement_around(&req.sql, req.cursor as usize),
+        Err(_) => None,
+    };
+    let (prefix, candidates) = match statement {
+        Some((statement, cursor)) => {
+            let cursor_context = cursor_context(&statement[..cursor]);
+            let candidates = candidates(&ctx, statement, &cursor_context).await;
+            (cursor_context.prefix, candidates)
+        }
+        None => (String::new(), vec![]),
+    };
+    debug!(
+        "request {}: {} candidate(s) for {prefix:?}",
+        req.request_id,
+        candidates.len()
+    );
+
+    reply(
+        &tx,
+        Service::CompletionsMsg(Completions {
+            request_id: req.request_id,
+            prefix,
+            candidates,
+        }),
+    )
+    .await
+}
+
+/// The statement containing `cursor` and the cursor offset inside of it.
+/// `None` if the text can't be tokenized, e.g. the cursor is inside an unterminated string.
+fn statement_around(sql: &str, cursor: usize) -> Option<(&str, usize)> {
+    let mut cursor = cursor.min(sql.len());
+    while !sql.is_char_boundary(cursor) {
+        cursor -= 1;
+    }
+    // cheap split, good enough to keep unrelated statements out of the table scope
+    let start = sql[..cursor].rfind(';').map(|i| i + 1).unwrap_or(0);
+    let end = sql[cursor..]
+        .find(';')
+        .map(|i| cursor + i)
+        .unwrap_or(sql.len());
+    Tokenizer::new(&GenericDialect {}, &sql[start..cursor])
+        .tokenize()
+        .ok()?;
+    Some((&sql[start..end], cursor - start))
+}
+
+fn significant_tokens(sql: &str) -> Vec<Token> {
+    Tokenizer::new(&GenericDialect {}, sql)
+        .tokenize()
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|t| !matches!(t, Token::Whitespace(_)))
+        .collect()
+}
+
+fn cursor_context(before_cursor: &str) -> CursorContext {
+    let mut tokens = significant_tokens(before_cursor);
+    let ends_with_word = before_cursor
+        .chars()
+        .last()
+        .is_some_and(|c| c.is_alphanumeric() || c == '_');
+
+    let prefix = match tokens.last() {
+        Some(Token::Word(w)) if ends_with_word => {
+            let prefix = w.value.clone();
+            tokens.pop();
+            prefix
+        }
+        _ => String::new(),
+    };
+
+    let qualifier = match tokens.as_slice() {
+        [.., Token::Word(w), Token::Period] => Some(w.value.clone()),
+        _ => None,
+    };
+    let expects_table = qualifier.is_none()
+        && matches!(
+            tokens.last(),
+            Some(Token::Word(Word { keyword, .. })) if TABLE_KEYWORDS.contains(keyword)
+        );
+
+    CursorContext {
+        prefix,
+        qualifier,
+        expects_table,
+    }
+}
+
+/// Tables referenced by the statement, keyed by the name they are visible as (alias or table name).
+fn tables_in_scope(statement: &str) -> HashMap<String, String> {
+    let tokens = significant_tokens(statement);
+    let mut scope = HashMap::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        let after_table_keyword =
+            matches!(&tokens[i], Token::Word(w) if TABLE_KEYWORDS.contains(&w.keyword));
+        i += 1;
+        if !after_table_keyword {
+            continue;
+        }
+
+        // dotted table name: catalog.schema.table
+        let mut parts = vec![];
+        while let Some(Token::Word(w)) = tokens.get(i) {
+            parts.push(w.value.clone());
+            i += 1;
+            if tokens.get(i) != Some(&Token::Period) {
+                break;
+            }
+            i += 1;
+        }
+        let Some(table) = parts.last().cloned() else {
+            continue;
+        };
+        let name = parts.join(".");
+
+        if matches!(&tokens.get(i), Some(Token::Word(w)) if w.keyword == Keyword::AS) {
+            i += 1;
+        }
+        match tokens.get(i) {
+            Some(Token::Word(w)) if w.keyword == Keyword::NoKeyword => {
+                scope.insert(w.value.clone(), name.clone());
+                i += 1;
+            }
+            _ => {}
+        }
+        scope.insert(table, name);
+    }
+    scope
+}
+
+async fn columns_of(ctx: &SessionContext, table: &str) -> Vec<Candidate> {
+    let Ok(provider) = ctx.table_provider(table).await else {
+        return vec![];
+    };
+    provider
+        .schema()
+        .fields()
+        .iter()
+        .map(|f| candidate(f.name(), CandidateKind::Column, f.data_type().to_string()))
+        .collect()
+}
+
+fn tables(ctx: &SessionContext) -> Vec<Candidate> {
+    let mut tables = vec![];
+    for catalog_name in ctx.catalog_names() {
+        let Some(catalog) = ctx.catalog(&catalog_name) else {
+            continue;
+        };
+        for schema_name in catalog.schema_names() {
+            let Some(schema) = catalog.schema(&schema_name) else {
+                continue;
+            };
+            for table in schema.table_names() {
+                tables.push(candidate(
+                    &table,
+                    CandidateKind::Table,
+                    format!("{catalog_name}.{schema_name}"),
+                ));
+            }
+        }
+    }
+    tables
+}
+
+fn functions(ctx: &SessionContext) -> Vec<Candidate> {
+    let state = ctx.state();
+    let scalar = state.scalar_functions().keys().map(|n| (n, "scalar"));
+    let aggregate = state.aggregate_functions().keys().map(|n| (n, "aggregate"));
+    let window = state.window_functions().keys().map(|n| (n, "window"));
+    scalar
+        .chain(aggregate)
+        .chain(window)
+        .map(|(name, detail)| candidate(name, CandidateKind::Function, detail.to_string()))
+        .collect()
+}
+
+fn keywords() -> Vec<Candidate> {
+    ALL_KEYWORDS
+        .iter()
+        .map(|k| candidate(k, CandidateKind::Keyword, String::new()))
+        .collect()
+}
+
+async fn candidates(
+    ctx: &SessionContext,
+    statement: &str,
+    cursor_context: &CursorContext,
+) -> Vec<Candidate> {
+    let scope = tables_in_scope(statement);
+    let mut candidates = vec![];
+    if let Some(qualifier) = &cursor_context.qualifier {
+        let table = scope.get(qualifier).unwrap_or(qualifier);
+        candidates.extend(columns_of(ctx, table).await);
+    } else if cursor_context.expects_table {
+        candidates.extend(tables(ctx));
+    } else {
+        for table in scope.values() {
+            candidates.extend(columns_of(ctx, table).await);
+        }
+        candidates.extend(functions(ctx));
+        candidates.extend(tables(ctx));
+        candidates.extend(keywords());
+    }
+
+    let prefix = cursor_context.prefix.to_lowercase();
+    candidates.retain(|c| c.text.to_lowercase().starts_with(&prefix));
+    candidates.sort_by_key(|c| (rank(c.kind()), c.text.to_lowercase()));
+    candidates.dedup_by(|a, b| a.kind == b.kind && a.text == b.text);
+    candidates.truncate(MAX_CANDIDATES);
+    candidates
+}
+
+/// Things defined by the user come before the built-in vocabulary.
+fn rank(kind: CandidateKind) -> u8 {
+    match kind {
+        CandidateKind::Column => 0,
+        CandidateKind::Table => 1,
+        CandidateKind::Function => 2,
+        CandidateKind::Keyword | CandidateKind::Unspecified => 3,
+    }
+}
+
+fn candidate(text: &str, kind: CandidateKind, detail: String) -> Candidate {
+    Candidate {
+        text: text.to_string(),
+        kind: kind as i32,
+        detail,
+    }
+}
@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use datafusion::config::ConfigOptions;
+use datafusion::prelude::SessionContext;
+use parking_lot::RwLock;
+use prost::Message as _;
+use tokio::sync::mpsc::Sender;
+use tracing::{info, warn};
+
+use proto_gen::config::{config_service::Service, Config, ConfigAck, ConfigService, SetConfig};
+
+use crate::access::Permission;
+use crate::errors::AppErrors;
+use crate::sessions::Role;
+
+/// Monitor pushes are sent this often unless the client asks otherwise.
+const DEFAULT_MONITOR_INTERVAL: Duration = Duration::from_secs(1);
+const MIN_MONITOR_INTERVAL: Duration = Duration::from_millis(100);
+const MAX_MONITOR_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Settings of one client connection, shared with the tasks serving it.
+pub type SharedClientConfig = Arc<RwLock<ClientConfig>>;
+
+pub struct ClientConfig {
+    pub pause_monitor: bool,
+    pub monitor_interval: Duration,
+    /// DataFusion options overriding those of the context
+    options: HashMap<String, String>,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        Self {
+            pause_monitor: false,
+            monitor_interval: DEFAULT_MONITOR_INTERVAL,
+            options: HashMap::new(),
+        }
+    }
+}
+
+impl ClientConfig {
+    /// Replaces the config with `config`, unless one of its options is invalid.
+    pub fn apply(&mut self, config: Config) -> Result<(), AppErrors> {
+        let mut options = ConfigOptions::new();
+        for (key, value) in config.options.iter() {
+            options
+                .set(key, value)
+                .map_err(|e| format!("invalid option {key}={value}: {e}"))?;
+        }
+        self.pause_monitor = config.pause_monitor;
+        self.monitor_interval = match config.monitor_interval_ms {
+            0 => DEFAULT_MONITOR_INTERVAL,
+            ms => {
+                Duration::from_millis(ms as u64).clamp(MIN_MONITOR_INTERVAL, MAX_MONITOR_INTERVAL)
+            }
+        };
+        self.options = config.options;
+        Ok(())
+    }
+
+    pub fn to_proto(&self) -> Config {
+        Config {
+            pause_monitor: self.pause_monitor,
+            monitor_interval_ms: self.monitor_interval.as_millis() as u32,
+            options: self.options.clone(),
+        }
+    }
+
+    /// `ctx` with the options of this connection, sharing its catalogs.
+    pub fn session_context(&self, ctx: SessionContext) -> SessionContext {
+        if self.options.is_empty() {
+            return ctx;
+        }
+        let mut state = ctx.state();
+        let options = state.config_mut().options_mut();
+        for (key, value) in self.options.iter() {
+            // checked when applied, only fails if the context disagrees
+            if let Err(e) = options.set(key, value) {
+                warn!("ignoring option {key}={value}: {e}");
+            }
+        }
+        SessionContext::new_with_state(state)
+    }
+}
+
+/// Applies the config of `req` to the connection, before any later request of the client is
+/// served, and returns the acknowledgement carrying the config in effect. DataFusion options
+/// apply in every context, so setting any needs the ddl permission of `role` itself.
+pub fn set_config(config: &RwLock<ClientConfig>, role: Role, req: SetConfig) -> ConfigAck {
+    let SetConfig {
+        request_id,
+        config: new,
+    } = req;
+    let new = new.unwrap_or_default();
+    let mut config = config.write();
+    let may_set = new.options.is_empty() || role.default_permissions().contains(&Permission::Ddl);
+    let result = if may_set {
+        config.apply(new)
+    } else {
+        Err(format!("role {} may not set DataFusion options", role.name()).into())
+    };
+    if result.is_ok() {
+        info!("client config applied: {:?}", config.to_proto());
+    }
+    ConfigAck {
+        request_id,
+        config: Some(config.to_proto()),
+        error: result.err().map(|e| e.to_string()).unwrap_or_default(),
+    }
+}
+
+pub(crate) async fn acknowledge(tx: Sender<Vec<u8>>, ack: ConfigAck) -> Result<(), AppErrors> {
+    let msg = ConfigService {
+        service: Some(Service::ConfigAckMsg(ack)),
+    }
+    .encode_to_vec();
+    tx.send(msg)
+        .await
+        .map_err(|e| format!("failed to queue reply, session closed: {e}"))?;
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use datafusion::execution::runtime_env::{RuntimeConfig, RuntimeEnv};
+use datafusion::prelude::{SessionConfig, SessionContext};
+use parking_lot::RwLock;
+use tracing::{info, warn};
+
+use crate::tables::TableLayouts;
+
+pub const DEFAULT_CONTEXT: &str = "Default";
+
+/// Named DataFusion contexts, shared by every connected client.
+#[derive(Default)]
+pub struct ContextRegistry {
+    contexts: RwLock<HashMap<String, SessionContext>>,
+    /// bytes the memory pool of each context may hand out, unbounded if unset
+    memory_limit: Option<usize>,
+}
+
+impl ContextRegistry {
+    pub fn with_memory_limit(memory_limit: Option<usize>) -> Self {
+        Self {
+            contexts: Default::default(),
+            memory_limit,
+        }
+    }
+
+    pub fn memory_limit(&self) -> Option<usize> {
+        self.memory_limit
+    }
+
+    /// Returns the context called `name`, creating it on first use.
+    /// An empty name refers to the default context.
+    pub fn get_or_create(&self, name: &str) -> SessionContext {
+        let name = context_name(name);
+        if let Some(ctx) = self.contexts.read().get(name) {
+            return ctx.clone();
+        }
+
+        self.contexts
+            .write()
+            .entry(name.to_string())
+            .or_insert_with(|| {
+                info!("creating context: {name}");
+                let config = SessionConfig::new()
+                    .with_information_schema(true)
+                    .with_extension(Arc::new(TableLayouts::default()));
+                let Some(limit) = self.memory_limit else {
+                    return SessionContext::new_with_config(config);
+                };
+                match RuntimeEnv::new(RuntimeConfig::new().with_memory_limit(limit, 1.0)) {
+                    Ok(runtime) => SessionContext::new_with_config_rt(config, Arc::new(runtime)),
+                    Err(e) => {
+                        warn!("failed to limit the memory of context {name}: {e}");
+                        SessionContext::new_with_config(config)
+                    }
+                }
+            })
+            .clone()
+    }
+
+    /// The context called `name` if a client created it.
+    pub fn get(&self, name: &str) -> Option<SessionContext> {
+        self.contexts.read().get(context_name(name)).cloned()
+    }
+
+    pub fn names(&self) -> Vec<String> {
+        self.contexts.read().keys().cloned().collect()
+    }
+
+    /// Bytes reserved in the memory pool of each context, by name.
+    pub fn memory_reserved(&self) -> Vec<(String, usize)> {
+        let mut reserved = self
+            .contexts
+            .read()
+            .iter()
+            .map(|(name, ctx)| (name.clone(), ctx.runtime_env().memory_pool.reserved()))
+            .collect::<Vec<_>>();
+        reserved.sort();
+        reserved
+    }
+}
+
+/// Clients leave the context empty to refer to the default one.
+pub fn context_name(name: &str) -> &str {
+    if name.is_empty() {
+        DEFAULT_CONTEXT
+    } else {
+        name
+    }
+}
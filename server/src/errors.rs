@@ -1,10 +1,39 @@
 use std::backtrace::Backtrace;
+use std::fmt::Display;
 
-use tracing::error;
+use tracing::{error, warn};
+
+use proto_gen::query::Limit;
 
 #[derive(Debug)]
 pub enum AppErrors {
     CommonError(String, Backtrace),
+    /// a quota or server limit refused a query
+    LimitExceeded(Limit, String),
+}
+
+impl AppErrors {
+    pub fn limit_exceeded(limit: Limit, msg: String) -> Self {
+        warn!("limit err: {msg}");
+        AppErrors::LimitExceeded(limit, msg)
+    }
+
+    /// The limit that was reached, [`Limit::Unspecified`] for other errors.
+    pub fn limit(&self) -> Limit {
+        match self {
+            AppErrors::CommonError(..) => Limit::Unspecified,
+            AppErrors::LimitExceeded(limit, _) => *limit,
+        }
+    }
+}
+
+impl Display for AppErrors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AppErrors::CommonError(msg, _) => write!(f, "{msg}"),
+            AppErrors::LimitExceeded(_, msg) => write!(f, "{msg}"),
+        }
+    }
 }
 
 impl From<&str> for AppErrors {
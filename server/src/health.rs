@@ -0,0 +1,113 @@
+use std::sync::atomic::Ordering;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::Serialize;
+
+use crate::state::ServerState;
+
+/// A context reserving this share of its memory limit degrades the server.
+const MEMORY_PRESSURE: f64 = 0.9;
+/// Resources are sampled every second, older samples mean the runtime is starved.
+const STALE_SAMPLE_MS: u64 = 5000;
+
+#[derive(Serialize)]
+struct HealthReport {
+    /// `ok` or `degraded`
+    status: &'static str,
+    checks: Vec<Check>,
+}
+
+#[derive(Serialize)]
+struct Check {
+    name: &'static str,
+    ok: bool,
+    detail: String,
+}
+
+#[derive(Serialize)]
+struct ReadinessReport {
+    ready: bool,
+}
+
+/// Status line and JSON body of `/healthz`. A degraded server still answers with 200, it keeps
+/// serving and restarting it would drop every session.
+pub fn health(state: &ServerState) -> (&'static str, String) {
+    let checks = vec![memory_check(state), runtime_check(state)];
+    let report = HealthReport {
+        status: if checks.iter().all(|c| c.ok) {
+            "ok"
+        } else {
+            "degraded"
+        },
+        checks,
+    };
+    ("200 OK", to_json(&report))
+}
+
+/// Status line and JSON body of `/readyz`, 503 until startup finished.
+pub fn readiness(state: &ServerState) -> (&'static str, String) {
+    let ready = state.ready.load(Ordering::Relaxed);
+    let status = if ready {
+        "200 OK"
+    } else {
+        "503 Service Unavailable"
+    };
+    (status, to_json(&ReadinessReport { ready }))
+}
+
+fn memory_check(state: &ServerState) -> Check {
+    let Some(limit) = state.contexts.memory_limit() else {
+        return Check {
+            name: "memory_pool",
+            ok: true,
+            detail: "memory pools are unbounded".to_string(),
+        };
+    };
+    let pressured = state
+        .contexts
+        .memory_reserved()
+        .into_iter()
+        .filter(|(_, reserved)| *reserved as f64 >= limit as f64 * MEMORY_PRESSURE)
+        .map(|(name, reserved)| format!("{name} at {}%", reserved * 100 / limit))
+        .collect::<Vec<_>>();
+    Check {
+        name: "memory_pool",
+        ok: pressured.is_empty(),
+        detail: if pressured.is_empty() {
+            format!(
+                "every context below {}% of its limit",
+                MEMORY_PRESSURE * 100.0
+            )
+        } else {
+            format!("memory pool nearly exhausted: {}", pressured.join(", "))
+        },
+    }
+}
+
+fn runtime_check(state: &ServerState) -> Check {
+    let now_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default();
+    let Some(sample) = state.resources.latest() else {
+        return Check {
+            name: "runtime",
+            ok: true,
+            detail: "no resource sample yet".to_string(),
+        };
+    };
+    let age_ms = now_ms.saturating_sub(sample.time_ms);
+    Check {
+        name: "runtime",
+        ok: age_ms < STALE_SAMPLE_MS,
+        detail: format!(
+            "last resource sample {age_ms} ms ago, {} tasks queued",
+            sample.queued_tasks
+        ),
+    }
+}
+
+fn to_json(report: &impl Serialize) -> String {
+    // plain structs of strings and numbers always serialize
+    serde_json::to_string(report).unwrap_or_default()
+}
@@ -0,0 +1,90 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+use tokio::sync::mpsc::Sender;
+use tracing::debug;
+
+use proto_gen::query::{query_service::Service, GetHistory, History, HistoryEntry};
+
+use crate::access::Permission;
+use crate::context::context_name;
+use crate::errors::AppErrors;
+use crate::query::reply;
+use crate::sessions::Role;
+use crate::state::ServerState;
+
+/// Older entries of a context are dropped beyond this.
+const ENTRIES_PER_CONTEXT: usize = 1000;
+
+/// Executed statements of every context, newest last.
+#[derive(Default)]
+pub struct HistoryRegistry {
+    entries: RwLock<HashMap<String, VecDeque<HistoryEntry>>>,
+}
+
+impl HistoryRegistry {
+    pub fn record(&self, entry: HistoryEntry) {
+        let mut entries = self.entries.write();
+        let context = entries
+            .entry(context_name(&entry.context).to_string())
+            .or_default();
+        if context.len() == ENTRIES_PER_CONTEXT {
+            context.pop_front();
+        }
+        context.push_back(entry);
+    }
+
+    /// Newest first, at most `limit` entries whose sql contains `search`.
+    pub fn search(&self, context: &str, search: &str, limit: usize) -> Vec<HistoryEntry> {
+        let search = search.to_lowercase();
+        let entries = self.entries.read();
+        let Some(context) = entries.get(context_name(context)) else {
+            return vec![];
+        };
+        context
+            .iter()
+            .rev()
+            .filter(|e| e.sql.to_lowercase().contains(&search))
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+}
+
+pub(crate) async fn get_history(
+    state: Arc<ServerState>,
+    tx: Sender<Vec<u8>>,
+    role: Role,
+    req: GetHistory,
+) -> Result<(), AppErrors> {
+    if let Err(e) = state.access.require(role, &req.context, Permission::Read) {
+        let history = History {
+            request_id: req.request_id,
+            error: e.to_string(),
+            ..Default::default()
+        };
+        return reply(&tx, Service::HistoryMsg(history)).await;
+    }
+    let limit = match req.limit {
+        0 => ENTRIES_PER_CONTEXT,
+        limit => limit as usize,
+    };
+    let entries = state.history.search(&req.context, &req.search, limit);
+    debug!(
+        "request {}: {} history entries of context {:?}",
+        req.request_id,
+        entries.len(),
+        req.context
+    );
+
+    reply(
+        &tx,
+        Service::HistoryMsg(History {
+            request_id: req.request_id,
+            entries,
+            ..Default::default()
+        }),
+    )
+    .await
+}
@@ -0,0 +1,469 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+use serde::Deserialize;
+use tokio::sync::Notify;
+
+use proto_gen::query::{Limit, StatementLimits};
+
+use crate::context::context_name;
+use crate::errors::AppErrors;
+use crate::sessions::Role;
+
+/// `queries_per_minute` counts the queries started within this window.
+const RATE_WINDOW: Duration = Duration::from_secs(60);
+const DEFAULT_MAX_QUEUED: usize = 100;
+
+/// The `limits` section of the config file, nothing is limited by default.
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LimitsConfig {
+    /// queries running at once over all clients, more wait in the admission queue
+    max_running: Option<usize>,
+    /// queries waiting beyond this are refused, 100 by default
+    max_queued: Option<usize>,
+    per_user: Quota,
+    per_session: Quota,
+    /// by context name, capping every statement run there
+    contexts: BTreeMap<String, StatementCaps>,
+}
+
+#[derive(Clone, Copy, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Quota {
+    /// queries running or waiting to run at once
+    max_concurrent: Option<u32>,
+    queries_per_minute: Option<u32>,
+    /// encoded result rows a single query may send, its result is cut short there
+    max_result_bytes: Option<u64>,
+}
+
+/// Caps of a single statement, its result is cut short where one is reached.
+#[derive(Clone, Copy, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct StatementCaps {
+    timeout_ms: Option<u64>,
+    pub max_rows: Option<u64>,
+    /// encoded result rows streamed to the client
+    pub max_bytes: Option<u64>,
+}
+
+impl StatementCaps {
+    /// The caps a client asked for, 0 for none.
+    pub fn requested(limits: &StatementLimits) -> Self {
+        let cap = |value| (value > 0).then_some(value);
+        Self {
+            timeout_ms: cap(limits.timeout_ms),
+            max_rows: cap(limits.max_rows),
+            max_bytes: cap(limits.max_bytes),
+        }
+    }
+
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout_ms.map(Duration::from_millis)
+    }
+
+    /// The lower of both caps of every kind.
+    fn tightened(self, other: Self) -> Self {
+        fn lower(a: Option<u64>, b: Option<u64>) -> Option<u64> {
+            match (a, b) {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                (a, b) => a.or(b),
+            }
+        }
+        Self {
+            timeout_ms: lower(self.timeout_ms, other.timeout_ms),
+            max_rows: lower(self.max_rows, other.max_rows),
+            max_bytes: lower(self.max_bytes, other.max_bytes),
+        }
+    }
+}
+
+/// Who asks to run a query.
+pub struct Requester {
+    /// the user name, or the peer host without authentication
+    pub user: String,
+    pub session_id: u64,
+    /// queued queries of higher roles run first
+    pub role: Role,
+}
+
+/// Quotas of users and sessions, and the admission queue of the server.
+pub struct Limits {
+    config: LimitsConfig,
+    state: Mutex<AdmissionState>,
+    /// notified whenever a query stops running or leaves the queue
+    moved: Notify,
+}
+
+#[derive(Default)]
+struct AdmissionState {
+    running: usize,
+    next_ticket: u64,
+    /// higher roles first, then in arrival order
+    queue: BTreeSet<(Reverse<Role>, u64)>,
+    usage: HashMap<UsageKey, Usage>,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum UsageKey {
+    User(String),
+    Session(u64),
+}
+
+#[derive(Default)]
+struct Usage {
+    /// running or queued
+    active: u32,
+    /// within the rate window, oldest first
+    started: VecDeque<Instant>,
+}
+
+pub enum Admission<'a> {
+    Running(Permit<'a>),
+    Queued(Ticket<'a>),
+}
+
+impl Limits {
+    /// Fails on a limit of 0, which no query could ever get past.
+    pub fn new(config: LimitsConfig) -> Result<Self, AppErrors> {
+        let quotas = [
+            ("per_user", config.per_user),
+            ("per_session", config.per_session),
+        ];
+        let mut limits = vec![("max_running".to_string(), config.max_running)];
+        for (name, quota) in quotas {
+            limits.push((
+                format!("{name}.max_concurrent"),
+                quota.max_concurrent.map(|max| max as usize),
+            ));
+            limits.push((
+                format!("{name}.queries_per_minute"),
+                quota.queries_per_minute.map(|max| max as usize),
+            ));
+        }
+        if let Some((key, _)) = limits.iter().find(|(_, max)| *max == Some(0)) {
+            return Err(
+                format!("limits.{key} must be at least 1, leave it out for no limit").into(),
+            );
+        }
+        Ok(Self {
+            config,
+            state: Default::default(),
+            moved: Notify::new(),
+        })
+    }
+
+    /// Counts a query of `requester` against its quotas, it runs right away unless the
+    /// server runs as many queries as it may. Fails with the quota reached or if the queue
+    /// is full.
+    pub fn admit(&self, requester: &Requester) -> Result<Admission<'_>, AppErrors> {
+        let quotas = [
+            (
+                UsageKey::User(requester.user.clone()),
+                self.config.per_user,
+                format!("user {}", requester.user),
+            ),
+            (
+                UsageKey::Session(requester.session_id),
+                self.config.per_session,
+                format!("session {}", requester.session_id),
+            ),
+        ];
+        let now = Instant::now();
+        let mut state = self.state.lock();
+        state.usage.retain(|_, usage| {
+            while let Some(started) = usage.started.front() {
+                if now.duration_since(*started) < RATE_WINDOW {
+                    break;
+                }
+                usage.started.pop_front();
+            }
+            usage.active > 0 || !usage.started.is_empty()
+        });
+
+        for (key, quota, who) in quotas.iter() {
+            let Some(usage) = state.usage.get(key) else {
+                continue;
+            };
+            if let Some(max) = quota.max_concurrent {
+                if usage.active >= max {
+                    return Err(AppErrors::limit_exceeded(
+                        Limit::ConcurrentQueries,
+                        format!("the {who} already runs {max} queries, wait for one to finish"),
+                    ));
+                }
+            }
+            if let Some(max) = quota.queries_per_minute {
+                if usage.started.len() >= max as usize {
+                    return Err(AppErrors::limit_exceeded(
+                        Limit::QueryRate,
+                        format!("the {who} started {max} queries within a minute, try later"),
+                    ));
+                }
+            }
+        }
+
+        let must_wait = match self.config.max_running {
+            Some(max) => state.running >= max || !state.queue.is_empty(),
+            None => false,
+        };
+        let max_queued = self.config.max_queued.unwrap_or(DEFAULT_MAX_QUEUED);
+        if must_wait && state.queue.len() >= max_queued {
+            return Err(AppErrors::limit_exceeded(
+                Limit::QueueFull,
+                format!("{max_queued} queries are waiting to run already, try later"),
+            ));
+        }
+
+        let keys = quotas.map(|(key, _, _)| key);
+        for key in keys.iter() {
+            let usage = state.usage.entry(key.clone()).or_default();
+            usage.active += 1;
+            usage.started.push_back(now);
+        }
+        let usage = UsageGuard { limits: self, keys };
+        if !must_wait {
+            state.running += 1;
+            return Ok(Admission::Running(Permit {
+                limits: self,
+                _usage: usage,
+            }));
+        }
+        let entry = (Reverse(requester.role), state.next_ticket);
+        state.next_ticket += 1;
+        state.queue.insert(entry);
+        Ok(Admission::Queued(Ticket {
+            limits: self,
+            entry,
+            usage: Some(usage),
+        }))
+    }
+
+    /// The caps of a statement run in `context`: those `requested` by the client, lowered to
+    /// the ones of the context and the result quotas.
+    pub fn statement_caps(&self, context: &str, requested: StatementCaps) -> StatementCaps {
+        let quota = |quota: Quota| StatementCaps {
+            max_bytes: quota.max_result_bytes,
+            ..Default::default()
+        };
+        let context = self.config.contexts.get(context_name(context));
+        requested
+            .tightened(context.copied().unwrap_or_default())
+            .tightened(quota(self.config.per_user))
+            .tightened(quota(self.config.per_session))
+    }
+}
+
+/// Keeps a query counted while running.
+pub struct Permit<'a> {
+    limits: &'a Limits,
+    _usage: UsageGuard<'a>,
+}
+
+impl Drop for Permit<'_> {
+    fn drop(&mut self) {
+        self.limits.state.lock().running -= 1;
+        self.limits.moved.notify_waiters();
+    }
+}
+
+/// A query waiting in the admission queue, it leaves the queue when dropped.
+pub struct Ticket<'a> {
+    limits: &'a Limits,
+    entry: (Reverse<Role>, u64),
+    /// taken over by the permit once admitted
+    usage: Option<UsageGuard<'a>>,
+}
+
+impl<'a> Ticket<'a> {
+    /// 1 runs next.
+    pub fn position(&self) -> u32 {
+        self.limits.state.lock().queue.range(..=self.entry).count() as u32
+    }
+
+    /// Waits for the queue to move, returns the permit once it is the turn of this query.
+    pub async fn advance(&mut self) -> Option<Permit<'a>> {
+        let moved = self.limits.moved.notified();
+        tokio::pin!(moved);
+        // registered before checking, a query finishing in between still wakes us
+        moved.as_mut().enable();
+        if let Some(permit) = self.try_run() {
+            return Some(permit);
+        }
+        moved.await;
+        self.try_run()
+    }
+
+    fn try_run(&mut self) -> Option<Permit<'a>> {
+        let mut state = self.limits.state.lock();
+        let max_running = self.limits.config.max_running.unwrap_or(usize::MAX);
+        if state.running >= max_running || state.queue.first() != Some(&self.entry) {
+            return None;
+        }
+        let usage = self.usage.take()?;
+        state.queue.remove(&self.entry);
+        state.running += 1;
+        Some(Permit {
+            limits: self.limits,
+            _usage: usage,
+        })
+    }
+}
+
+impl Drop for Ticket<'_> {
+    fn drop(&mut self) {
+        if self.usage.is_some() {
+            self.limits.state.lock().queue.remove(&self.entry);
+            self.limits.moved.notify_waiters();
+        }
+    }
+}
+
+struct UsageGuard<'a> {
+    limits: &'a Limits,
+    keys: [UsageKey; 2],
+}
+
+impl Drop for UsageGuard<'_> {
+    fn drop(&mut self) {
+        let mut state = self.limits.state.lock();
+        for key in self.keys.iter() {
+            if let Some(usage) = state.usage.get_mut(key) {
+                usage.active -= 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn requester(user: &str, session_id: u64, role: Role) -> Requester {
+        Requester {
+            user: user.to_string(),
+            session_id,
+            role,
+        }
+    }
+
+    fn running(admission: Admission<'_>) -> Permit<'_> {
+        match admission {
+            Admission::Running(permit) => permit,
+            Admission::Queued(_) => panic!("queued instead of running"),
+        }
+    }
+
+    fn queued(admission: Admission<'_>) -> Ticket<'_> {
+        match admission {
+            Admission::Queued(ticket) => ticket,
+            Admission::Running(_) => panic!("running instead of queued"),
+        }
+    }
+
+    #[test]
+    fn higher_roles_run_first_then_in_arrival_order() {
+        let limits = Limits::new(LimitsConfig {
+            max_running: Some(1),
+            ..Default::default()
+        })
+        .unwrap();
+        let permit = running(limits.admit(&requester("a", 1, Role::Viewer)).unwrap());
+        let mut viewer = queued(limits.admit(&requester("b", 2, Role::Viewer)).unwrap());
+        let mut analyst = queued(limits.admit(&requester("c", 3, Role::Analyst)).unwrap());
+        let mut admin = queued(limits.admit(&requester("d", 4, Role::Admin)).unwrap());
+        let mut late = queued(limits.admit(&requester("e", 5, Role::Analyst)).unwrap());
+        assert_eq!(admin.position(), 1);
+        assert_eq!(analyst.position(), 2);
+        assert_eq!(late.position(), 3);
+        assert_eq!(viewer.position(), 4);
+
+        // nothing runs before the running query is done
+        assert!(admin.try_run().is_none());
+        drop(permit);
+        assert!(analyst.try_run().is_none());
+        let permit = admin.try_run().unwrap();
+        assert_eq!(analyst.position(), 1);
+
+        // a query leaving the queue moves the ones behind it
+        drop(analyst);
+        assert_eq!(late.position(), 1);
+        assert_eq!(viewer.position(), 2);
+        drop(permit);
+        assert!(viewer.try_run().is_none());
+        assert!(late.try_run().is_some());
+    }
+
+    #[test]
+    fn queries_queue_behind_waiting_ones() {
+        let limits = Limits::new(LimitsConfig {
+            max_running: Some(1),
+            ..Default::default()
+        })
+        .unwrap();
+        let first = running(limits.admit(&requester("a", 1, Role::Analyst)).unwrap());
+        let mut waiting = queued(limits.admit(&requester("b", 2, Role::Analyst)).unwrap());
+        drop(first);
+        // the slot is free, but goes to the query that waited for it
+        let newcomer = queued(limits.admit(&requester("c", 3, Role::Analyst)).unwrap());
+        assert_eq!(newcomer.position(), 2);
+        assert!(waiting.try_run().is_some());
+    }
+
+    #[test]
+    fn queries_beyond_the_queue_are_refused() {
+        let limits = Limits::new(LimitsConfig {
+            max_running: Some(1),
+            max_queued: Some(1),
+            ..Default::default()
+        })
+        .unwrap();
+        let _running = running(limits.admit(&requester("a", 1, Role::Analyst)).unwrap());
+        let _waiting = queued(limits.admit(&requester("b", 2, Role::Analyst)).unwrap());
+        let refused = limits.admit(&requester("c", 3, Role::Admin));
+        assert!(matches!(
+            refused,
+            Err(AppErrors::LimitExceeded(Limit::QueueFull, _))
+        ));
+    }
+
+    #[test]
+    fn quotas_refuse_before_queueing() {
+        let limits = Limits::new(LimitsConfig {
+            per_user: Quota {
+                max_concurrent: Some(1),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .unwrap();
+        let _running = running(limits.admit(&requester("a", 1, Role::Analyst)).unwrap());
+        let refused = limits.admit(&requester("a", 2, Role::Admin));
+        assert!(matches!(
+            refused,
+            Err(AppErrors::LimitExceeded(Limit::ConcurrentQueries, _))
+        ));
+        assert!(limits.admit(&requester("b", 3, Role::Analyst)).is_ok());
+    }
+
+    #[test]
+    fn limits_of_zero_are_rejected() {
+        let zero = |config: LimitsConfig| Limits::new(config).err().map(|e| e.to_string());
+        let error = zero(LimitsConfig {
+            max_running: Some(0),
+            ..Default::default()
+        });
+        assert!(error.is_some_and(|e| e.contains("limits.max_running")));
+        let error = zero(LimitsConfig {
+            per_session: Quota {
+                queries_per_minute: Some(0),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        assert!(error.is_some_and(|e| e.contains("limits.per_session.queries_per_minute")));
+    }
+}
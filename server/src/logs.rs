@@ -0,0 +1,333 @@
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use parking_lot::Mutex;
+use prost::Message as _;
+use tokio::sync::broadcast::{self, error::RecvError};
+use tokio::sync::mpsc::Sender;
+use tracing::field::Visit;
+use tracing::span::{Attributes, Id, Record};
+use tracing::{info, Event, Subscriber};
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::{reload, EnvFilter, Layer, Registry};
+
+use proto_gen::logs::{
+    log_service::Service, Field, Level, LogBatch, LogEvent, LogFilter, LogService, Span,
+    SubscribeLogs,
+};
+
+use crate::config::SharedClientConfig;
+use crate::errors::AppErrors;
+use crate::sessions::Session;
+use crate::state::ServerState;
+
+/// Events kept for subscribers that ask for a backlog.
+const BUFFERED_EVENTS: usize = 5000;
+/// Live events are collected for this long before they are sent as one batch.
+const BATCH_WINDOW: Duration = Duration::from_millis(200);
+/// Events a subscriber may fall behind by before it misses some.
+const LIVE_CAPACITY: usize = 1024;
+
+/// The most recent events recorded through `tracing`, fed by [`LogLayer`].
+pub struct LogBuffer {
+    /// the sequence number of the last event and the events, oldest first
+    events: Mutex<(u64, VecDeque<Arc<LogEvent>>)>,
+    live: broadcast::Sender<Arc<LogEvent>>,
+}
+
+impl Default for LogBuffer {
+    fn default() -> Self {
+        Self {
+            events: Mutex::new((0, VecDeque::with_capacity(BUFFERED_EVENTS))),
+            live: broadcast::channel(LIVE_CAPACITY).0,
+        }
+    }
+}
+
+impl LogBuffer {
+    // must not log, it runs inside the subscriber
+    fn push(&self, mut event: LogEvent) {
+        let mut events = self.events.lock();
+        let (last_seq, buffered) = &mut *events;
+        *last_seq += 1;
+        event.seq = *last_seq;
+        let event = Arc::new(event);
+        if buffered.len() == BUFFERED_EVENTS {
+            buffered.pop_front();
+        }
+        buffered.push_back(event.clone());
+        // sent while locked so subscribers see events in sequence order, having none is fine
+        let _ = self.live.send(event);
+    }
+
+    /// Subscribes to new events and returns the last `count` buffered ones matching `filter`,
+    /// oldest first, with the sequence number of the last buffered event.
+    fn subscribe(
+        &self,
+        filter: &LogFilter,
+        count: usize,
+    ) -> (broadcast::Receiver<Arc<LogEvent>>, Vec<LogEvent>, u64) {
+        let events = self.events.lock();
+        let (last_seq, buffered) = &*events;
+        let mut backlog = buffered
+            .iter()
+            .rev()
+            .filter(|e| matches(filter, e))
+            .take(count)
+            .map(|e| (**e).clone())
+            .collect::<Vec<_>>();
+        backlog.reverse();
+        (self.live.subscribe(), backlog, *last_seq)
+    }
+}
+
+fn matches(filter: &LogFilter, event: &LogEvent) -> bool {
+    event.level >= filter.min_level
+        && event.target.starts_with(&filter.target)
+        && filter.span_fields.iter().all(|wanted| {
+            event
+                .spans
+                .iter()
+                .flat_map(|span| span.fields.iter())
+                .any(|field| field == wanted)
+        })
+}
+
+/// The filter in front of every layer, replaceable while the server runs.
+pub struct LogFilterControl {
+    handle: reload::Handle<EnvFilter, Registry>,
+    directive: Mutex<String>,
+}
+
+impl LogFilterControl {
+    pub fn new(handle: reload::Handle<EnvFilter, Registry>, directive: String) -> Self {
+        Self {
+            handle,
+            directive: Mutex::new(directive),
+        }
+    }
+
+    pub fn directive(&self) -> String {
+        self.directive.lock().clone()
+    }
+
+    /// Replaces the filter with `directive`, in the syntax of `RUST_LOG`.
+    pub fn set(&self, directive: &str) -> Result<(), AppErrors> {
+        let filter = EnvFilter::try_new(directive)
+            .map_err(|e| format!("invalid log filter {directive:?}: {e}"))?;
+        self.handle
+            .reload(filter)
+            .map_err(|e| format!("failed to replace the log filter: {e}"))?;
+        *self.directive.lock() = directive.to_string();
+        info!("log filter set to {directive:?}");
+        Ok(())
+    }
+}
+
+/// Records every event into a [`LogBuffer`], with the fields of the spans it happened in.
+pub struct LogLayer {
+    buffer: Arc<LogBuffer>,
+}
+
+impl LogLayer {
+    pub fn new(buffer: Arc<LogBuffer>) -> Self {
+        Self { buffer }
+    }
+}
+
+/// Fields of a span as recorded so far, kept in its extensions.
+struct SpanFields(Vec<Field>);
+
+impl<S> Layer<S> for LogLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut visitor = FieldVisitor::default();
+        attrs.record(&mut visitor);
+        span.extensions_mut().insert(SpanFields(visitor.fields));
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut visitor = FieldVisitor::default();
+        values.record(&mut visitor);
+        let mut extensions = span.extensions_mut();
+        let Some(SpanFields(fields)) = extensions.get_mut::<SpanFields>() else {
+            return;
+        };
+        for field in visitor.fields {
+            match fields.iter_mut().find(|f| f.name == field.name) {
+                Some(recorded) => recorded.value = field.value,
+                None => fields.push(field),
+            }
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+        let spans = ctx
+            .event_scope(event)
+            .map(|scope| {
+                scope
+                    .from_root()
+                    .map(|span| Span {
+                        name: span.name().to_string(),
+                        fields: span
+                            .extensions()
+                            .get::<SpanFields>()
+                            .map(|SpanFields(fields)| fields.clone())
+                            .unwrap_or_default(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        let metadata = event.metadata();
+        let level = match *metadata.level() {
+            tracing::Level::TRACE => Level::Trace,
+            tracing::Level::DEBUG => Level::Debug,
+            tracing::Level::INFO => Level::Info,
+            tracing::Level::WARN => Level::Warn,
+            tracing::Level::ERROR => Level::Error,
+        };
+        self.buffer.push(LogEvent {
+            seq: 0,
+            time_ms: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_millis() as u64)
+                .unwrap_or_default(),
+            level: level.into(),
+            // records of the `log` crate carry their target in a field
+            target: visitor
+                .log_target
+                .unwrap_or_else(|| metadata.target().to_string()),
+            message: visitor.message,
+            fields: visitor.fields,
+            spans,
+        });
+    }
+}
+
+#[derive(Default)]
+struct FieldVisitor {
+    message: String,
+    log_target: Option<String>,
+    fields: Vec<Field>,
+}
+
+impl FieldVisitor {
+    fn record(&mut self, field: &tracing::field::Field, value: String) {
+        match field.name() {
+            "message" => self.message = value,
+            "log.target" => self.log_target = Some(value),
+            name if name.starts_with("log.") => {}
+            name => self.fields.push(Field {
+                name: name.to_string(),
+                value,
+            }),
+        }
+    }
+}
+
+impl Visit for FieldVisitor {
+    fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+        self.record(field, value.to_string());
+    }
+
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn Debug) {
+        self.record(field, format!("{value:?}"));
+    }
+}
+
+async fn reply(tx: &Sender<Vec<u8>>, batch: LogBatch) -> Result<(), AppErrors> {
+    let msg = LogService {
+        service: Some(Service::BatchMsg(batch)),
+    }
+    .encode_to_vec();
+    tx.send(msg)
+        .await
+        .map_err(|e| format!("failed to queue reply, session closed: {e}"))?;
+    Ok(())
+}
+
+/// Sends the requested backlog, then batches of new events matching the filter until the
+/// session goes away, the session aborts the task on unsubscribe. New events are dropped while
+/// the session paused the monitor.
+pub(crate) async fn stream_logs(
+    state: Arc<ServerState>,
+    tx: Sender<Vec<u8>>,
+    session: Arc<Session>,
+    config: SharedClientConfig,
+    req: SubscribeLogs,
+) -> Result<(), AppErrors> {
+    if let Err(e) = session.require_admin("streaming the server log") {
+        let batch = LogBatch {
+            error: e.to_string(),
+            ..Default::default()
+        };
+        return reply(&tx, batch).await;
+    }
+    let filter = req.filter.unwrap_or_default();
+    let (mut live, backlog, last_seq) = state
+        .logs
+        .subscribe(&filter, (req.backlog as usize).min(BUFFERED_EVENTS));
+    reply(
+        &tx,
+        LogBatch {
+            events: backlog,
+            ..Default::default()
+        },
+    )
+    .await?;
+
+    let mut dropped = 0;
+    loop {
+        let mut events = vec![];
+        match live.recv().await {
+            Ok(event) => events.push(event),
+            Err(RecvError::Lagged(n)) => dropped += n,
+            Err(RecvError::Closed) => return Ok(()),
+        }
+        let window = tokio::time::sleep(BATCH_WINDOW);
+        tokio::pin!(window);
+        loop {
+            tokio::select! {
+                _ = &mut window => break,
+                received = live.recv() => match received {
+                    Ok(event) => events.push(event),
+                    Err(RecvError::Lagged(n)) => dropped += n,
+                    Err(RecvError::Closed) => break,
+                },
+            }
+        }
+
+        if config.read().pause_monitor {
+            continue;
+        }
+        let events = events
+            .into_iter()
+            // the backlog may already hold the first ones
+            .filter(|e| e.seq > last_seq && matches(&filter, e))
+            .map(|e| (*e).clone())
+            .collect::<Vec<_>>();
+        if events.is_empty() && dropped == 0 {
+            continue;
+        }
+        let batch = LogBatch {
+            events,
+            dropped,
+            ..Default::default()
+        };
+        reply(&tx, batch).await?;
+        dropped = 0;
+    }
+}
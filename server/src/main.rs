@@ -1,5 +1,6 @@
 use std::path::PathBuf;
 use std::process::ExitCode;
+use std::sync::Arc;
 
 // use datafusion::execution::{
 //     memory_pool::{FairSpillPool, GreedyMemoryPool},
@@ -8,23 +9,60 @@ use std::process::ExitCode;
 use regex;
 use tokio::{
     io::{AsyncBufReadExt, BufReader},
-    net::TcpListener,
+    net::{TcpListener, TcpStream},
 };
-use tokio_tungstenite::accept_async;
-use tracing::{debug, error, info};
+use tokio_tungstenite::accept_hdr_async;
+use tokio_tungstenite::tungstenite::handshake::server::{Request, Response};
+use tracing::{debug, error, info, warn};
+use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{reload, EnvFilter};
 
 use assets::{GeneratedAssets, StaticAssets};
+use auth::{authorize_http, hash_password};
+use catalog::restore_catalog;
 use errors::AppErrors;
-use server::{hello_service_handler, http_serve_file};
+use health::{health, readiness};
+use logs::{LogBuffer, LogFilterControl, LogLayer};
+use metrics::{render_metrics, METRICS_CONTENT_TYPE};
+use origin::OriginPolicy;
+use resources::sample_resources;
+use server::{http_header, http_respond, http_serve_file, session_handler};
+use server_config::load_config;
+use state::{state_dir, ServerState};
+use tls::{reload_on_hangup, ClientStream, TlsTerminator};
 
+mod access;
+mod admin;
 mod assets;
+mod audit;
+mod auth;
+mod catalog;
+mod completion;
 mod config;
+mod context;
 mod errors;
+mod health;
+mod history;
+mod limits;
+mod logs;
 mod messages;
+mod metrics;
+mod monitor;
+mod origin;
+mod query;
+mod resources;
+mod sandbox;
+mod saved;
 mod server;
+mod server_config;
+mod sessions;
+mod state;
+mod tables;
+mod tls;
+mod validation;
 
-pub async fn serve() -> Result<(), AppErrors> {
+pub async fn serve(logs: Arc<LogBuffer>, log_filter: LogFilterControl) -> Result<(), AppErrors> {
     let bind = "0.0.0.0:8081";
     let bind_socket = TcpListener::bind(bind)
         .await
@@ -37,94 +75,263 @@ pub async fn serve() -> Result<(), AppErrors> {
         debug!("serving static asset: {r}");
     }
 
-    info!("starting console/dashboard server on ws://{bind}");
-    'outer: while let Ok((mut stream, _)) = bind_socket.accept().await {
-        let Ok(peer_addr) = stream.peer_addr() else {
-            error!("failed to get peer_addr");
-            continue
-        };
+    let mut config = load_config()?;
+    let tls = config
+        .tls
+        .take()
+        .map(TlsTerminator::new)
+        .transpose()?
+        .map(Arc::new);
+    if let Some(tls) = tls.as_ref() {
+        tokio::spawn(reload_on_hangup(tls.clone()));
+    }
+    let origins = Arc::new(OriginPolicy::new(
+        std::mem::take(&mut config.allowed_origins),
+        tls.is_some(),
+    ));
+    let state = Arc::new(ServerState::load(state_dir(), logs, log_filter, config)?);
+    tokio::spawn(sample_resources(state.clone()));
+    // ready once the catalog is restored, the listener accepts meanwhile
+    tokio::spawn(restore_catalog(state.clone()));
+    let scheme = if tls.is_some() { "wss" } else { "ws" };
+    info!("starting console/dashboard server on {scheme}://{bind}");
+    while let Ok((stream, _)) = bind_socket.accept().await {
+        // a slow handshake or request must not hold up the next connection
+        tokio::spawn(handle_connection(
+            stream,
+            tls.clone(),
+            origins.clone(),
+            state.clone(),
+        ));
+    }
+    Ok(())
+}
+
+/// Serves one accepted connection: a websocket session or a single HTTP request.
+async fn handle_connection(
+    stream: TcpStream,
+    tls: Option<Arc<TlsTerminator>>,
+    origins: Arc<OriginPolicy>,
+    state: Arc<ServerState>,
+) {
+    let Ok(peer_addr) = stream.peer_addr() else {
+        error!("failed to get peer_addr");
+        return;
+    };
 
-        info!("client connected from: {peer_addr}");
-        let mut peek_buf: [u8; 512] = [0; 512];
-        let Ok(peek_size) = stream
-            .peek(&mut peek_buf)
-            .await else {
-            error!("failed to peek"); 
-            continue;
+    info!("client connected from: {peer_addr}");
+    let mut stream = match tls.as_ref() {
+        Some(tls) => match tls.accept(stream).await {
+            Ok(stream) => stream,
+            Err(e) => {
+                error!("{peer_addr}: {e}");
+                return;
+            }
+        },
+        None => ClientStream::plain(stream),
+    };
+    let mut peek_buf: [u8; 512] = [0; 512];
+    let Ok(peek_size) = stream.peek(&mut peek_buf).await else {
+        error!("failed to peek");
+        return;
+    };
+    let peek = String::from_utf8_lossy(&peek_buf[..peek_size]);
+    if peek.contains("Upgrade: websocket") {
+        info!("ws client connected, proceed to handshake");
+        let check = |req: &Request, resp: Response| origins.check_handshake(peer_addr, req, resp);
+        let Ok(ws) = accept_hdr_async(stream, check).await else {
+            error!("failed to handshake");
+            return;
         };
-        let peek = String::from_utf8_lossy(&peek_buf[..peek_size]);
-        if peek.contains("Upgrade: websocket") {
-            info!("ws client connected, proceed to handshake");
-            let Ok(ws) = accept_async(stream).await else {
-                error!("failed to handshake");
-                continue;
+        if let Err(e) = session_handler(ws, peer_addr, state).await {
+            error!("session of {peer_addr} failed: {e:?}");
+        }
+    } else {
+        info!("http client connected, proceed to serve static files");
+        let buf_reader = BufReader::new(&mut stream);
+        let mut req_lines = buf_reader.lines();
+        let mut lines = vec![];
+        loop {
+            let Ok(line) = req_lines.next_line().await else {
+                error!("failed to read next req line");
+                return;
             };
-            tokio::spawn(hello_service_handler(ws, peer_addr.clone()));
-        } else {
-            info!("http client connected, proceed to serve static files");
-            let buf_reader = BufReader::new(&mut stream);
-            let mut req_lines = buf_reader.lines();
-            let mut lines = vec![];
-            loop {
-                let Ok(line) = req_lines.next_line().await else {
-                    error!("failed to read next req line");
-                    continue 'outer;
-                };
-                if let Some(line) = line {
-                    if line.is_empty() {
-                        break;
-                    }
-                    lines.push(line)
-                } else {
+            if let Some(line) = line {
+                if line.is_empty() {
                     break;
                 }
+                lines.push(line)
+            } else {
+                break;
             }
+        }
 
-            let Some(method) = lines.first() else {
-                error!("failed to read req method: request is empty");
-                continue;
-            };
+        let Some(method) = lines.first() else {
+            error!("failed to read req method: request is empty");
+            return;
+        };
 
-            // serve static files
-            let path_regex = regex::Regex::new(r"GET (.*?) HTTP").expect("failed to compile regex");
-            let Some(path) = path_regex
-                .captures(&method)
-                .map(|m| m.get(1).unwrap().as_str()) else {
-                error!("no request method and path found, request: {lines:?}");
-                continue
-            };
-            let path_buf = PathBuf::from(path);
-            debug!("peer: {peer_addr} requested: GET {path}");
-            if path_buf == PathBuf::from("/") {
-                if let Err(e) = http_serve_file("index.html", &mut stream).await {
+        // serve static files
+        let path_regex =
+            regex::Regex::new(r"(GET|OPTIONS) (.*?) HTTP").expect("failed to compile regex");
+        let Some((verb, path)) = path_regex
+            .captures(&method)
+            .map(|m| (m.get(1).unwrap().as_str(), m.get(2).unwrap().as_str()))
+        else {
+            error!("no request method and path found, request: {lines:?}");
+            return;
+        };
+        let path_buf = PathBuf::from(path);
+        debug!("peer: {peer_addr} requested: {verb} {path}");
+
+        // pages of other origins may only call the API if allowed, and read what it returns
+        let api = matches!(path, "/metrics" | "/healthz" | "/readyz");
+        let origin = http_header(&lines, "origin");
+        let mut cors = vec![];
+        if api {
+            if !origins.allows(origin, http_header(&lines, "host")) {
+                warn!("rejected {verb} {path} from {peer_addr}: origin {origin:?} is not allowed");
+                let body = b"origin not allowed";
+                if let Err(e) =
+                    http_respond(&mut stream, "403 Forbidden", "text/plain", &[], body).await
+                {
                     error!("{e:?}");
-                    continue;
                 };
+                return;
+            }
+            cors = origins.cors_headers(origin);
+        }
+        if verb == "OPTIONS" {
+            // preflight of a request with the bearer token of /metrics
+            let (status, headers) = if api {
+                cors.push(("Access-Control-Allow-Methods", "GET".to_string()));
+                cors.push(("Access-Control-Allow-Headers", "Authorization".to_string()));
+                ("204 No Content", cors)
             } else {
-                let file_path = path_buf
-                    .strip_prefix("/")
-                    .unwrap_or(path_buf.as_path())
-                    .to_str()
-                    .unwrap_or("index.html");
-                if let Err(e) = http_serve_file(file_path, &mut stream).await {
+                ("405 Method Not Allowed", vec![("Allow", "GET".to_string())])
+            };
+            if let Err(e) = http_respond(&mut stream, status, "text/plain", &headers, b"").await {
+                error!("{e:?}");
+            };
+            return;
+        }
+
+        if path_buf == PathBuf::from("/metrics") {
+            if let Err(e) = authorize_http(state.auth.as_ref(), &lines) {
+                info!("refused metrics to {peer_addr}: {e}");
+                let body = e.to_string();
+                if let Err(e) = http_respond(
+                    &mut stream,
+                    "401 Unauthorized",
+                    "text/plain",
+                    &cors,
+                    body.as_bytes(),
+                )
+                .await
+                {
                     error!("{e:?}");
-                    continue;
                 };
+                return;
             }
-            info!("finished serving, disconnecting client {peer_addr}");
+            let body = render_metrics(&state);
+            if let Err(e) = http_respond(
+                &mut stream,
+                "200 OK",
+                METRICS_CONTENT_TYPE,
+                &cors,
+                body.as_bytes(),
+            )
+            .await
+            {
+                error!("{e:?}");
+                return;
+            };
+        } else if path == "/healthz" || path == "/readyz" {
+            let (status, body) = if path == "/healthz" {
+                health(&state)
+            } else {
+                readiness(&state)
+            };
+            if let Err(e) = http_respond(
+                &mut stream,
+                status,
+                "application/json",
+                &cors,
+                body.as_bytes(),
+            )
+            .await
+            {
+                error!("{e:?}");
+                return;
+            };
+        } else if path_buf == PathBuf::from("/") {
+            if let Err(e) = http_serve_file("index.html", &mut stream).await {
+                error!("{e:?}");
+                return;
+            };
+        } else {
+            let file_path = path_buf
+                .strip_prefix("/")
+                .unwrap_or(path_buf.as_path())
+                .to_str()
+                .unwrap_or("index.html");
+            if let Err(e) = http_serve_file(file_path, &mut stream).await {
+                error!("{e:?}");
+                return;
+            };
+        }
+        info!("finished serving, disconnecting client {peer_addr}");
+    }
+}
+
+/// Reads a password from stdin and prints the hash to put in the config.
+fn print_password_hash() -> ExitCode {
+    let mut password = String::new();
+    if let Err(e) = std::io::stdin().read_line(&mut password) {
+        eprintln!("failed to read password: {e}");
+        return ExitCode::FAILURE;
+    }
+    match hash_password(password.trim_end_matches(['\r', '\n'])) {
+        Ok(hash) => {
+            println!("{hash}");
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
         }
     }
-    Ok(())
 }
 
 #[tokio::main]
 async fn main() -> ExitCode {
-    let c = tracing_subscriber::fmt()
-        .with_max_level(tracing::Level::DEBUG)
-        .finish();
-    c.try_init().expect("failed to install tracing");
+    if std::env::args().nth(1).as_deref() == Some("hash-password") {
+        return print_password_hash();
+    }
+    // RUST_LOG or debug, replaceable at runtime from the admin service
+    let requested = std::env::var("RUST_LOG").unwrap_or_else(|_| "debug".to_string());
+    let (directive, filter, rejected) = match EnvFilter::try_new(&requested) {
+        Ok(filter) => (requested, filter, None),
+        Err(e) => {
+            let rejected = format!("ignoring invalid RUST_LOG {requested:?}, using debug: {e}");
+            ("debug".to_string(), EnvFilter::new("debug"), Some(rejected))
+        }
+    };
+    let (filter, filter_handle) = reload::Layer::new(filter);
+    // events also go to a buffer that clients can follow from the monitor
+    let logs = Arc::new(LogBuffer::default());
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(tracing_subscriber::fmt::layer())
+        .with(LogLayer::new(logs.clone()))
+        .try_init()
+        .expect("failed to install tracing");
+    if let Some(rejected) = rejected {
+        warn!("{rejected}");
+    }
 
-    if let Err(e) = serve().await {
+    let log_filter = LogFilterControl::new(filter_handle, directive);
+    if let Err(e) = serve(logs, log_filter).await {
         ExitCode::FAILURE
     } else {
         ExitCode::SUCCESS
@@ -0,0 +1,198 @@
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::time::Duration;
+
+use parking_lot::Mutex;
+
+use crate::state::ServerState;
+
+/// Content type of the Prometheus text exposition format.
+pub const METRICS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// Every metric name gets this prefix.
+const PREFIX: &str = "console_";
+/// Upper bounds of the query latency buckets, in seconds.
+const LATENCY_BUCKETS: [f64; 14] = [
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0, 300.0,
+];
+
+/// Counters of the statements run since the server started, by context.
+#[derive(Default)]
+pub struct QueryMetrics {
+    contexts: Mutex<BTreeMap<String, ContextMetrics>>,
+}
+
+#[derive(Default)]
+struct ContextMetrics {
+    started: u64,
+    completed: u64,
+    /// killed queries included
+    failed: u64,
+    rows_sent: u64,
+    bytes_sent: u64,
+    spilled_bytes: u64,
+    /// queries per latency bucket, the last one counts those slower than every bound
+    latency: [u64; LATENCY_BUCKETS.len() + 1],
+    latency_sum: f64,
+}
+
+/// What a statement did, recorded once it ended.
+pub struct QueryOutcome {
+    pub succeeded: bool,
+    pub duration: Duration,
+    pub rows_sent: u64,
+    pub bytes_sent: u64,
+    pub spilled_bytes: u64,
+}
+
+impl QueryMetrics {
+    pub fn started(&self, context: &str) {
+        self.contexts
+            .lock()
+            .entry(context.to_string())
+            .or_default()
+            .started += 1;
+    }
+
+    pub fn finished(&self, context: &str, outcome: QueryOutcome) {
+        let mut contexts = self.contexts.lock();
+        let metrics = contexts.entry(context.to_string()).or_default();
+        if outcome.succeeded {
+            metrics.completed += 1;
+        } else {
+            metrics.failed += 1;
+        }
+        metrics.rows_sent += outcome.rows_sent;
+        metrics.bytes_sent += outcome.bytes_sent;
+        metrics.spilled_bytes += outcome.spilled_bytes;
+        let seconds = outcome.duration.as_secs_f64();
+        let bucket = LATENCY_BUCKETS
+            .iter()
+            .position(|bound| seconds <= *bound)
+            .unwrap_or(LATENCY_BUCKETS.len());
+        metrics.latency[bucket] += 1;
+        metrics.latency_sum += seconds;
+    }
+}
+
+/// The metrics of the server in the Prometheus text format, served at `/metrics`.
+pub fn render_metrics(state: &ServerState) -> String {
+    let mut out = String::new();
+    let contexts = state.metrics.contexts.lock();
+    let counters: [(&str, &str, fn(&ContextMetrics) -> u64); 6] = [
+        ("queries_started_total", "Statements started.", |m| {
+            m.started
+        }),
+        (
+            "queries_completed_total",
+            "Statements that completed successfully.",
+            |m| m.completed,
+        ),
+        (
+            "queries_failed_total",
+            "Statements that failed or were killed.",
+            |m| m.failed,
+        ),
+        ("rows_sent_total", "Result rows streamed to clients.", |m| {
+            m.rows_sent
+        }),
+        (
+            "bytes_sent_total",
+            "Encoded result bytes streamed to clients.",
+            |m| m.bytes_sent,
+        ),
+        (
+            "spilled_bytes_total",
+            "Bytes spilled to disk by finished statements.",
+            |m| m.spilled_bytes,
+        ),
+    ];
+    for (name, help, value) in counters {
+        write_header(&mut out, name, help, "counter");
+        for (context, metrics) in contexts.iter() {
+            let labels = format!("context=\"{}\"", escape_label(context));
+            write_sample(&mut out, name, &labels, value(metrics) as f64);
+        }
+    }
+
+    let name = "query_duration_seconds";
+    write_header(
+        &mut out,
+        name,
+        "Latency of finished statements.",
+        "histogram",
+    );
+    for (context, metrics) in contexts.iter() {
+        let context = escape_label(context);
+        let mut cumulative = 0;
+        for (bound, count) in LATENCY_BUCKETS.iter().zip(metrics.latency.iter()) {
+            cumulative += count;
+            let labels = format!("context=\"{context}\",le=\"{bound}\"");
+            write_sample(
+                &mut out,
+                "query_duration_seconds_bucket",
+                &labels,
+                cumulative as f64,
+            );
+        }
+        let count = metrics.latency.iter().sum::<u64>();
+        let labels = format!("context=\"{context}\",le=\"+Inf\"");
+        write_sample(
+            &mut out,
+            "query_duration_seconds_bucket",
+            &labels,
+            count as f64,
+        );
+        let labels = format!("context=\"{context}\"");
+        write_sample(
+            &mut out,
+            "query_duration_seconds_sum",
+            &labels,
+            metrics.latency_sum,
+        );
+        write_sample(
+            &mut out,
+            "query_duration_seconds_count",
+            &labels,
+            count as f64,
+        );
+    }
+    drop(contexts);
+
+    let name = "sessions_active";
+    write_header(&mut out, name, "Connected websocket clients.", "gauge");
+    write_sample(&mut out, name, "", state.resources.clients() as f64);
+
+    let name = "memory_pool_reserved_bytes";
+    write_header(
+        &mut out,
+        name,
+        "Memory reserved in the DataFusion memory pool of each context.",
+        "gauge",
+    );
+    for (context, reserved) in state.contexts.memory_reserved() {
+        let labels = format!("context=\"{}\"", escape_label(&context));
+        write_sample(&mut out, name, &labels, reserved as f64);
+    }
+    out
+}
+
+fn write_header(out: &mut String, name: &str, help: &str, kind: &str) {
+    let _ = writeln!(out, "# HELP {PREFIX}{name} {help}");
+    let _ = writeln!(out, "# TYPE {PREFIX}{name} {kind}");
+}
+
+fn write_sample(out: &mut String, name: &str, labels: &str, value: f64) {
+    if labels.is_empty() {
+        let _ = writeln!(out, "{PREFIX}{name} {value}");
+    } else {
+        let _ = writeln!(out, "{PREFIX}{name}{{{labels}}} {value}");
+    }
+}
+
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
@@ -0,0 +1,425 @@
+use std::collections::{HashMap, VecDeque};
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+use datafusion::physical_plan::{displayable, ExecutionPlan};
+use parking_lot::RwLock;
+use prost::Message as _;
+use tokio::sync::mpsc::Sender;
+use tokio::sync::Notify;
+
+use proto_gen::admin::ActiveQuery;
+use proto_gen::monitor::{
+    monitor_service::Service, InspectQuery, KillQuery, KillResult, MonitorService, OperatorMetrics,
+    QueryDetail, QueryFeed, QueryInfo, QueryState, ResourceFeed,
+};
+use proto_gen::query::Limit;
+
+use crate::config::SharedClientConfig;
+use crate::errors::AppErrors;
+use crate::limits::StatementCaps;
+use crate::sessions::Session;
+use crate::state::ServerState;
+
+/// Finished queries kept for the monitor besides the running ones.
+const RECENT_QUERIES: usize = 200;
+
+/// Every statement run by any session, while it runs and for a while after.
+#[derive(Default)]
+pub struct QueryRegistry {
+    next_id: AtomicU64,
+    /// oldest first
+    queries: RwLock<VecDeque<Arc<TrackedQuery>>>,
+}
+
+impl QueryRegistry {
+    /// Tracks a statement waiting for admission, see [`TrackedQuery::admitted`].
+    pub fn register(
+        &self,
+        sql: &str,
+        client_addr: SocketAddr,
+        context: &str,
+        started_at_ms: u64,
+    ) -> Arc<TrackedQuery> {
+        let query = Arc::new(TrackedQuery {
+            id: self.next_id.fetch_add(1, Ordering::Relaxed) + 1,
+            sql: sql.to_string(),
+            client_addr: client_addr.to_string(),
+            context: context.to_string(),
+            started_at_ms,
+            started: Instant::now(),
+            rows: AtomicU64::new(0),
+            bytes_sent: AtomicU64::new(0),
+            caps: RwLock::new(StatementCaps::default()),
+            plan: RwLock::new(None),
+            outcome: RwLock::new(None),
+            queued: AtomicBool::new(true),
+            killed: AtomicBool::new(false),
+            kill_switch: Notify::new(),
+        });
+
+        let mut queries = self.queries.write();
+        queries.push_back(query.clone());
+        let mut finished = queries.iter().filter(|q| !q.is_running()).count();
+        while finished > RECENT_QUERIES {
+            let Some(oldest) = queries.iter().position(|q| !q.is_running()) else {
+                break;
+            };
+            queries.remove(oldest);
+            finished -= 1;
+        }
+        query
+    }
+
+    pub fn get(&self, id: u64) -> Option<Arc<TrackedQuery>> {
+        self.queries.read().iter().find(|q| q.id == id).cloned()
+    }
+
+    pub fn snapshot(&self) -> Vec<QueryInfo> {
+        self.queries.read().iter().map(|q| q.info()).collect()
+    }
+
+    /// The running queries by the address of the client that started them.
+    pub fn running_by_client(&self) -> HashMap<String, Vec<ActiveQuery>> {
+        let mut running = HashMap::<String, Vec<ActiveQuery>>::new();
+        for query in self.queries.read().iter().filter(|q| q.is_running()) {
+            running
+                .entry(query.client_addr.clone())
+                .or_default()
+                .push(ActiveQuery {
+                    id: query.id,
+                    sql: query.sql.clone(),
+                    duration_ms: query.started.elapsed().as_millis() as u64,
+                });
+        }
+        running
+    }
+
+    pub fn kill(&self, id: u64) -> Result<(), AppErrors> {
+        let query = self
+            .get(id)
+            .ok_or_else(|| format!("query {id} is not known, it may have been forgotten"))?;
+        if !query.is_running() {
+            return Err(format!("query {id} is not running").into());
+        }
+        query.killed.store(true, Ordering::Relaxed);
+        // a permit is kept if the query doesn't wait yet
+        query.kill_switch.notify_one();
+        Ok(())
+    }
+}
+
+pub struct TrackedQuery {
+    id: u64,
+    sql: String,
+    client_addr: String,
+    context: String,
+    started_at_ms: u64,
+    started: Instant,
+    rows: AtomicU64,
+    bytes_sent: AtomicU64,
+    caps: RwLock<StatementCaps>,
+    plan: RwLock<Option<Arc<dyn ExecutionPlan>>>,
+    /// `None` while queued or running
+    outcome: RwLock<Option<Outcome>>,
+    /// until admitted to run
+    queued: AtomicBool,
+    killed: AtomicBool,
+    kill_switch: Notify,
+}
+
+struct Outcome {
+    state: QueryState,
+    duration_ms: u64,
+    error: String,
+}
+
+impl TrackedQuery {
+    /// The query left the admission queue and runs.
+    pub fn admitted(&self) {
+        self.queued.store(false, Ordering::Relaxed);
+    }
+
+    pub fn set_plan(&self, plan: Arc<dyn ExecutionPlan>) {
+        self.plan.write().replace(plan);
+    }
+
+    pub fn set_caps(&self, caps: StatementCaps) {
+        *self.caps.write() = caps;
+    }
+
+    /// How many of the next `rows` fit under the row cap.
+    pub fn rows_allowed(&self, rows: u64) -> u64 {
+        match self.caps.read().max_rows {
+            Some(max) => rows.min(max.saturating_sub(self.rows.load(Ordering::Relaxed))),
+            None => rows,
+        }
+    }
+
+    /// Counts a frame of `rows` about to be sent, unless its `bytes` go beyond the byte cap:
+    /// the frame must not be sent then.
+    pub fn add_sent(&self, rows: u64, bytes: u64) -> Result<(), Limit> {
+        let sent = self.bytes_sent.load(Ordering::Relaxed);
+        if self
+            .caps
+            .read()
+            .max_bytes
+            .is_some_and(|max| sent.saturating_add(bytes) > max)
+        {
+            return Err(Limit::ResultBytes);
+        }
+        self.rows.fetch_add(rows, Ordering::Relaxed);
+        self.bytes_sent.fetch_add(bytes, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Result rows and their encoded bytes sent so far.
+    pub fn sent(&self) -> (u64, u64) {
+        (
+            self.rows.load(Ordering::Relaxed),
+            self.bytes_sent.load(Ordering::Relaxed),
+        )
+    }
+
+    /// Bytes spilled to disk by every node of the plan so far.
+    pub fn spilled_bytes(&self) -> u64 {
+        fn spilled(plan: &dyn ExecutionPlan) -> u64 {
+            let own = plan.metrics().and_then(|m| m.spilled_bytes());
+            own.unwrap_or_default() as u64
+                + plan
+                    .children()
+                    .iter()
+                    .map(|child| spilled(child.as_ref()))
+                    .sum::<u64>()
+        }
+        self.plan
+            .read()
+            .as_ref()
+            .map(|plan| spilled(plan.as_ref()))
+            .unwrap_or_default()
+    }
+
+    /// Resolves once the query is killed from the monitor, while queued as well.
+    pub async fn kill_requested(&self) {
+        self.kill_switch.notified().await
+    }
+
+    /// Whether the query was killed from the monitor or by an admin.
+    pub fn was_killed(&self) -> bool {
+        self.killed.load(Ordering::Relaxed)
+    }
+
+    pub fn finish<T>(&self, result: &Result<T, AppErrors>) {
+        let state = match result {
+            Ok(_) => QueryState::Finished,
+            Err(_) if self.was_killed() => QueryState::Killed,
+            Err(_) => QueryState::Failed,
+        };
+        self.outcome.write().replace(Outcome {
+            state,
+            duration_ms: self.started.elapsed().as_millis() as u64,
+            error: result
+                .as_ref()
+                .err()
+                .map(|e| e.to_string())
+                .unwrap_or_default(),
+        });
+    }
+
+    fn is_running(&self) -> bool {
+        self.outcome.read().is_none()
+    }
+
+    fn info(&self) -> QueryInfo {
+        let outcome = self.outcome.read();
+        let (state, duration_ms, error) = match outcome.as_ref() {
+            Some(o) => (o.state, o.duration_ms, o.error.clone()),
+            None if self.queued.load(Ordering::Relaxed) => (
+                QueryState::Queued,
+                self.started.elapsed().as_millis() as u64,
+                String::new(),
+            ),
+            None => (
+                QueryState::Running,
+                self.started.elapsed().as_millis() as u64,
+                String::new(),
+            ),
+        };
+        QueryInfo {
+            id: self.id,
+            sql: self.sql.clone(),
+            client_addr: self.client_addr.clone(),
+            context: self.context.clone(),
+            state: state.into(),
+            started_at_ms: self.started_at_ms,
+            duration_ms,
+            rows: self.rows.load(Ordering::Relaxed),
+            bytes_sent: self.bytes_sent.load(Ordering::Relaxed),
+            error,
+        }
+    }
+
+    fn detail(&self, request_id: u64) -> QueryDetail {
+        let plan = self.plan.read();
+        QueryDetail {
+            request_id,
+            query: Some(self.info()),
+            plan: plan
+                .as_ref()
+                .map(|plan| displayable(plan.as_ref()).indent(true).to_string())
+                .unwrap_or_default(),
+            error: String::new(),
+            operators: plan.as_ref().map(|plan| operator_metrics(plan.as_ref())),
+        }
+    }
+}
+
+/// Samples the metrics of `plan` and its children, the nodes update them while executing.
+fn operator_metrics(plan: &dyn ExecutionPlan) -> OperatorMetrics {
+    let metrics = plan.metrics().map(|m| m.aggregate_by_name());
+    let metric = |value: Option<usize>| value.unwrap_or_default() as u64;
+    OperatorMetrics {
+        name: displayable(plan)
+            .one_line()
+            .to_string()
+            .trim_end()
+            .to_string(),
+        output_rows: metric(metrics.as_ref().and_then(|m| m.output_rows())),
+        elapsed_compute_ns: metric(metrics.as_ref().and_then(|m| m.elapsed_compute())),
+        spilled_bytes: metric(metrics.as_ref().and_then(|m| m.spilled_bytes())),
+        spill_count: metric(metrics.as_ref().and_then(|m| m.spill_count())),
+        mem_used: metric(
+            metrics
+                .as_ref()
+                .and_then(|m| m.sum_by_name("mem_used"))
+                .map(|v| v.as_usize()),
+        ),
+        estimated_rows: plan
+            .statistics()
+            .ok()
+            .and_then(|s| s.num_rows.get_value().map(|rows| *rows as u64)),
+        children: plan
+            .children()
+            .iter()
+            .map(|child| operator_metrics(child.as_ref()))
+            .collect(),
+    }
+}
+
+async fn reply(tx: &Sender<Vec<u8>>, service: Service) -> Result<(), AppErrors> {
+    let msg = MonitorService {
+        service: Some(service),
+    }
+    .encode_to_vec();
+    tx.send(msg)
+        .await
+        .map_err(|e| format!("failed to queue reply, session closed: {e}"))?;
+    Ok(())
+}
+
+/// Sends the registry and the resource samples taken since the previous push to a subscribed
+/// session at its monitor interval until it goes away, the session aborts the task on
+/// unsubscribe. Nothing is sent while the session paused the monitor.
+pub(crate) async fn monitor_feed(
+    state: Arc<ServerState>,
+    tx: Sender<Vec<u8>>,
+    session: Arc<Session>,
+    config: SharedClientConfig,
+) -> Result<(), AppErrors> {
+    if let Err(e) = session.require_admin("monitoring queries") {
+        let feed = QueryFeed {
+            error: e.to_string(),
+            ..Default::default()
+        };
+        return reply(&tx, Service::FeedMsg(feed)).await;
+    }
+    // the first push carries every kept sample
+    let mut last_sample = 0;
+    loop {
+        let (paused, interval) = {
+            let config = config.read();
+            (config.pause_monitor, config.monitor_interval)
+        };
+        if !paused {
+            let feed = QueryFeed {
+                queries: state.queries.snapshot(),
+                ..Default::default()
+            };
+            reply(&tx, Service::FeedMsg(feed)).await?;
+            let (samples, last) = state.resources.samples_since(last_sample);
+            last_sample = last;
+            if !samples.is_empty() {
+                reply(&tx, Service::ResourcesMsg(ResourceFeed { samples })).await?;
+            }
+        }
+        tokio::time::sleep(interval).await;
+    }
+}
+
+/// Sends the detail of the inspected query with its operator metrics at the monitor interval
+/// until it ends, the session aborts the task when inspection stops.
+pub(crate) async fn inspect_query(
+    state: Arc<ServerState>,
+    tx: Sender<Vec<u8>>,
+    session: Arc<Session>,
+    config: SharedClientConfig,
+    req: InspectQuery,
+) -> Result<(), AppErrors> {
+    let query = session.require_admin("inspecting a query").and_then(|()| {
+        state.queries.get(req.id).ok_or_else(|| {
+            format!("query {} is not known, it may have been forgotten", req.id).into()
+        })
+    });
+    let query = match query {
+        Ok(query) => query,
+        Err(e) => {
+            let detail = QueryDetail {
+                request_id: req.request_id,
+                error: e.to_string(),
+                ..Default::default()
+            };
+            return reply(&tx, Service::DetailMsg(detail)).await;
+        }
+    };
+    loop {
+        let (paused, interval) = {
+            let config = config.read();
+            (config.pause_monitor, config.monitor_interval)
+        };
+        // the last detail is sent after the query ended with its final metrics, even if paused
+        let running = query.is_running();
+        if !running || !paused {
+            reply(&tx, Service::DetailMsg(query.detail(req.request_id))).await?;
+        }
+        if !running {
+            return Ok(());
+        }
+        tokio::time::sleep(interval).await;
+    }
+}
+
+pub(crate) async fn kill_query(
+    state: Arc<ServerState>,
+    tx: Sender<Vec<u8>>,
+    session: Arc<Session>,
+    req: KillQuery,
+) -> Result<(), AppErrors> {
+    let killed = session
+        .require_admin("killing a query")
+        .and_then(|()| state.queries.kill(req.id));
+    let error = match killed {
+        Ok(()) => String::new(),
+        Err(e) => e.to_string(),
+    };
+    reply(
+        &tx,
+        Service::KillResultMsg(KillResult {
+            request_id: req.request_id,
+            id: req.id,
+            error,
+        }),
+    )
+    .await
+}
@@ -0,0 +1,82 @@
+use std::net::{IpAddr, SocketAddr};
+
+use tokio_tungstenite::tungstenite::handshake::server::{ErrorResponse, Request, Response};
+use tokio_tungstenite::tungstenite::http::StatusCode;
+use tracing::warn;
+
+/// Which pages may open websockets and call the HTTP API of the server from a browser.
+#[derive(Default)]
+pub struct OriginPolicy {
+    /// besides the server itself, `*` allows any
+    allowed: Vec<String>,
+    /// of the pages this server serves, `https` behind TLS
+    scheme: &'static str,
+}
+
+impl OriginPolicy {
+    /// `allowed` are the `allowed_origins` of the config file, as `scheme://host[:port]`.
+    pub fn new(allowed: Vec<String>, tls: bool) -> Self {
+        Self {
+            allowed: allowed
+                .into_iter()
+                .map(|o| o.trim_end_matches('/').to_ascii_lowercase())
+                .collect(),
+            scheme: if tls { "https" } else { "http" },
+        }
+    }
+
+    /// Requests without an `Origin` don't come from a page and are allowed, pages served by
+    /// this server are too when it is addressed by IP or as localhost. Any name could resolve
+    /// to the server, its pages reached by name must be among the allowed origins.
+    pub fn allows(&self, origin: Option<&str>, host: Option<&str>) -> bool {
+        let Some(origin) = origin else {
+            return true;
+        };
+        let origin = origin.to_ascii_lowercase();
+        if let Some(host) = host.map(str::to_ascii_lowercase) {
+            if is_local_or_ip(&host) && origin == format!("{}://{host}", self.scheme) {
+                return true;
+            }
+        }
+        self.allowed.iter().any(|a| a == "*" || *a == origin)
+    }
+
+    /// Rejects the websocket handshake of a page from another origin with 403.
+    pub fn check_handshake(
+        &self,
+        peer: SocketAddr,
+        request: &Request,
+        response: Response,
+    ) -> Result<Response, ErrorResponse> {
+        let header = |name| request.headers().get(name).and_then(|v| v.to_str().ok());
+        let origin = header("origin");
+        if self.allows(origin, header("host")) {
+            return Ok(response);
+        }
+        warn!("rejected websocket from {peer}: origin {origin:?} is not allowed");
+        let mut rejection = ErrorResponse::new(Some("origin not allowed".to_string()));
+        *rejection.status_mut() = StatusCode::FORBIDDEN;
+        Err(rejection)
+    }
+
+    /// Headers letting the page at `origin` read an API response, none for other clients.
+    pub fn cors_headers(&self, origin: Option<&str>) -> Vec<(&'static str, String)> {
+        let Some(origin) = origin else {
+            return vec![];
+        };
+        vec![
+            ("Access-Control-Allow-Origin", origin.to_string()),
+            ("Vary", "Origin".to_string()),
+        ]
+    }
+}
+
+/// Whether the `host[:port]` of a Host header is localhost or an IP address, which unlike
+/// other names can't be rebound to the server by whoever controls a DNS zone.
+fn is_local_or_ip(host: &str) -> bool {
+    let name = match host.strip_prefix('[') {
+        Some(v6) => v6.split_once(']').map_or(v6, |(address, _)| address),
+        None => host.rsplit_once(':').map_or(host, |(name, _)| name),
+    };
+    name == "localhost" || name.parse::<IpAddr>().is_ok()
+}
@@ -0,0 +1,441 @@
+use std::net::SocketAddr;
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use datafusion::arrow::array::{Array, ArrayRef, AsArray};
+use datafusion::arrow::compute::cast;
+use datafusion::arrow::datatypes::{DataType, Float64Type, TimeUnit};
+use datafusion::arrow::error::ArrowError;
+use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::arrow::util::display::{ArrayFormatter, FormatOptions};
+use datafusion::logical_expr::LogicalPlan;
+use datafusion::physical_plan::execute_stream;
+use datafusion::prelude::SessionContext;
+use futures_util::StreamExt;
+use prost::Message as _;
+use tokio::sync::mpsc::Sender;
+use tracing::{debug, info};
+
+use proto_gen::query::{
+    query_service::Service, Cell, Column, ExecuteDone, ExecuteSql, HistoryEntry, Limit,
+    QueryService, Queued, ResultHeader, ResultRows, Row, StatementDone,
+};
+
+use crate::audit::{plan_hash, AuditRecord, Outcome};
+use crate::config::SharedClientConfig;
+use crate::context::context_name;
+use crate::errors::AppErrors;
+use crate::limits::{Admission, Limits, Permit, Requester, StatementCaps};
+use crate::metrics::QueryOutcome;
+use crate::monitor::TrackedQuery;
+use crate::state::ServerState;
+use crate::tables::ListingTables;
+
+/// Rows are split into frames of at most this many rows.
+const ROWS_PER_FRAME: usize = 512;
+
+pub(crate) async fn reply(tx: &Sender<Vec<u8>>, service: Service) -> Result<(), AppErrors> {
+    send(tx, encode(service)).await
+}
+
+fn encode(service: Service) -> Vec<u8> {
+    QueryService {
+        service: Some(service),
+    }
+    .encode_to_vec()
+}
+
+async fn send(tx: &Sender<Vec<u8>>, msg: Vec<u8>) -> Result<(), AppErrors> {
+    tx.send(msg)
+        .await
+        .map_err(|e| format!("failed to queue reply, session closed: {e}"))?;
+    Ok(())
+}
+
+/// Runs the statements of `req` in order against its context and streams the results back.
+/// Execution stops at the first failing statement, a statement killed from the monitor fails
+/// and so does one the requester has no permission for, one touching files outside the data
+/// roots and one refused by a limit. One reaching its caps succeeds with the rows sent so far.
+pub(crate) async fn execute_sql(
+    state: Arc<ServerState>,
+    tx: Sender<Vec<u8>>,
+    client_addr: SocketAddr,
+    requester: Requester,
+    config: SharedClientConfig,
+    req: ExecuteSql,
+) -> Result<(), AppErrors> {
+    let ExecuteSql {
+        request_id,
+        context,
+        statements,
+        limits,
+    } = req;
+    let ctx = config
+        .read()
+        .session_context(state.contexts.get_or_create(&context));
+    info!(
+        "executing request {request_id}: {} statement(s) in context {context:?}",
+        statements.len()
+    );
+
+    let caps = state.limits.statement_caps(
+        &context,
+        StatementCaps::requested(&limits.unwrap_or_default()),
+    );
+
+    for (index, sql) in statements.iter().enumerate() {
+        let statement_index = index as u32;
+        let queued_at_ms = now_ms();
+        // tracked while queued, so the monitor shows it and may kill it
+        let tracked =
+            state
+                .queries
+                .register(sql, client_addr, context_name(&context), queued_at_ms);
+        tracked.set_caps(caps);
+        let admitted = admit(
+            &state.limits,
+            &tx,
+            &requester,
+            &tracked,
+            request_id,
+            statement_index,
+        )
+        .await;
+        let started_at_ms = now_ms();
+        let started = Instant::now();
+        state.metrics.started(context_name(&context));
+        let hash = OnceLock::new();
+        // set once the definition took effect, to persist it
+        let executed_ddl = OnceLock::new();
+        let authorize = |plan: &LogicalPlan| {
+            hash.get_or_init(|| plan_hash(plan));
+            state.access.check(requester.role, &context, plan)?;
+            state.data_roots.check_plan(plan)?;
+            Ok::<_, AppErrors>(keep_ddl(plan, &executed_ddl))
+        };
+        let statement = run_statement(
+            &ctx,
+            &tx,
+            &tracked,
+            request_id,
+            statement_index,
+            sql,
+            authorize,
+        );
+        let result = match admitted {
+            // the permit is released once the statement is done
+            Ok(_permit) => tokio::select! {
+                result = statement => result,
+                _ = tracked.kill_requested() => Err("killed from the monitor".into()),
+                _ = expired(caps.timeout()) => Ok(Some(Limit::Timeout)),
+            },
+            Err(e) => Err(e),
+        };
+        tracked.finish(&result);
+        // whatever happened after it, a definition executed is in the context now
+        if let Some(plan) = executed_ddl.get() {
+            state.catalog.record(&context, sql, plan);
+        }
+        let (rows_sent, bytes_sent) = tracked.sent();
+        state.metrics.finished(
+            context_name(&context),
+            QueryOutcome {
+                succeeded: result.is_ok(),
+                duration: started.elapsed(),
+                rows_sent,
+                bytes_sent,
+                spilled_bytes: tracked.spilled_bytes(),
+            },
+        );
+        let outcome = match &result {
+            Ok(None) => Outcome::Succeeded,
+            Ok(Some(_)) => Outcome::Partial,
+            Err(_) if tracked.was_killed() => Outcome::Killed,
+            Err(_) => Outcome::Failed,
+        };
+        let (row_count, error, limit) = match result {
+            Ok(None) => (rows_sent, String::new(), Limit::Unspecified),
+            Ok(Some(limit)) => {
+                info!("request {request_id}#{statement_index} cut short by {limit:?}");
+                (rows_sent, String::new(), limit)
+            }
+            Err(e) => (0, e.to_string(), e.limit()),
+        };
+        let duration_ms = started.elapsed().as_millis() as u64;
+        let failed = !error.is_empty();
+        state.history.record(HistoryEntry {
+            sql: sql.clone(),
+            context: context_name(&context).to_string(),
+            started_at_ms,
+            duration_ms,
+            row_count,
+            error: error.clone(),
+        });
+        state.audit.record(&AuditRecord {
+            timestamp_ms: started_at_ms,
+            user: requester.user.clone(),
+            peer_addr: client_addr.to_string(),
+            context: context_name(&context).to_string(),
+            sql: sql.clone(),
+            plan_hash: hash.get().cloned().unwrap_or_default(),
+            outcome,
+            rows: row_count,
+            duration_ms,
+            error: error.clone(),
+        });
+        reply(
+            &tx,
+            Service::StatementDoneMsg(StatementDone {
+                request_id,
+                statement_index,
+                row_count,
+                duration_ms,
+                error,
+                started_at_ms,
+                limit: limit as i32,
+            }),
+        )
+        .await?;
+        if failed {
+            break;
+        }
+    }
+
+    reply(&tx, Service::ExecuteDoneMsg(ExecuteDone { request_id })).await
+}
+
+/// Waits until the statement may run, telling the client its place in the admission queue
+/// whenever it changes. Fails once `tracked` is killed meanwhile.
+async fn admit<'a>(
+    limits: &'a Limits,
+    tx: &Sender<Vec<u8>>,
+    requester: &Requester,
+    tracked: &TrackedQuery,
+    request_id: u64,
+    statement_index: u32,
+) -> Result<Permit<'a>, AppErrors> {
+    let mut ticket = match limits.admit(requester)? {
+        Admission::Running(permit) => {
+            tracked.admitted();
+            return Ok(permit);
+        }
+        Admission::Queued(ticket) => ticket,
+    };
+    let mut reported = 0;
+    loop {
+        let position = ticket.position();
+        if position != reported {
+            reply(
+                tx,
+                Service::QueuedMsg(Queued {
+                    request_id,
+                    statement_index,
+                    position,
+                }),
+            )
+            .await?;
+            reported = position;
+        }
+        tokio::select! {
+            permit = ticket.advance() => {
+                if let Some(permit) = permit {
+                    tracked.admitted();
+                    return Ok(permit);
+                }
+            }
+            _ = tracked.kill_requested() => {
+                return Err("killed from the monitor while queued".into());
+            }
+            _ = tx.closed() => return Err("session closed while queued".into()),
+        }
+    }
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+/// Resolves once `timeout` passed, never without one.
+async fn expired(timeout: Option<Duration>) {
+    match timeout {
+        Some(timeout) => tokio::time::sleep(timeout).await,
+        None => std::future::pending().await,
+    }
+}
+
+/// What to call once `plan` was executed, it keeps `plan` in `executed_ddl` if it is DDL.
+fn keep_ddl<'a>(plan: &LogicalPlan, executed_ddl: &'a OnceLock<LogicalPlan>) -> impl FnOnce() + 'a {
+    let ddl = matches!(plan, LogicalPlan::Ddl(_)).then(|| plan.clone());
+    move || {
+        if let Some(plan) = ddl {
+            executed_ddl.get_or_init(|| plan);
+        }
+    }
+}
+
+/// Runs `sql` once `authorize` accepted its plan, returns the cap of `tracked` that cut the
+/// result short. The stream is dropped there, cancelling the rest of the plan. What
+/// `authorize` returns is called once the plan was executed against `ctx`.
+async fn run_statement<E: FnOnce()>(
+    ctx: &SessionContext,
+    tx: &Sender<Vec<u8>>,
+    tracked: &TrackedQuery,
+    request_id: u64,
+    statement_index: u32,
+    sql: &str,
+    authorize: impl FnOnce(&LogicalPlan) -> Result<E, AppErrors>,
+) -> Result<Option<Limit>, AppErrors> {
+    debug!("request {request_id}#{statement_index}: {sql}");
+    // DDL takes effect when executing the plan, it is checked before
+    let plan = ctx
+        .state()
+        .create_logical_plan(sql)
+        .await
+        .map_err(|e| e.to_string())?;
+    let executed = authorize(&plan)?;
+    let tables = ListingTables::of(&plan);
+    let df = ctx
+        .execute_logical_plan(plan)
+        .await
+        .map_err(|e| e.to_string())?;
+    executed();
+    let task_ctx = Arc::new(df.task_ctx());
+    let plan = df.create_physical_plan().await.map_err(|e| e.to_string())?;
+    tracked.set_plan(plan.clone());
+    let scans = tables.scans(ctx, plan.as_ref()).await;
+    let mut stream = execute_stream(plan, task_ctx).map_err(|e| e.to_string())?;
+
+    let columns = stream
+        .schema()
+        .fields()
+        .iter()
+        .map(|f| Column {
+            name: f.name().clone(),
+            data_type: f.data_type().to_string(),
+            nullable: f.is_nullable(),
+        })
+        .collect();
+    reply(
+        tx,
+        Service::ResultHeaderMsg(ResultHeader {
+            request_id,
+            statement_index,
+            columns,
+            scans,
+        }),
+    )
+    .await?;
+
+    while let Some(batch) = stream.next().await {
+        let batch = batch.map_err(|e| e.to_string())?;
+        let allowed = tracked.rows_allowed(batch.num_rows() as u64) as usize;
+        let rows = batch_to_rows(&batch.slice(0, allowed))
+            .map_err(|e| format!("failed to format batch: {e}"))?;
+        for chunk in rows.chunks(ROWS_PER_FRAME) {
+            let msg = encode(Service::ResultRowsMsg(ResultRows {
+                request_id,
+                statement_index,
+                rows: chunk.to_vec(),
+            }));
+            if let Err(limit) = tracked.add_sent(chunk.len() as u64, msg.len() as u64) {
+                return Ok(Some(limit));
+            }
+            send(tx, msg).await?;
+        }
+        if allowed < batch.num_rows() {
+            return Ok(Some(Limit::ResultRows));
+        }
+    }
+
+    Ok(None)
+}
+
+fn batch_to_rows(batch: &RecordBatch) -> Result<Vec<Row>, ArrowError> {
+    let options = FormatOptions::default().with_null("NULL");
+    let formatters = batch
+        .columns()
+        .iter()
+        .map(|c| ArrayFormatter::try_new(c.as_ref(), &options))
+        .collect::<Result<Vec<_>, _>>()?;
+    let numbers = batch.columns().iter().map(plottable).collect::<Vec<_>>();
+
+    let rows = (0..batch.num_rows())
+        .map(|r| Row {
+            cells: batch
+                .columns()
+                .iter()
+                .zip(formatters.iter().zip(numbers.iter()))
+                .map(|(column, (formatter, number))| Cell {
+                    text: formatter.value(r).to_string(),
+                    is_null: column.is_null(r),
+                    number: number.as_ref().and_then(|n| {
+                        let n = n.as_primitive::<Float64Type>();
+                        n.is_valid(r).then(|| n.value(r))
+                    }),
+                })
+                .collect(),
+        })
+        .collect();
+    Ok(rows)
+}
+
+/// The column as `Float64` for plotting, temporal values as milliseconds since the epoch.
+fn plottable(column: &ArrayRef) -> Option<ArrayRef> {
+    let millis = match column.data_type() {
+        t if t.is_numeric() => return cast(column, &DataType::Float64).ok(),
+        DataType::Timestamp(_, _) => {
+            cast(column, &DataType::Timestamp(TimeUnit::Millisecond, None)).ok()?
+        }
+        DataType::Date32 | DataType::Date64 => cast(column, &DataType::Date64).ok()?,
+        _ => return None,
+    };
+    let millis = cast(&millis, &DataType::Int64).ok()?;
+    cast(&millis, &DataType::Float64).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::sync::mpsc::channel;
+
+    use super::*;
+    use crate::monitor::QueryRegistry;
+
+    #[tokio::test]
+    async fn ddl_executed_before_a_timeout_is_kept() {
+        let ctx = SessionContext::new();
+        // a full channel holds the statement up once its plan was executed
+        let (tx, _rx) = channel(1);
+        tx.send(vec![]).await.unwrap();
+        let sql = "CREATE VIEW v AS SELECT 1 AS a";
+        let tracked = QueryRegistry::default().register(sql, ([127, 0, 0, 1], 1).into(), "", 0);
+        let executed_ddl = OnceLock::new();
+        let statement = run_statement(&ctx, &tx, &tracked, 1, 0, sql, |plan| {
+            Ok(keep_ddl(plan, &executed_ddl))
+        });
+        let timed_out = tokio::time::timeout(Duration::from_millis(200), statement).await;
+
+        assert!(timed_out.is_err());
+        assert!(matches!(executed_ddl.get(), Some(LogicalPlan::Ddl(_))));
+        assert!(ctx.table_exist("v").unwrap());
+    }
+
+    #[tokio::test]
+    async fn ddl_failing_to_execute_is_not_kept() {
+        let ctx = SessionContext::new();
+        ctx.sql("CREATE VIEW v AS SELECT 1 AS a").await.unwrap();
+        let (tx, _rx) = channel(8);
+        let sql = "CREATE VIEW v AS SELECT 2 AS a";
+        let tracked = QueryRegistry::default().register(sql, ([127, 0, 0, 1], 1).into(), "", 0);
+        let executed_ddl = OnceLock::new();
+        let result = run_statement(&ctx, &tx, &tracked, 1, 0, sql, |plan| {
+            Ok(keep_ddl(plan, &executed_ddl))
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert!(executed_ddl.get().is_none());
+    }
+}
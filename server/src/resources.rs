@@ -0,0 +1,159 @@
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use parking_lot::Mutex;
+use tokio::runtime::Handle;
+
+use proto_gen::monitor::{ContextMemory, ResourceSample};
+
+use crate::state::ServerState;
+
+/// Resources are sampled this often, whatever the monitor interval of the clients.
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+/// Samples kept for clients that start monitoring, five minutes worth.
+const KEPT_SAMPLES: usize = 300;
+/// Unit of the CPU times in `/proc/self/stat`, USER_HZ is 100 on every Linux architecture.
+const CLOCK_TICKS: f64 = 100.0;
+
+/// Recent samples of the health of the server and the number of connected clients.
+#[derive(Default)]
+pub struct ResourceMonitor {
+    clients: AtomicU64,
+    /// the sequence number of the last sample and the samples, oldest first
+    samples: Mutex<(u64, VecDeque<ResourceSample>)>,
+}
+
+impl ResourceMonitor {
+    /// Counts a connected client until the returned guard is dropped.
+    pub fn connect(&self) -> ClientGuard<'_> {
+        self.clients.fetch_add(1, Ordering::Relaxed);
+        ClientGuard(&self.clients)
+    }
+
+    pub fn clients(&self) -> u64 {
+        self.clients.load(Ordering::Relaxed)
+    }
+
+    pub fn latest(&self) -> Option<ResourceSample> {
+        self.samples.lock().1.back().cloned()
+    }
+
+    /// The samples taken after the one numbered `after` that are still kept, with the number of
+    /// the last sample.
+    pub fn samples_since(&self, after: u64) -> (Vec<ResourceSample>, u64) {
+        let samples = self.samples.lock();
+        let (last_seq, kept) = &*samples;
+        let new = (*last_seq - after).min(kept.len() as u64) as usize;
+        let new = kept.iter().skip(kept.len() - new).cloned().collect();
+        (new, *last_seq)
+    }
+
+    fn push(&self, sample: ResourceSample) {
+        let mut samples = self.samples.lock();
+        let (last_seq, kept) = &mut *samples;
+        *last_seq += 1;
+        if kept.len() == KEPT_SAMPLES {
+            kept.pop_front();
+        }
+        kept.push_back(sample);
+    }
+}
+
+pub struct ClientGuard<'a>(&'a AtomicU64);
+
+impl Drop for ClientGuard<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+struct ProcStats {
+    rss_bytes: u64,
+    /// user and system time of the process so far
+    cpu_ticks: u64,
+    open_fds: u64,
+}
+
+/// Reads the figures of this process from `/proc`, see proc(5).
+#[cfg(target_os = "linux")]
+fn read_proc() -> Option<ProcStats> {
+    let stat = std::fs::read_to_string("/proc/self/stat").ok()?;
+    // the command name may contain spaces, numbering restarts after it with the state as 3
+    let fields = stat
+        .rsplit_once(')')?
+        .1
+        .split_whitespace()
+        .collect::<Vec<_>>();
+    let field = |n: usize| fields.get(n - 3)?.parse::<u64>().ok();
+    let cpu_ticks = field(14)? + field(15)?;
+
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let rss_kib = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmRSS:"))?
+        .trim()
+        .trim_end_matches("kB")
+        .trim()
+        .parse::<u64>()
+        .ok()?;
+
+    // the directory being read holds a descriptor as well
+    let open_fds = std::fs::read_dir("/proc/self/fd").ok()?.count() as u64;
+    Some(ProcStats {
+        rss_bytes: rss_kib * 1024,
+        cpu_ticks,
+        open_fds: open_fds.saturating_sub(1),
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_proc() -> Option<ProcStats> {
+    None
+}
+
+/// Samples the process, the tokio runtime and the memory pools of the contexts every
+/// [`SAMPLE_INTERVAL`] while the server runs.
+pub(crate) async fn sample_resources(state: Arc<ServerState>) {
+    let runtime = Handle::current().metrics();
+    let mut interval = tokio::time::interval(SAMPLE_INTERVAL);
+    // when and how much CPU time was used at the previous sample
+    let mut previous: Option<(Instant, u64)> = None;
+    loop {
+        interval.tick().await;
+        let now = Instant::now();
+        let proc = read_proc();
+        let cpu_percent = match (&proc, previous) {
+            (Some(p), Some((at, ticks))) => {
+                let used = p.cpu_ticks.saturating_sub(ticks) as f64 / CLOCK_TICKS;
+                Some(used / now.duration_since(at).as_secs_f64() * 100.0)
+            }
+            _ => None,
+        };
+        previous = proc.as_ref().map(|p| (now, p.cpu_ticks));
+
+        state.resources.push(ResourceSample {
+            time_ms: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_millis() as u64)
+                .unwrap_or_default(),
+            rss_bytes: proc.as_ref().map(|p| p.rss_bytes),
+            cpu_percent,
+            open_fds: proc.as_ref().map(|p| p.open_fds),
+            alive_tasks: runtime.num_alive_tasks() as u64,
+            workers: runtime.num_workers() as u64,
+            queued_tasks: runtime.global_queue_depth() as u64,
+            clients: state.resources.clients(),
+            contexts: state
+                .contexts
+                .memory_reserved()
+                .into_iter()
+                .map(|(name, reserved)| ContextMemory {
+                    name,
+                    reserved_bytes: reserved as u64,
+                })
+                .collect(),
+        });
+    }
+}
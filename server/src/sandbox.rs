@@ -0,0 +1,204 @@
+use std::path::{Component, Path, PathBuf};
+
+use datafusion::logical_expr::{DdlStatement, LogicalPlan};
+use tracing::{info, warn};
+use url::Url;
+
+use crate::errors::AppErrors;
+
+/// Characters starting the pattern part of a table location.
+const GLOB_CHARS: &[char] = &['*', '?', '[', '{'];
+
+/// Directories tables may be read from and results written to.
+#[derive(Default)]
+pub struct DataRoots {
+    /// canonical, any path is allowed when unset
+    roots: Option<Vec<PathBuf>>,
+}
+
+impl DataRoots {
+    /// `roots` are the `data_roots` of the config file, each must exist.
+    pub fn new(roots: Option<Vec<PathBuf>>) -> Result<Self, AppErrors> {
+        let Some(roots) = roots else {
+            warn!("no data roots configured, statements may read and write any file");
+            return Ok(Self::default());
+        };
+        let roots = roots
+            .iter()
+            .map(|root| {
+                root.canonicalize()
+                    .map_err(|e| format!("invalid data root {}: {e}", root.display()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        info!("files restricted to the data roots {roots:?}");
+        Ok(Self { roots: Some(roots) })
+    }
+
+    /// Fails unless every file `plan` registers or writes is inside the roots.
+    pub fn check_plan(&self, plan: &LogicalPlan) -> Result<(), AppErrors> {
+        match plan {
+            LogicalPlan::Ddl(DdlStatement::CreateExternalTable(table)) => {
+                self.check_location(&table.location)?
+            }
+            LogicalPlan::Copy(copy) => self.check_location(&copy.output_url)?,
+            _ => {}
+        }
+        plan.inputs()
+            .into_iter()
+            .try_for_each(|input| self.check_plan(input))
+    }
+
+    /// Fails unless `location`, a path or `file` URL possibly ending in a glob, is inside the
+    /// roots once symlinks are resolved, and so are the links in a directory it names.
+    pub fn check_location(&self, location: &str) -> Result<(), AppErrors> {
+        let Some(roots) = self.roots.as_ref() else {
+            return Ok(());
+        };
+        let path = local_path(location)?;
+        let resolved = resolve(&static_prefix(&path))?;
+        if !roots.iter().any(|root| resolved.starts_with(root)) {
+            return Err(outside(location));
+        }
+        if resolved.is_dir() {
+            self.check_links(&resolved, location)?;
+        }
+        Ok(())
+    }
+
+    /// Tables over a directory read the files below it, links among them must not lead out.
+    fn check_links(&self, dir: &Path, location: &str) -> Result<(), AppErrors> {
+        let roots = self.roots.as_deref().unwrap_or_default();
+        let entries =
+            std::fs::read_dir(dir).map_err(|e| format!("failed to list {}: {e}", dir.display()))?;
+        for entry in entries.flatten() {
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+            if file_type.is_symlink() {
+                // dangling links can't be read
+                let Ok(target) = entry.path().canonicalize() else {
+                    continue;
+                };
+                if !roots.iter().any(|root| target.starts_with(root)) {
+                    return Err(outside(location));
+                }
+            } else if file_type.is_dir() {
+                self.check_links(&entry.path(), location)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn outside(location: &str) -> AppErrors {
+    format!("permission denied: {location} is outside the data roots").into()
+}
+
+/// The local path of `location`, other stores are refused as they can't be confined.
+fn local_path(location: &str) -> Result<PathBuf, AppErrors> {
+    // plain paths are relative URLs and don't parse
+    let Ok(url) = Url::parse(location) else {
+        return Ok(PathBuf::from(location));
+    };
+    if url.scheme() != "file" {
+        return Err(format!(
+            "permission denied: {location} is not a local file, only the data roots may be used"
+        )
+        .into());
+    }
+    url.to_file_path()
+        .map_err(|_| format!("invalid file URL {location}").into())
+}
+
+/// `path` up to the first component with a glob pattern.
+fn static_prefix(path: &Path) -> PathBuf {
+    path.components()
+        .take_while(|c| !c.as_os_str().to_string_lossy().contains(GLOB_CHARS))
+        .collect()
+}
+
+/// The absolute `path` with symlinks resolved, the part that doesn't exist yet, as the target
+/// of a write, is appended to the existing one and can't step out with `..`.
+fn resolve(path: &Path) -> Result<PathBuf, AppErrors> {
+    let absolute = std::env::current_dir()
+        .map_err(|e| format!("failed to resolve {}: {e}", path.display()))?
+        .join(path);
+    let mut existing = absolute.as_path();
+    let mut missing = vec![];
+    loop {
+        if let Ok(canonical) = existing.canonicalize() {
+            return Ok(missing.into_iter().rev().fold(canonical, |p, c| p.join(c)));
+        }
+        match (existing.parent(), existing.components().next_back()) {
+            (Some(parent), Some(Component::Normal(name))) => {
+                missing.push(name);
+                existing = parent;
+            }
+            _ => return Err(format!("cannot resolve {}", path.display()).into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A fresh directory holding `root`, the data root, and `outside` next to it.
+    fn dirs(test: &str) -> (PathBuf, PathBuf) {
+        let base = std::env::temp_dir().join(format!("sandbox-{test}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&base);
+        let (root, outside) = (base.join("root"), base.join("outside"));
+        std::fs::create_dir_all(root.join("data")).unwrap();
+        std::fs::create_dir_all(&outside).unwrap();
+        std::fs::write(root.join("data/t.csv"), "a\n1\n").unwrap();
+        std::fs::write(outside.join("secret.csv"), "a\n1\n").unwrap();
+        (
+            root.canonicalize().unwrap(),
+            outside.canonicalize().unwrap(),
+        )
+    }
+
+    fn check(roots: &DataRoots, location: impl AsRef<Path>) -> Result<(), AppErrors> {
+        roots.check_location(&location.as_ref().to_string_lossy())
+    }
+
+    #[test]
+    fn any_location_without_roots() {
+        let roots = DataRoots::new(None).unwrap();
+        assert!(roots.check_location("/etc/passwd").is_ok());
+        assert!(roots.check_location("s3://bucket/t.parquet").is_ok());
+    }
+
+    #[test]
+    fn locations_inside_the_roots_only() {
+        let (root, outside) = dirs("inside");
+        let roots = DataRoots::new(Some(vec![root.clone()])).unwrap();
+        assert!(check(&roots, root.join("data/t.csv")).is_ok());
+        assert!(check(&roots, root.join("data")).is_ok());
+        assert!(check(&roots, root.join("data/*.csv")).is_ok());
+        assert!(roots
+            .check_location(&format!("file://{}", root.join("data/t.csv").display()))
+            .is_ok());
+        // written by COPY TO
+        assert!(check(&roots, root.join("out/result.csv")).is_ok());
+
+        assert!(check(&roots, outside.join("secret.csv")).is_err());
+        assert!(check(&roots, root.join("../outside/secret.csv")).is_err());
+        assert!(check(&roots, root.join("out/../../outside/new.csv")).is_err());
+        assert!(roots.check_location("s3://bucket/t.parquet").is_err());
+        let _ = std::fs::remove_dir_all(root.parent().unwrap());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn links_leading_out_of_the_roots() {
+        let (root, outside) = dirs("links");
+        let roots = DataRoots::new(Some(vec![root.clone()])).unwrap();
+        std::os::unix::fs::symlink(&outside, root.join("data/escape")).unwrap();
+        assert!(check(&roots, root.join("data/escape/secret.csv")).is_err());
+        // a table over the directory would read through the link
+        assert!(check(&roots, root.join("data")).is_err());
+        assert!(check(&roots, root.join("data/t.csv")).is_ok());
+        let _ = std::fs::remove_dir_all(root.parent().unwrap());
+    }
+}
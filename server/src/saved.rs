@@ -0,0 +1,212 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use parking_lot::RwLock;
+use prost::Message as _;
+use tokio::sync::mpsc::Sender;
+use tracing::{error, info};
+
+use proto_gen::saved::{
+    saved_service::Service, QueryList, SavedQuery, SavedQueryStore, SavedService,
+};
+
+use crate::access::Permission;
+use crate::errors::AppErrors;
+use crate::sessions::Role;
+use crate::state::ServerState;
+
+/// Fails unless the requester may change the saved queries of a context.
+type MayWrite<'a> = &'a dyn Fn(&str) -> Result<(), AppErrors>;
+
+/// Named queries shared by every client, written through to a file of the state directory.
+pub struct SavedQueries {
+    path: PathBuf,
+    queries: RwLock<BTreeMap<u64, SavedQuery>>,
+}
+
+impl SavedQueries {
+    /// Loads the queries saved in `path`, starting empty if it doesn't exist yet.
+    pub fn load(path: PathBuf) -> Self {
+        let queries = match std::fs::read(&path) {
+            Ok(bytes) => match SavedQueryStore::decode(bytes.as_slice()) {
+                Ok(store) => store.queries,
+                Err(e) => {
+                    error!("ignoring corrupt saved queries in {path:?}: {e}");
+                    vec![]
+                }
+            },
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => vec![],
+            Err(e) => {
+                error!("failed to read saved queries from {path:?}: {e}");
+                vec![]
+            }
+        };
+        info!("loaded {} saved queries from {path:?}", queries.len());
+        Self {
+            path,
+            queries: RwLock::new(queries.into_iter().map(|q| (q.id, q)).collect()),
+        }
+    }
+
+    /// The queries of the contexts `may_read` allows, sorted by name.
+    pub fn list(&self, may_read: &dyn Fn(&str) -> bool) -> Vec<SavedQuery> {
+        let mut queries = self
+            .queries
+            .read()
+            .values()
+            .filter(|q| may_read(&q.context))
+            .cloned()
+            .collect::<Vec<_>>();
+        queries.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+        queries
+    }
+
+    /// `may_write` checks the contexts of the query and of the one it updates.
+    pub fn save(&self, mut query: SavedQuery, may_write: MayWrite) -> Result<(), AppErrors> {
+        query.name = query.name.trim().to_string();
+        if query.name.is_empty() {
+            return Err("a saved query needs a name".into());
+        }
+        may_write(&query.context)?;
+        let mut queries = self.queries.write();
+        if queries
+            .values()
+            .any(|q| q.name == query.name && q.id != query.id)
+        {
+            return Err(format!("a query named {:?} already exists", query.name).into());
+        }
+        if query.id == 0 {
+            query.id = next_id(&queries);
+        } else {
+            let saved = queries
+                .get(&query.id)
+                .ok_or_else(|| format!("saved query {} doesn't exist", query.id))?;
+            may_write(&saved.context)?;
+        }
+        query.updated_at_ms = now_ms();
+        let mut updated = queries.clone();
+        updated.insert(query.id, query);
+        self.persist(&updated)?;
+        *queries = updated;
+        Ok(())
+    }
+
+    /// `may_write` checks the context of the query.
+    pub fn delete(&self, id: u64, may_write: MayWrite) -> Result<(), AppErrors> {
+        let mut queries = self.queries.write();
+        let saved = queries
+            .get(&id)
+            .ok_or_else(|| format!("saved query {id} doesn't exist"))?;
+        may_write(&saved.context)?;
+        let mut updated = queries.clone();
+        updated.remove(&id);
+        self.persist(&updated)?;
+        *queries = updated;
+        Ok(())
+    }
+
+    /// Adds `imported`, replacing saved queries of the same name. Nothing is imported unless
+    /// `may_write` allows the contexts of every query added or replaced.
+    pub fn import(&self, imported: Vec<SavedQuery>, may_write: MayWrite) -> Result<(), AppErrors> {
+        if imported.iter().any(|q| q.name.trim().is_empty()) {
+            return Err("every imported query needs a name".into());
+        }
+        let mut queries = self.queries.write();
+        for query in imported.iter() {
+            may_write(&query.context)?;
+            let name = query.name.trim();
+            if let Some(saved) = queries.values().find(|q| q.name == name) {
+                may_write(&saved.context)?;
+            }
+        }
+        let mut updated = queries.clone();
+        for mut query in imported {
+            query.name = query.name.trim().to_string();
+            query.id = updated
+                .values()
+                .find(|q| q.name == query.name)
+                .map(|q| q.id)
+                .unwrap_or_else(|| next_id(&updated));
+            query.updated_at_ms = now_ms();
+            updated.insert(query.id, query);
+        }
+        self.persist(&updated)?;
+        *queries = updated;
+        Ok(())
+    }
+
+    /// Writes `queries` before they replace the ones in memory, so a failed write changes
+    /// nothing.
+    fn persist(&self, queries: &BTreeMap<u64, SavedQuery>) -> Result<(), AppErrors> {
+        let path = &self.path;
+        let store = SavedQueryStore {
+            queries: queries.values().cloned().collect(),
+        };
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|e| format!("failed to create state directory {dir:?}: {e}"))?;
+        }
+        // write aside and rename, a crash mid-write must not lose the previous file
+        let tmp = path.with_extension("tmp");
+        std::fs::write(&tmp, store.encode_to_vec())
+            .map_err(|e| format!("failed to write saved queries to {tmp:?}: {e}"))?;
+        std::fs::rename(&tmp, path)
+            .map_err(|e| format!("failed to replace saved queries in {path:?}: {e}"))?;
+        Ok(())
+    }
+}
+
+fn next_id(queries: &BTreeMap<u64, SavedQuery>) -> u64 {
+    queries.keys().next_back().map(|id| id + 1).unwrap_or(1)
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+/// Applies a saved queries request and replies with the resulting list.
+pub(crate) async fn handle_saved(
+    state: Arc<ServerState>,
+    tx: Sender<Vec<u8>>,
+    role: Role,
+    service: Service,
+) -> Result<(), AppErrors> {
+    let saved = &state.saved_queries;
+    let may_read = |context: &str| {
+        state
+            .access
+            .require(role, context, Permission::Read)
+            .is_ok()
+    };
+    let may_write = |context: &str| state.access.require(role, context, Permission::Write);
+    let (request_id, result) = match service {
+        Service::ListMsg(m) => (m.request_id, Ok(())),
+        Service::SaveMsg(m) => (
+            m.request_id,
+            m.query
+                .ok_or_else(|| "no query to save".into())
+                .and_then(|q| saved.save(q, &may_write)),
+        ),
+        Service::DeleteMsg(m) => (m.request_id, saved.delete(m.id, &may_write)),
+        Service::ImportMsg(m) => (m.request_id, saved.import(m.queries, &may_write)),
+        Service::QueryListMsg(_) => return Err("unexpected query list from client".into()),
+    };
+
+    let msg = SavedService {
+        service: Some(Service::QueryListMsg(QueryList {
+            request_id,
+            queries: saved.list(&may_read),
+            error: result.err().map(|e| e.to_string()).unwrap_or_default(),
+        })),
+    }
+    .encode_to_vec();
+    tx.send(msg)
+        .await
+        .map_err(|e| format!("failed to queue reply, session closed: {e}"))?;
+    Ok(())
+}
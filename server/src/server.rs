@@ -1,62 +1,365 @@
 use std::net::SocketAddr;
+use std::sync::Arc;
 
 use futures_util::{SinkExt, StreamExt};
 use prost::Message as _;
 use tokio::io::AsyncWriteExt;
-use tokio::net::TcpStream;
+use tokio::sync::mpsc::channel;
 use tokio_tungstenite::{tungstenite::Message, WebSocketStream};
 use tracing::{error, info};
 
-use proto_gen::control::{Hello, hello_service::Service, HelloService};
+use proto_gen::admin::AdminService;
+use proto_gen::auth::AuthService;
+use proto_gen::config::{config_service, ConfigService};
+use proto_gen::control::{hello_service, Hello, HelloService};
+use proto_gen::logs::{log_service, LogService};
+use proto_gen::monitor::{monitor_service, MonitorService};
+use proto_gen::query::{query_service, QueryService};
+use proto_gen::saved::SavedService;
 
+use crate::admin::handle_admin;
 use crate::assets::{GeneratedAssets, StaticAssets};
+use crate::auth::{authenticate, identity_message, Identity};
+use crate::catalog::catalog_feed;
+use crate::completion::complete_sql;
+use crate::config::{acknowledge, set_config, SharedClientConfig};
 use crate::errors::AppErrors;
+use crate::history::get_history;
+use crate::logs::stream_logs;
+use crate::monitor::{inspect_query, kill_query, monitor_feed};
+use crate::query::execute_sql;
+use crate::saved::handle_saved;
+use crate::sessions::Role;
+use crate::state::ServerState;
+use crate::tables::register_table;
+use crate::tls::ClientStream;
+use crate::validation::validate_sql;
 
-pub(crate) async fn hello_service_handler(
-    mut stream: WebSocketStream<TcpStream>,
+pub(crate) async fn session_handler(
+    stream: WebSocketStream<ClientStream>,
     client_addr: SocketAddr,
+    state: Arc<ServerState>,
 ) -> Result<(), AppErrors> {
-    let hello = Hello {
-        from: "server".to_string(),
-        to: client_addr.to_string(),
+    let _connected = state.resources.connect();
+    let (mut sink, mut source) = stream.split();
+    let identity = match state.auth.as_ref() {
+        Some(auth) => {
+            match authenticate(auth, &state.access, &mut sink, &mut source, client_addr).await? {
+                Some(identity) => identity,
+                None => return Ok(()),
+            }
+        }
+        None => Identity {
+            user: String::new(),
+            role: Role::of_peer(&client_addr, state.loopback_admin),
+        },
+    };
+    let session = state.sessions.register(client_addr, identity);
+
+    // replies are produced by spawned query tasks as well, funnel them through one writer
+    let (tx, mut rx) = channel::<Vec<u8>>(64);
+    let counted = session.clone();
+    let writer = tokio::spawn(async move {
+        while let Some(msg) = rx.recv().await {
+            counted.add_out(msg.len());
+            if let Err(e) = sink.send(Message::binary(msg)).await {
+                error!("failed to send message, err: {e}");
+                break;
+            }
+        }
+    });
+
+    let hello = HelloService {
+        service: Some(hello_service::Service::HelloMsg(Hello {
+            from: "server".to_string(),
+            to: client_addr.to_string(),
+        })),
     }
     .encode_to_vec();
-
-    stream
-        .send(Message::binary(hello))
+    tx.send(hello)
         .await
         .map_err(|e| format!("failed to send message, err: {e}"))?;
+    if state.auth.is_none() {
+        // a login would have told the client
+        tx.send(identity_message(&session.identity(), &state.access))
+            .await
+            .map_err(|e| format!("failed to send message, err: {e}"))?;
+    }
 
-    while let Some(msg) = stream.next().await {
-        if let Ok(msg) = msg {
-            if msg.is_close() || msg.is_empty() || msg.is_ping() || msg.is_pong() {
+    let catalog = tokio::spawn(catalog_feed(state.catalog.subscribe(), tx.clone()));
+
+    let config = SharedClientConfig::default();
+    // the monitor feed of this session while subscribed, and the query it inspects
+    let mut feed = None;
+    let mut inspect = None;
+    // the log stream of this session while subscribed
+    let mut logs = None;
+    loop {
+        let msg = tokio::select! {
+            msg = source.next() => msg,
+            _ = session.kicked() => {
+                info!("disconnecting {client_addr} on admin request");
                 break;
             }
-            let hello_msg: HelloService = HelloService::decode(msg.into_data().as_slice())
-                .map_err(|e| format!("failed to decode msg as HelloService, decode err: {e:?}"))?;
-            let Some(e) = hello_msg.service else {
-                Err("no msg found")?
-            };
-            match e {
-                Service::HelloMsg(e) => {
+        };
+        let Some(msg) = msg else {
+            break;
+        };
+        let Ok(msg) = msg else {
+            error!("failed to receive message, err: {msg:?}");
+            continue;
+        };
+        if msg.is_close() || msg.is_empty() || msg.is_ping() || msg.is_pong() {
+            break;
+        }
+        let data = msg.into_data();
+        session.add_in(data.len());
+
+        if let Ok(HelloService {
+            service: Some(service),
+        }) = HelloService::decode(data.as_slice())
+        {
+            match service {
+                hello_service::Service::HelloMsg(e) => {
                     info!("hello msg recv: {e:?}");
                 }
-                Service::HelloReplyMsg(e) => {
+                hello_service::Service::HelloReplyMsg(e) => {
                     info!("hello reply recv: {e:?}");
                 }
             }
-        } else {
-            error!("failed to echo message, err: {msg:?}");
             continue;
         }
+
+        if let Ok(QueryService {
+            service: Some(service),
+        }) = QueryService::decode(data.as_slice())
+        {
+            match service {
+                query_service::Service::ExecuteMsg(req) => {
+                    session.set_context(&req.context);
+                    tokio::spawn(execute_sql(
+                        state.clone(),
+                        tx.clone(),
+                        client_addr,
+                        session.requester(),
+                        config.clone(),
+                        req,
+                    ));
+                }
+                query_service::Service::CompleteMsg(req) => {
+                    tokio::spawn(complete_sql(
+                        state.clone(),
+                        tx.clone(),
+                        session.requester().role,
+                        req,
+                    ));
+                }
+                query_service::Service::ValidateMsg(req) => {
+                    tokio::spawn(validate_sql(
+                        state.clone(),
+                        tx.clone(),
+                        session.requester().role,
+                        config.clone(),
+                        req,
+                    ));
+                }
+                query_service::Service::GetHistoryMsg(req) => {
+                    tokio::spawn(get_history(
+                        state.clone(),
+                        tx.clone(),
+                        session.requester().role,
+                        req,
+                    ));
+                }
+                query_service::Service::RegisterTableMsg(req) => {
+                    session.set_context(&req.context);
+                    tokio::spawn(register_table(
+                        state.clone(),
+                        tx.clone(),
+                        client_addr,
+                        session.requester(),
+                        req,
+                    ));
+                }
+                other => error!("unexpected query msg from {client_addr}: {other:?}"),
+            }
+            continue;
+        }
+
+        if let Ok(SavedService {
+            service: Some(service),
+        }) = SavedService::decode(data.as_slice())
+        {
+            tokio::spawn(handle_saved(
+                state.clone(),
+                tx.clone(),
+                session.requester().role,
+                service,
+            ));
+            continue;
+        }
+
+        if let Ok(ConfigService {
+            service: Some(service),
+        }) = ConfigService::decode(data.as_slice())
+        {
+            match service {
+                config_service::Service::SetConfigMsg(req) => {
+                    // applied right away, statements sent after it must see it
+                    let ack = set_config(&config, session.requester().role, req);
+                    tokio::spawn(acknowledge(tx.clone(), ack));
+                }
+                other => error!("unexpected config msg from {client_addr}: {other:?}"),
+            }
+            continue;
+        }
+
+        if let Ok(LogService {
+            service: Some(service),
+        }) = LogService::decode(data.as_slice())
+        {
+            match service {
+                log_service::Service::SubscribeMsg(req) => {
+                    let task = tokio::spawn(stream_logs(
+                        state.clone(),
+                        tx.clone(),
+                        session.clone(),
+                        config.clone(),
+                        req,
+                    ));
+                    if let Some(previous) = logs.replace(task) {
+                        previous.abort();
+                    }
+                }
+                log_service::Service::UnsubscribeMsg(_) => {
+                    if let Some(task) = logs.take() {
+                        task.abort();
+                    }
+                }
+                other => error!("unexpected log msg from {client_addr}: {other:?}"),
+            }
+            continue;
+        }
+
+        if let Ok(MonitorService {
+            service: Some(service),
+        }) = MonitorService::decode(data.as_slice())
+        {
+            match service {
+                monitor_service::Service::SubscribeMsg(_) => {
+                    let task = tokio::spawn(monitor_feed(
+                        state.clone(),
+                        tx.clone(),
+                        session.clone(),
+                        config.clone(),
+                    ));
+                    if let Some(previous) = feed.replace(task) {
+                        previous.abort();
+                    }
+                }
+                monitor_service::Service::UnsubscribeMsg(_) => {
+                    if let Some(task) = feed.take() {
+                        task.abort();
+                    }
+                }
+                monitor_service::Service::InspectMsg(req) => {
+                    let task = tokio::spawn(inspect_query(
+                        state.clone(),
+                        tx.clone(),
+                        session.clone(),
+                        config.clone(),
+                        req,
+                    ));
+                    if let Some(previous) = inspect.replace(task) {
+                        previous.abort();
+                    }
+                }
+                monitor_service::Service::StopInspectMsg(_) => {
+                    if let Some(task) = inspect.take() {
+                        task.abort();
+                    }
+                }
+                monitor_service::Service::KillMsg(req) => {
+                    tokio::spawn(kill_query(state.clone(), tx.clone(), session.clone(), req));
+                }
+                other => error!("unexpected monitor msg from {client_addr}: {other:?}"),
+            }
+            continue;
+        }
+
+        if let Ok(AuthService { service: Some(_) }) = AuthService::decode(data.as_slice()) {
+            let reply = identity_message(&session.identity(), &state.access);
+            tx.send(reply)
+                .await
+                .map_err(|e| format!("failed to send message, err: {e}"))?;
+            continue;
+        }
+
+        if let Ok(AdminService {
+            service: Some(service),
+        }) = AdminService::decode(data.as_slice())
+        {
+            tokio::spawn(handle_admin(
+                state.clone(),
+                tx.clone(),
+                session.clone(),
+                service,
+            ));
+            continue;
+        }
+
+        error!("no service matched msg from {client_addr}, ignoring...");
     }
 
+    for task in [feed, inspect, logs].into_iter().flatten() {
+        task.abort();
+    }
+    catalog.abort();
+    writer.abort();
     Ok(())
 }
 
+/// Writes a complete response with `body`, `status` is the code followed by its reason and
+/// `headers` go along with the content headers.
+pub async fn http_respond(
+    stream: &mut ClientStream,
+    status: &str,
+    content_type: &str,
+    headers: &[(&str, String)],
+    body: &[u8],
+) -> Result<(), AppErrors> {
+    let mut header = format!("HTTP/1.1 {status}\r\n");
+    for (name, value) in headers {
+        header.push_str(&format!("{name}: {value}\r\n"));
+    }
+    header.push_str(&format!(
+        "Content-Type: {content_type}\r\nContent-Length: {}\r\n\r\n",
+        body.len()
+    ));
+    stream
+        .write_all(header.as_bytes())
+        .await
+        .map_err(|e| format!("failed to write header: {e}"))?;
+    stream
+        .write_all(body)
+        .await
+        .map_err(|e| format!("failed to write body: {e}"))?;
+    Ok(())
+}
+
+/// The value of the header `name` among the `lines` of a request.
+pub fn http_header<'a>(lines: &'a [String], name: &str) -> Option<&'a str> {
+    lines.iter().skip(1).find_map(|line| {
+        let (key, value) = line.split_once(':')?;
+        key.trim()
+            .eq_ignore_ascii_case(name)
+            .then_some(value.trim())
+    })
+}
+
 pub async fn http_serve_file(
     rel_path: impl AsRef<str>,
-    stream: &mut TcpStream,
+    stream: &mut ClientStream,
 ) -> Result<(), AppErrors> {
     let rel_path = rel_path.as_ref();
     let header;
@@ -0,0 +1,57 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use tracing::info;
+
+use crate::access::Permission;
+use crate::audit::AuditConfig;
+use crate::auth::AuthConfig;
+use crate::errors::AppErrors;
+use crate::limits::LimitsConfig;
+use crate::sessions::Role;
+use crate::tls::TlsConfig;
+
+const DEFAULT_CONFIG_FILE: &str = "console.json";
+
+/// Settings of the server read once at startup, every one has a default.
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ServerConfig {
+    /// pages that may connect, as `scheme://host[:port]`, `*` for any; pages of the server
+    /// itself only need listing when it is reached by name rather than by IP or as localhost
+    pub allowed_origins: Vec<String>,
+    /// where every statement executed is recorded
+    pub audit: AuditConfig,
+    /// clients must log in when set
+    pub auth: Option<AuthConfig>,
+    /// directories tables may be read from and `COPY TO` may write to, any when unset
+    pub data_roots: Option<Vec<PathBuf>>,
+    /// quotas of users and sessions, and how many queries run at once
+    pub limits: LimitsConfig,
+    /// without auth, clients on the server host get the admin role instead of analyst
+    pub loopback_admin: bool,
+    /// by context name and role, replacing the defaults of the role in that context
+    pub permissions: BTreeMap<String, BTreeMap<Role, Vec<Permission>>>,
+    /// serve https and wss instead of http and ws when set, the certificate is reloaded on
+    /// SIGHUP
+    pub tls: Option<TlsConfig>,
+}
+
+/// Reads the JSON file named by `CONSOLE_CONFIG`, or `console.json` if it exists.
+pub fn load_config() -> Result<ServerConfig, AppErrors> {
+    let (path, required) = match std::env::var_os("CONSOLE_CONFIG") {
+        Some(path) => (PathBuf::from(path), true),
+        None => (PathBuf::from(DEFAULT_CONFIG_FILE), false),
+    };
+    if !required && !path.exists() {
+        info!("no {DEFAULT_CONFIG_FILE}, using the default settings");
+        return Ok(ServerConfig::default());
+    }
+    let text = std::fs::read_to_string(&path)
+        .map_err(|e| format!("failed to read config {}: {e}", path.display()))?;
+    let config = serde_json::from_str(&text)
+        .map_err(|e| format!("invalid config {}: {e}", path.display()))?;
+    info!("settings loaded from {}", path.display());
+    Ok(config)
+}
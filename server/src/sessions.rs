@@ -0,0 +1,231 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::net::SocketAddr;
+use std::ops::Deref;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use parking_lot::RwLock;
+use serde::Deserialize;
+use tokio::sync::Notify;
+
+use proto_gen::admin::{ActiveQuery, SessionInfo};
+
+use crate::access::Permission;
+use crate::auth::Identity;
+use crate::errors::AppErrors;
+use crate::limits::Requester;
+
+/// What a session may do, see [`Role::default_permissions`], admin RPCs need [`Role::Admin`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    Viewer,
+    Analyst,
+    Admin,
+}
+
+impl Role {
+    /// Without authentication, clients are analysts, except those on the server host when
+    /// `loopback_admin` lets them administer it.
+    pub fn of_peer(peer: &SocketAddr, loopback_admin: bool) -> Self {
+        if loopback_admin && peer.ip().is_loopback() {
+            Role::Admin
+        } else {
+            Role::Analyst
+        }
+    }
+
+    /// As written in the config file.
+    pub fn name(self) -> &'static str {
+        match self {
+            Role::Viewer => "viewer",
+            Role::Analyst => "analyst",
+            Role::Admin => "admin",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "viewer" => Some(Role::Viewer),
+            "analyst" => Some(Role::Analyst),
+            "admin" => Some(Role::Admin),
+            _ => None,
+        }
+    }
+
+    /// In contexts the config gives no permissions of their own for the role.
+    pub fn default_permissions(self) -> BTreeSet<Permission> {
+        let permissions = match self {
+            Role::Viewer => &[Permission::Read][..],
+            Role::Analyst => &[Permission::Read, Permission::Ddl, Permission::Write],
+            Role::Admin => &[
+                Permission::Read,
+                Permission::Ddl,
+                Permission::Write,
+                Permission::Admin,
+            ],
+        };
+        permissions.iter().copied().collect()
+    }
+}
+
+/// Every connected websocket client.
+#[derive(Default)]
+pub struct SessionRegistry {
+    next_id: AtomicU64,
+    sessions: RwLock<BTreeMap<u64, Arc<Session>>>,
+}
+
+impl SessionRegistry {
+    /// Adds a session for `peer`, it is removed when the returned guard is dropped.
+    pub fn register(&self, peer: SocketAddr, identity: Identity) -> SessionGuard<'_> {
+        let session = Arc::new(Session {
+            id: self.next_id.fetch_add(1, Ordering::Relaxed) + 1,
+            peer,
+            connected_at_ms: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_millis() as u64)
+                .unwrap_or_default(),
+            user: identity.user,
+            role: identity.role,
+            context: RwLock::new(String::new()),
+            bytes_in: AtomicU64::new(0),
+            bytes_out: AtomicU64::new(0),
+            kicked: Notify::new(),
+        });
+        self.sessions.write().insert(session.id, session.clone());
+        SessionGuard {
+            registry: self,
+            session,
+        }
+    }
+
+    /// Oldest first.
+    pub fn list(&self) -> Vec<Arc<Session>> {
+        self.sessions.read().values().cloned().collect()
+    }
+
+    pub fn disconnect(&self, id: u64) -> Result<(), AppErrors> {
+        let session = self
+            .sessions
+            .read()
+            .get(&id)
+            .cloned()
+            .ok_or_else(|| format!("session {id} is not connected"))?;
+        // a permit is kept if the session doesn't wait yet
+        session.kicked.notify_one();
+        Ok(())
+    }
+}
+
+pub struct SessionGuard<'a> {
+    registry: &'a SessionRegistry,
+    session: Arc<Session>,
+}
+
+impl Deref for SessionGuard<'_> {
+    type Target = Arc<Session>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.session
+    }
+}
+
+impl Drop for SessionGuard<'_> {
+    fn drop(&mut self) {
+        self.registry.sessions.write().remove(&self.session.id);
+    }
+}
+
+pub struct Session {
+    id: u64,
+    peer: SocketAddr,
+    connected_at_ms: u64,
+    /// empty without authentication
+    user: String,
+    role: Role,
+    /// of the last statements run
+    context: RwLock<String>,
+    bytes_in: AtomicU64,
+    bytes_out: AtomicU64,
+    kicked: Notify,
+}
+
+impl Session {
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn peer(&self) -> SocketAddr {
+        self.peer
+    }
+
+    pub fn role(&self) -> Role {
+        self.role
+    }
+
+    pub fn identity(&self) -> Identity {
+        Identity {
+            user: self.user.clone(),
+            role: self.role,
+        }
+    }
+
+    /// Whom the quotas of the queries of this session count against.
+    pub fn requester(&self) -> Requester {
+        Requester {
+            user: if self.user.is_empty() {
+                self.peer.ip().to_string()
+            } else {
+                self.user.clone()
+            },
+            session_id: self.id,
+            role: self.role,
+        }
+    }
+
+    /// Fails unless the session has the admin role, `action` names what was refused.
+    pub fn require_admin(&self, action: &str) -> Result<(), AppErrors> {
+        if self.role == Role::Admin {
+            return Ok(());
+        }
+        let who = if self.user.is_empty() {
+            self.peer.to_string()
+        } else {
+            self.user.clone()
+        };
+        Err(format!("{action} needs the admin role, {who} does not have it").into())
+    }
+
+    pub fn set_context(&self, context: &str) {
+        *self.context.write() = context.to_string();
+    }
+
+    pub fn add_in(&self, bytes: usize) {
+        self.bytes_in.fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+
+    pub fn add_out(&self, bytes: usize) {
+        self.bytes_out.fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+
+    /// Resolves once an admin disconnects the session.
+    pub async fn kicked(&self) {
+        self.kicked.notified().await
+    }
+
+    pub fn info(&self, queries: Vec<ActiveQuery>) -> SessionInfo {
+        SessionInfo {
+            id: self.id,
+            peer_addr: self.peer.to_string(),
+            connected_at_ms: self.connected_at_ms,
+            context: self.context.read().clone(),
+            queries,
+            bytes_in: self.bytes_in.load(Ordering::Relaxed),
+            bytes_out: self.bytes_out.load(Ordering::Relaxed),
+            admin: self.role == Role::Admin,
+            user: self.user.clone(),
+        }
+    }
+}
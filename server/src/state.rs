@@ -0,0 +1,96 @@
+use std::path::PathBuf;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+use tracing::warn;
+
+use crate::access::AccessControl;
+use crate::audit::AuditLog;
+use crate::auth::Authenticator;
+use crate::catalog::CatalogStore;
+use crate::context::ContextRegistry;
+use crate::errors::AppErrors;
+use crate::history::HistoryRegistry;
+use crate::limits::Limits;
+use crate::logs::{LogBuffer, LogFilterControl};
+use crate::metrics::QueryMetrics;
+use crate::monitor::QueryRegistry;
+use crate::resources::ResourceMonitor;
+use crate::sandbox::DataRoots;
+use crate::saved::SavedQueries;
+use crate::server_config::ServerConfig;
+use crate::sessions::SessionRegistry;
+
+/// State shared by all sessions, cloned into every connection as an `Arc`.
+pub struct ServerState {
+    pub access: AccessControl,
+    pub audit: AuditLog,
+    /// clients must log in when set
+    pub auth: Option<Authenticator>,
+    pub catalog: CatalogStore,
+    pub contexts: ContextRegistry,
+    pub data_roots: DataRoots,
+    pub history: HistoryRegistry,
+    pub limits: Limits,
+    pub logs: Arc<LogBuffer>,
+    pub log_filter: LogFilterControl,
+    /// see [`ServerConfig::loopback_admin`]
+    pub loopback_admin: bool,
+    pub metrics: QueryMetrics,
+    pub queries: QueryRegistry,
+    pub resources: ResourceMonitor,
+    pub saved_queries: SavedQueries,
+    pub sessions: SessionRegistry,
+    /// set once the catalog of earlier runs is restored
+    pub ready: AtomicBool,
+}
+
+impl ServerState {
+    /// Loads what earlier runs left in `state_dir`, `logs` and `log_filter` belong to the
+    /// tracing subscriber. Fails if a setting of `config` is invalid.
+    pub fn load(
+        state_dir: PathBuf,
+        logs: Arc<LogBuffer>,
+        log_filter: LogFilterControl,
+        config: ServerConfig,
+    ) -> Result<Self, AppErrors> {
+        Ok(Self {
+            access: AccessControl::new(config.permissions)?,
+            audit: AuditLog::new(config.audit, &state_dir)?,
+            auth: config.auth.map(Authenticator::new).transpose()?,
+            catalog: CatalogStore::load(state_dir.join("catalog.json"))?,
+            contexts: ContextRegistry::with_memory_limit(memory_limit()),
+            data_roots: DataRoots::new(config.data_roots)?,
+            history: Default::default(),
+            limits: Limits::new(config.limits)?,
+            logs,
+            log_filter,
+            loopback_admin: config.loopback_admin,
+            metrics: Default::default(),
+            queries: Default::default(),
+            resources: Default::default(),
+            saved_queries: SavedQueries::load(state_dir.join("saved_queries.pb")),
+            sessions: Default::default(),
+            ready: AtomicBool::new(false),
+        })
+    }
+}
+
+/// Memory each context may use for query execution, `CONSOLE_MEMORY_LIMIT_MB` or unbounded.
+fn memory_limit() -> Option<usize> {
+    let mb = std::env::var("CONSOLE_MEMORY_LIMIT_MB").ok()?;
+    match mb.trim().parse::<usize>() {
+        Ok(mb) => Some(mb * 1024 * 1024),
+        Err(e) => {
+            warn!("ignoring CONSOLE_MEMORY_LIMIT_MB={mb:?}: {e}");
+            None
+        }
+    }
+}
+
+/// Where the server keeps data across restarts, `CONSOLE_STATE_DIR` or `./state`.
+pub fn state_dir() -> PathBuf {
+    std::env::var_os("CONSOLE_STATE_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("state"))
+}
@@ -0,0 +1,484 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use datafusion::arrow::datatypes::DataType;
+use datafusion::datasource::file_format::arrow::ArrowFormat;
+use datafusion::datasource::file_format::avro::AvroFormat;
+use datafusion::datasource::file_format::csv::CsvFormat;
+use datafusion::datasource::file_format::json::JsonFormat;
+use datafusion::datasource::file_format::parquet::ParquetFormat;
+use datafusion::datasource::file_format::FileFormat;
+use datafusion::datasource::listing::{
+    ListingOptions, ListingTable, ListingTableConfig, ListingTableUrl,
+};
+use datafusion::datasource::physical_plan::{
+    ArrowExec, AvroExec, CsvExec, FileScanConfig, NdJsonExec, ParquetExec,
+};
+use datafusion::datasource::view::ViewTable;
+use datafusion::datasource::{source_as_provider, TableProvider};
+use datafusion::logical_expr::LogicalPlan;
+use datafusion::physical_plan::ExecutionPlan;
+use datafusion::prelude::{col, SessionContext};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc::Sender;
+use tracing::info;
+
+use proto_gen::query::{query_service::Service, RegisterTable, TableRegistered, TableScan};
+
+use crate::access::Permission;
+use crate::audit::{AuditRecord, Outcome};
+use crate::context::context_name;
+use crate::errors::AppErrors;
+use crate::limits::Requester;
+use crate::query::reply;
+use crate::sessions::Role;
+use crate::state::ServerState;
+
+/// A table over the files below a directory, hive partitioned by the directory levels named
+/// `column=value`.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct TableSpec {
+    pub name: String,
+    pub location: String,
+    pub file_type: String,
+    /// name and Arrow type, in the order of the directory levels
+    pub partition_columns: Vec<(String, String)>,
+    /// "." and the file type when empty
+    pub file_extension: String,
+    pub sort_order: Vec<SortSpec>,
+    pub has_header: bool,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SortSpec {
+    pub name: String,
+    pub descending: bool,
+    pub nulls_first: bool,
+}
+
+impl From<&RegisterTable> for TableSpec {
+    fn from(req: &RegisterTable) -> Self {
+        TableSpec {
+            name: req.name.trim().to_string(),
+            location: req.location.trim().to_string(),
+            file_type: req.file_type.to_lowercase(),
+            partition_columns: req
+                .partition_columns
+                .iter()
+                .map(|c| (c.name.trim().to_string(), c.data_type.trim().to_string()))
+                .collect(),
+            file_extension: req.file_extension.trim().to_string(),
+            sort_order: req
+                .sort_order
+                .iter()
+                .map(|s| SortSpec {
+                    name: s.name.trim().to_string(),
+                    descending: s.descending,
+                    nulls_first: s.nulls_first,
+                })
+                .collect(),
+            has_header: req.has_header,
+        }
+    }
+}
+
+impl TableSpec {
+    fn extension(&self) -> String {
+        match self.file_extension.as_str() {
+            "" => format!(".{}", self.file_type),
+            extension => extension.to_string(),
+        }
+    }
+
+    fn url(&self) -> Result<ListingTableUrl, AppErrors> {
+        ListingTableUrl::parse(&self.location)
+            .map_err(|e| format!("invalid location {}: {e}", self.location).into())
+    }
+
+    /// Registers the table in `ctx`, replacing one of the same name. The columns of the files
+    /// are inferred from them.
+    pub async fn register(&self, ctx: &SessionContext) -> Result<(), AppErrors> {
+        if self.name.is_empty() {
+            return Err("a table needs a name".into());
+        }
+        let url = self.url()?;
+        let format: Arc<dyn FileFormat> = match self.file_type.as_str() {
+            "parquet" => Arc::new(ParquetFormat::default()),
+            "csv" => Arc::new(CsvFormat::default().with_has_header(self.has_header)),
+            "json" => Arc::new(JsonFormat::default()),
+            "avro" => Arc::new(AvroFormat),
+            "arrow" => Arc::new(ArrowFormat),
+            other => return Err(format!("unsupported file type {other:?}").into()),
+        };
+        let partition_cols = self
+            .partition_columns
+            .iter()
+            .map(|(name, data_type)| {
+                let data_type = data_type
+                    .parse::<DataType>()
+                    .map_err(|e| format!("invalid type of partition column {name}: {e}"))?;
+                Ok((name.clone(), data_type))
+            })
+            .collect::<Result<Vec<_>, AppErrors>>()?;
+        let sort_order = self
+            .sort_order
+            .iter()
+            .map(|s| col(&s.name).sort(!s.descending, s.nulls_first))
+            .collect::<Vec<_>>();
+        let mut options = ListingOptions::new(format)
+            .with_file_extension(self.extension())
+            .with_table_partition_cols(partition_cols);
+        if !sort_order.is_empty() {
+            options = options.with_file_sort_order(vec![sort_order]);
+        }
+        let schema = options
+            .infer_schema(&ctx.state(), &url)
+            .await
+            .map_err(|e| format!("failed to read the schema of {}: {e}", self.location))?;
+        let config = ListingTableConfig::new(url)
+            .with_listing_options(options)
+            .with_schema(schema);
+        let table = ListingTable::try_new(config).map_err(|e| e.to_string())?;
+        ctx.register_table(self.name.as_str(), Arc::new(table))
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    /// Files and partitions below the location, found again and kept for the scans of `ctx`.
+    async fn layout(&self, ctx: &SessionContext) -> Result<Layout, AppErrors> {
+        let key = LayoutKey {
+            url: self.url()?,
+            extension: self.extension(),
+            columns: self
+                .partition_columns
+                .iter()
+                .map(|(name, _)| name.clone())
+                .collect(),
+        };
+        let layout = key.clone().walk().await;
+        if let Some(layouts) = TableLayouts::of(ctx) {
+            layouts.0.lock().insert(key, layout);
+        }
+        Ok(layout)
+    }
+}
+
+/// The layouts of the tables of a context, found when a table is registered or first scanned
+/// and kept as listing large directories takes a while. Registering a table again refreshes
+/// them.
+#[derive(Default)]
+pub struct TableLayouts(Mutex<HashMap<LayoutKey, Layout>>);
+
+impl TableLayouts {
+    /// Those kept in `ctx`, contexts not made by the registry keep none.
+    fn of(ctx: &SessionContext) -> Option<Arc<Self>> {
+        ctx.state().config().get_extension::<Self>()
+    }
+
+    async fn get(layouts: Option<&Self>, key: LayoutKey) -> Layout {
+        if let Some(layout) = layouts.and_then(|l| l.0.lock().get(&key).copied()) {
+            return layout;
+        }
+        let layout = key.clone().walk().await;
+        if let Some(layouts) = layouts {
+            layouts.0.lock().insert(key, layout);
+        }
+        layout
+    }
+}
+
+/// What the layout of a table depends on.
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct LayoutKey {
+    url: ListingTableUrl,
+    extension: String,
+    columns: Vec<String>,
+}
+
+impl LayoutKey {
+    async fn walk(self) -> Layout {
+        Layout::walk(self.url, self.extension, self.columns).await
+    }
+}
+
+/// Registers the table `req` describes and replies with what was found below its location.
+/// The attempt is audited like a statement.
+pub(crate) async fn register_table(
+    state: Arc<ServerState>,
+    tx: Sender<Vec<u8>>,
+    client_addr: SocketAddr,
+    requester: Requester,
+    req: RegisterTable,
+) -> Result<(), AppErrors> {
+    let started_at_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default();
+    let started = Instant::now();
+    let mut reply_msg = TableRegistered {
+        request_id: req.request_id,
+        ..Default::default()
+    };
+    let outcome = match register(&state, requester.role, &req).await {
+        Ok(layout) => {
+            reply_msg.files = layout.files;
+            reply_msg.partitions = layout.partitions;
+            Outcome::Succeeded
+        }
+        Err(e) => {
+            reply_msg.error = e.to_string();
+            Outcome::Failed
+        }
+    };
+    state.audit.record(&AuditRecord {
+        timestamp_ms: started_at_ms,
+        user: requester.user,
+        peer_addr: client_addr.to_string(),
+        context: context_name(&req.context).to_string(),
+        sql: format!(
+            "REGISTER TABLE {} AS {} AT '{}'",
+            req.name.trim(),
+            req.file_type,
+            req.location.trim()
+        ),
+        plan_hash: String::new(),
+        outcome,
+        rows: reply_msg.files,
+        duration_ms: started.elapsed().as_millis() as u64,
+        error: reply_msg.error.clone(),
+    });
+    reply(&tx, Service::TableRegisteredMsg(reply_msg)).await
+}
+
+async fn register(
+    state: &ServerState,
+    role: Role,
+    req: &RegisterTable,
+) -> Result<Layout, AppErrors> {
+    let spec = TableSpec::from(req);
+    state.access.require(role, &req.context, Permission::Ddl)?;
+    state.data_roots.check_location(&spec.location)?;
+    let ctx = state.contexts.get_or_create(&req.context);
+    let exists = ctx
+        .table_exist(spec.name.as_str())
+        .map_err(|e| e.to_string())?;
+    if exists && !req.replace {
+        return Err(format!("table {} already exists", spec.name).into());
+    }
+    spec.register(&ctx).await?;
+    let layout = spec.layout(&ctx).await?;
+    info!(
+        "registered table {} over {} in context {}: {} files in {} partitions",
+        spec.name,
+        spec.location,
+        context_name(&req.context),
+        layout.files,
+        layout.partitions
+    );
+    state.catalog.record_table(&req.context, spec);
+    Ok(layout)
+}
+
+/// The tables over directories a statement reads, by name.
+#[derive(Default)]
+pub struct ListingTables(BTreeMap<String, Arc<dyn TableProvider>>);
+
+impl ListingTables {
+    /// Those of `plan` and of the views it reads.
+    pub fn of(plan: &LogicalPlan) -> Self {
+        let mut tables = Self::default();
+        tables.add(plan);
+        tables
+    }
+
+    fn add(&mut self, plan: &LogicalPlan) {
+        if let LogicalPlan::TableScan(scan) = plan {
+            if let Ok(provider) = source_as_provider(&scan.source) {
+                if let Some(view) = provider.as_any().downcast_ref::<ViewTable>() {
+                    self.add(view.logical_plan());
+                } else if provider.as_any().is::<ListingTable>() {
+                    self.0.insert(scan.table_name.to_string(), provider);
+                }
+            }
+        }
+        for input in plan.inputs() {
+            self.add(input);
+        }
+    }
+
+    /// The files `plan`, planned from the statement in `ctx`, reads of each table, and the
+    /// totals below their locations kept in `ctx`.
+    pub async fn scans(&self, ctx: &SessionContext, plan: &dyn ExecutionPlan) -> Vec<TableScan> {
+        if self.0.is_empty() {
+            return vec![];
+        }
+        let mut files = BTreeSet::new();
+        scanned_files(plan, &mut files);
+        let layouts = TableLayouts::of(ctx);
+        let mut scans = vec![];
+        for (name, provider) in self.0.iter() {
+            if let Some(table) = provider.as_any().downcast_ref::<ListingTable>() {
+                scans.push(scan_of(name, table, &files, layouts.as_deref()).await);
+            }
+        }
+        scans
+    }
+}
+
+async fn scan_of(
+    name: &str,
+    table: &ListingTable,
+    files: &BTreeSet<String>,
+    layouts: Option<&TableLayouts>,
+) -> TableScan {
+    let options = table.options();
+    let columns = options
+        .table_partition_cols
+        .iter()
+        .map(|(name, _)| name.clone())
+        .collect::<Vec<_>>();
+    let mut scan = TableScan {
+        table: name.to_string(),
+        ..Default::default()
+    };
+    let mut partitions = BTreeSet::new();
+    for url in table.table_paths() {
+        let prefix = url.prefix().as_ref();
+        for file in files.iter().filter(|file| is_below(file, prefix)) {
+            scan.files_scanned += 1;
+            partitions.insert(file.rsplit_once('/').map(|(dir, _)| dir));
+        }
+        let key = LayoutKey {
+            url: url.clone(),
+            extension: options.file_extension.clone(),
+            columns: columns.clone(),
+        };
+        let layout = TableLayouts::get(layouts, key).await;
+        scan.files_total += layout.files;
+        scan.partitions_total += layout.partitions;
+    }
+    if !columns.is_empty() {
+        scan.partitions_scanned = partitions.len() as u64;
+    }
+    scan
+}
+
+/// Adds the paths of the files read by the scans of `plan` to `files`.
+fn scanned_files(plan: &dyn ExecutionPlan, files: &mut BTreeSet<String>) {
+    let any = plan.as_any();
+    let config: Option<&FileScanConfig> = any
+        .downcast_ref::<ParquetExec>()
+        .map(ParquetExec::base_config)
+        .or_else(|| any.downcast_ref::<CsvExec>().map(CsvExec::base_config))
+        .or_else(|| {
+            any.downcast_ref::<NdJsonExec>()
+                .map(NdJsonExec::base_config)
+        })
+        .or_else(|| any.downcast_ref::<AvroExec>().map(AvroExec::base_config))
+        .or_else(|| any.downcast_ref::<ArrowExec>().map(ArrowExec::base_config));
+    if let Some(config) = config {
+        // large files are split in ranges read apart
+        files.extend(
+            config
+                .file_groups
+                .iter()
+                .flatten()
+                .map(|f| f.object_meta.location.to_string()),
+        );
+    }
+    for child in plan.children() {
+        scanned_files(child.as_ref(), files);
+    }
+}
+
+/// Whether the store path `file` is `prefix` or below it.
+fn is_below(file: &str, prefix: &str) -> bool {
+    file == prefix
+        || prefix.is_empty()
+        || file
+            .strip_prefix(prefix)
+            .is_some_and(|rest| rest.starts_with('/'))
+}
+
+/// The files of a table found below its location.
+#[derive(Clone, Copy, Default)]
+struct Layout {
+    files: u64,
+    /// directories the files are in, 0 without partition columns
+    partitions: u64,
+}
+
+impl Layout {
+    /// [`Layout::of`] on a blocking thread, listing large directories takes a while.
+    async fn walk(url: ListingTableUrl, extension: String, columns: Vec<String>) -> Self {
+        tokio::task::spawn_blocking(move || {
+            let columns = columns.iter().map(String::as_str).collect::<Vec<_>>();
+            Self::of(&url, &extension, &columns)
+        })
+        .await
+        .unwrap_or_default()
+    }
+
+    /// Counts the files ending with `extension` in directories named after `columns`, local
+    /// locations only. Symbolic links to directories are not followed.
+    fn of(url: &ListingTableUrl, extension: &str, columns: &[&str]) -> Self {
+        if url.scheme() != "file" {
+            return Self::default();
+        }
+        let root = Path::new("/").join(url.prefix().as_ref());
+        if root.is_file() {
+            return Self {
+                files: 1,
+                partitions: 0,
+            };
+        }
+        let mut files = 0;
+        let mut partitions = BTreeSet::new();
+        let mut dirs = vec![(root, String::new())];
+        while let Some((dir, relative)) = dirs.pop() {
+            let Ok(entries) = std::fs::read_dir(&dir) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let name = entry.file_name().to_string_lossy().to_string();
+                let path = entry.path();
+                let Ok(metadata) = std::fs::symlink_metadata(&path) else {
+                    continue;
+                };
+                if metadata.is_dir() {
+                    let child = match relative.as_str() {
+                        "" => name,
+                        relative => format!("{relative}/{name}"),
+                    };
+                    dirs.push((path, child));
+                } else if name.ends_with(extension) && is_partition(&relative, columns) {
+                    files += 1;
+                    partitions.insert(relative.clone());
+                }
+            }
+        }
+        if columns.is_empty() {
+            partitions.clear();
+        }
+        Self {
+            files,
+            partitions: partitions.len() as u64,
+        }
+    }
+}
+
+/// Whether the directory levels of `relative` are `column=value` of each of `columns` in turn,
+/// the files of other directories aren't part of the table.
+fn is_partition(relative: &str, columns: &[&str]) -> bool {
+    let levels = relative.split('/').filter(|level| !level.is_empty());
+    levels.clone().count() == columns.len()
+        && levels.zip(columns).all(|(level, column)| {
+            level
+                .strip_prefix(column)
+                .is_some_and(|value| value.starts_with('='))
+        })
+}
@@ -0,0 +1,197 @@
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use parking_lot::RwLock;
+use serde::Deserialize;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, ReadBuf};
+use tokio::net::TcpStream;
+use tokio_rustls::rustls::ServerConfig as RustlsConfig;
+use tokio_rustls::server::TlsStream;
+use tokio_rustls::TlsAcceptor;
+use tracing::{error, info};
+
+use crate::errors::AppErrors;
+
+/// A client must finish the handshake within this time.
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The `tls` section of the config file, both files in the PEM format.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TlsConfig {
+    /// certificate chain, the server certificate first
+    cert: PathBuf,
+    key: PathBuf,
+}
+
+/// Encrypts every accepted connection with the certificate last loaded.
+pub struct TlsTerminator {
+    config: TlsConfig,
+    acceptor: RwLock<TlsAcceptor>,
+}
+
+impl TlsTerminator {
+    pub fn new(config: TlsConfig) -> Result<Self, AppErrors> {
+        let acceptor = load(&config)?;
+        Ok(Self {
+            config,
+            acceptor: RwLock::new(acceptor),
+        })
+    }
+
+    /// Reads the files again, connections keep the certificate they were accepted with and the
+    /// current one stays in use if the new one is invalid.
+    pub fn reload(&self) -> Result<(), AppErrors> {
+        *self.acceptor.write() = load(&self.config)?;
+        Ok(())
+    }
+
+    pub async fn accept(&self, stream: TcpStream) -> Result<ClientStream, AppErrors> {
+        let acceptor = self.acceptor.read().clone();
+        let stream = tokio::time::timeout(HANDSHAKE_TIMEOUT, acceptor.accept(stream))
+            .await
+            .map_err(|_| "TLS handshake timed out")?
+            .map_err(|e| format!("TLS handshake failed: {e}"))?;
+        Ok(ClientStream::new(Transport::Tls(Box::new(stream))))
+    }
+}
+
+fn load(config: &TlsConfig) -> Result<TlsAcceptor, AppErrors> {
+    let open = |path: &PathBuf| {
+        File::open(path)
+            .map(BufReader::new)
+            .map_err(|e| format!("failed to open {}: {e}", path.display()))
+    };
+    let certs = rustls_pemfile::certs(&mut open(&config.cert)?)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("invalid certificate {}: {e}", config.cert.display()))?;
+    if certs.is_empty() {
+        return Err(format!("no certificate in {}", config.cert.display()).into());
+    }
+    let key = rustls_pemfile::private_key(&mut open(&config.key)?)
+        .map_err(|e| format!("invalid key {}: {e}", config.key.display()))?
+        .ok_or_else(|| format!("no private key in {}", config.key.display()))?;
+    let server_config = RustlsConfig::builder()
+        .with_no_client_auth()
+        .with_single_cert(certs, key)
+        .map_err(|e| format!("certificate and key don't match: {e}"))?;
+    info!("TLS certificate loaded from {}", config.cert.display());
+    Ok(TlsAcceptor::from(Arc::new(server_config)))
+}
+
+/// Reloads the certificate on every SIGHUP, renewed certificates apply without a restart.
+#[cfg(unix)]
+pub async fn reload_on_hangup(tls: Arc<TlsTerminator>) {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    let mut hangups = match signal(SignalKind::hangup()) {
+        Ok(hangups) => hangups,
+        Err(e) => {
+            error!("failed to listen for SIGHUP, the certificate is never reloaded: {e}");
+            return;
+        }
+    };
+    while hangups.recv().await.is_some() {
+        info!("SIGHUP received, reloading the TLS certificate");
+        if let Err(e) = tls.reload() {
+            error!("keeping the current certificate: {e}");
+        }
+    }
+}
+
+#[cfg(not(unix))]
+pub async fn reload_on_hangup(_tls: Arc<TlsTerminator>) {}
+
+enum Transport {
+    Plain(TcpStream),
+    Tls(Box<TlsStream<TcpStream>>),
+}
+
+/// An accepted connection, encrypted when TLS is configured.
+pub struct ClientStream {
+    transport: Transport,
+    /// read ahead by `peek`, handed out again before reading on
+    peeked: Vec<u8>,
+}
+
+impl ClientStream {
+    fn new(transport: Transport) -> Self {
+        Self {
+            transport,
+            peeked: vec![],
+        }
+    }
+
+    pub fn plain(stream: TcpStream) -> Self {
+        Self::new(Transport::Plain(stream))
+    }
+
+    /// Reads the start of the request without consuming it, encrypted streams can't be
+    /// peeked at the socket.
+    pub async fn peek(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.peeked.is_empty() {
+            let mut ahead = vec![0; buf.len()];
+            let read = match &mut self.transport {
+                Transport::Plain(stream) => stream.read(&mut ahead).await?,
+                Transport::Tls(stream) => stream.read(&mut ahead).await?,
+            };
+            ahead.truncate(read);
+            self.peeked = ahead;
+        }
+        let len = self.peeked.len().min(buf.len());
+        buf[..len].copy_from_slice(&self.peeked[..len]);
+        Ok(len)
+    }
+}
+
+impl AsyncRead for ClientStream {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        if !this.peeked.is_empty() {
+            let len = this.peeked.len().min(buf.remaining());
+            buf.put_slice(&this.peeked[..len]);
+            this.peeked.drain(..len);
+            return Poll::Ready(Ok(()));
+        }
+        match &mut this.transport {
+            Transport::Plain(stream) => Pin::new(stream).poll_read(cx, buf),
+            Transport::Tls(stream) => Pin::new(stream).poll_read(cx, buf),
+        }
+    }
+}
+
+impl AsyncWrite for ClientStream {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        match &mut self.get_mut().transport {
+            Transport::Plain(stream) => Pin::new(stream).poll_write(cx, buf),
+            Transport::Tls(stream) => Pin::new(stream).poll_write(cx, buf),
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match &mut self.get_mut().transport {
+            Transport::Plain(stream) => Pin::new(stream).poll_flush(cx),
+            Transport::Tls(stream) => Pin::new(stream).poll_flush(cx),
+        }
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match &mut self.get_mut().transport {
+            Transport::Plain(stream) => Pin::new(stream).poll_shutdown(cx),
+            Transport::Tls(stream) => Pin::new(stream).poll_shutdown(cx),
+        }
+    }
+}
@@ -0,0 +1,288 @@
+use std::sync::Arc;
+
+use datafusion::execution::context::SessionState;
+use datafusion::logical_expr::LogicalPlan;
+use datafusion::prelude::SessionContext;
+use datafusion::sql::parser::DFParser;
+use datafusion::sql::sqlparser::dialect::{dialect_from_str, Dialect, GenericDialect};
+use datafusion::sql::sqlparser::keywords::Keyword;
+use datafusion::sql::sqlparser::parser::ParserError;
+use datafusion::sql::sqlparser::tokenizer::{Location, Token, TokenWithLocation, Tokenizer};
+use tokio::sync::mpsc::Sender;
+use tracing::debug;
+
+use proto_gen::query::{
+    query_service::Service, Diagnostic, Diagnostics, Severity, Span, ValidateSql,
+};
+
+use crate::access::Permission;
+use crate::config::SharedClientConfig;
+use crate::errors::AppErrors;
+use crate::query::reply;
+use crate::sessions::Role;
+use crate::state::ServerState;
+
+/// A significant token and the locations it starts and ends (exclusive) at.
+struct Located {
+    token: Token,
+    start: Location,
+    end: Location,
+}
+
+/// Parses and plans every statement of `req` without executing anything and replies with
+/// the problems found, planning with the options of the connection as execution would. A role
+/// that may not read the context is told so instead.
+pub(crate) async fn validate_sql(
+    state: Arc<ServerState>,
+    tx: Sender<Vec<u8>>,
+    role: Role,
+    config: SharedClientConfig,
+    req: ValidateSql,
+) -> Result<(), AppErrors> {
+    let diagnostics = match state.access.require(role, &req.context, Permission::Read) {
+        Ok(()) => {
+            // a context that wasn't created yet has no tables to plan against
+            let ctx = state.contexts.get(&req.context).unwrap_or_default();
+            let ctx = config.read().session_context(ctx);
+            diagnose(&ctx, &req.sql).await
+        }
+        Err(e) => {
+            let start = Location { line: 1, column: 1 };
+            vec![diagnostic(
+                Severity::Error,
+                e.to_string(),
+                start,
+                end_of(&req.sql),
+            )]
+        }
+    };
+    debug!(
+        "request {}: {} diagnostic(s)",
+        req.request_id,
+        diagnostics.len()
+    );
+
+    reply(
+        &tx,
+        Service::DiagnosticsMsg(Diagnostics {
+            request_id: req.request_id,
+            diagnostics,
+        }),
+    )
+    .await
+}
+
+async fn diagnose(ctx: &SessionContext, sql: &str) -> Vec<Diagnostic> {
+    let session = ctx.state();
+    let dialect_name = session.config().options().sql_parser.dialect.clone();
+    let dialect = dialect_from_str(dialect_name).unwrap_or_else(|| Box::new(GenericDialect {}));
+
+    let tokens = match Tokenizer::new(dialect.as_ref(), sql).tokenize_with_location() {
+        Ok(tokens) => locate(tokens, end_of(sql)),
+        Err(e) => {
+            let end = Location {
+                line: e.location.line,
+                column: e.location.column + 1,
+            };
+            return vec![diagnostic(Severity::Error, e.message, e.location, end)];
+        }
+    };
+
+    let mut diagnostics = vec![];
+    for statement in tokens.split(|t| t.token == Token::SemiColon) {
+        if statement.is_empty() {
+            continue;
+        }
+        diagnostics.extend(null_comparisons(statement));
+        match check_statement(&session, dialect.as_ref(), sql, statement).await {
+            Ok(plan) => diagnostics.extend(plan_warning(&plan, statement)),
+            Err(e) => diagnostics.push(e),
+        }
+    }
+    diagnostics
+}
+
+/// Parses and plans the statement, the error is placed on the tokens it is about.
+async fn check_statement(
+    session: &SessionState,
+    dialect: &dyn Dialect,
+    sql: &str,
+    statement: &[Located],
+) -> Result<LogicalPlan, Diagnostic> {
+    let (first, last) = (&statement[0], &statement[statement.len() - 1]);
+    let text = &sql[byte_offset(sql, first.start)..byte_offset(sql, last.end)];
+
+    let mut parsed = DFParser::parse_sql_with_dialect(text, dialect).map_err(|e| {
+        let message = match e {
+            ParserError::TokenizerError(m) | ParserError::ParserError(m) => m,
+            e @ ParserError::RecursionLimitExceeded => e.to_string(),
+        };
+        let (message, location) = split_location(&message);
+        // locations are relative to `text`, which starts at the first token
+        let token = location
+            .map(|l| Location {
+                line: first.start.line + l.line - 1,
+                column: if l.line == 1 {
+                    first.start.column + l.column - 1
+                } else {
+                    l.column
+                },
+            })
+            .and_then(|l| statement.iter().find(|t| t.start == l))
+            // no location: the statement ended unexpectedly
+            .unwrap_or(last);
+        diagnostic(Severity::Error, message, token.start, token.end)
+    })?;
+    let Some(parsed) = parsed.pop_front() else {
+        return Err(diagnostic(
+            Severity::Error,
+            "no statement found".to_string(),
+            first.start,
+            last.end,
+        ));
+    };
+
+    let plan = match session.statement_to_plan(parsed).await {
+        Ok(plan) => session.optimize(&plan),
+        Err(e) => Err(e),
+    };
+    plan.map_err(|e| {
+        let message = e.to_string();
+        let (start, end) = blame(&message, statement)
+            .map(|t| (t.start, t.end))
+            .unwrap_or((first.start, last.end));
+        diagnostic(Severity::Error, message, start, end)
+    })
+}
+
+fn plan_warning(plan: &LogicalPlan, statement: &[Located]) -> Option<Diagnostic> {
+    // checked after optimization, a cross join with a join condition in the filter becomes an
+    // inner join
+    has_cross_join(plan).then(|| {
+        diagnostic(
+            Severity::Warning,
+            "cross join: every row of one input is combined with every row of the other"
+                .to_string(),
+            statement[0].start,
+            statement[statement.len() - 1].end,
+        )
+    })
+}
+
+fn has_cross_join(plan: &LogicalPlan) -> bool {
+    matches!(plan, LogicalPlan::CrossJoin(_)) || plan.inputs().into_iter().any(has_cross_join)
+}
+
+/// `= NULL` and `<> NULL` are never true, `IS NULL` was most likely meant.
+fn null_comparisons(statement: &[Located]) -> Vec<Diagnostic> {
+    statement
+        .windows(2)
+        .filter_map(|pair| match (&pair[0].token, &pair[1].token) {
+            (Token::Eq | Token::Neq, Token::Word(w)) if w.keyword == Keyword::NULL => {
+                Some(diagnostic(
+                    Severity::Warning,
+                    "comparison with NULL is never true, use IS NULL or IS NOT NULL".to_string(),
+                    pair[0].start,
+                    pair[1].end,
+                ))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// The identifier a planner error is about: planner errors don't carry locations, so look for
+/// the names quoted in the message or following "named".
+fn blame<'t>(message: &str, statement: &'t [Located]) -> Option<&'t Located> {
+    let quoted = message
+        .split(['\'', '"'])
+        .skip(1)
+        .step_by(2)
+        .map(str::to_string);
+    let named = message
+        .split_whitespace()
+        .skip_while(|w| *w != "named")
+        .nth(1)
+        .map(|w| w.trim_end_matches(['.', ',']).to_string());
+
+    quoted.chain(named).find_map(|name| {
+        // qualified names: only the last part is a token of its own
+        let name = name.rsplit('.').next().unwrap_or_default().to_string();
+        statement
+            .iter()
+            .find(|t| matches!(&t.token, Token::Word(w) if w.value.eq_ignore_ascii_case(&name)))
+    })
+}
+
+/// Splits the " at Line: l, Column c" suffix sqlparser appends to its messages.
+fn split_location(message: &str) -> (String, Option<Location>) {
+    let location = message
+        .rsplit_once(" at Line: ")
+        .and_then(|(message, location)| {
+            let (line, column) = location.split_once(", Column ")?;
+            let location = Location {
+                line: line.parse().ok()?,
+                column: column.trim().parse().ok()?,
+            };
+            Some((message, location))
+        });
+    match location {
+        Some((message, location)) => (message.to_string(), Some(location)),
+        None => (message.to_string(), None),
+    }
+}
+
+/// Drops whitespace and comments, every remaining token ends where the next token starts.
+fn locate(tokens: Vec<TokenWithLocation>, end: Location) -> Vec<Located> {
+    let ends = tokens
+        .iter()
+        .skip(1)
+        .map(|t| t.location)
+        .chain([end])
+        .collect::<Vec<_>>();
+    tokens
+        .into_iter()
+        .zip(ends)
+        .filter(|(t, _)| !matches!(t.token, Token::Whitespace(_) | Token::EOF))
+        .map(|(t, end)| Located {
+            token: t.token,
+            start: t.location,
+            end,
+        })
+        .collect()
+}
+
+/// Location just past the last character of `sql`.
+fn end_of(sql: &str) -> Location {
+    let last_line = sql.rsplit('\n').next().unwrap_or_default();
+    Location {
+        line: sql.matches('\n').count() as u64 + 1,
+        column: last_line.chars().count() as u64 + 1,
+    }
+}
+
+fn byte_offset(sql: &str, location: Location) -> usize {
+    let line_start: usize = sql
+        .split_inclusive('\n')
+        .take(location.line.saturating_sub(1) as usize)
+        .map(str::len)
+        .sum();
+    sql[line_start..]
+        .char_indices()
+        .nth(location.column.saturating_sub(1) as usize)
+        .map(|(i, _)| line_start + i)
+        .unwrap_or(sql.len())
+}
+
+fn diagnostic(severity: Severity, message: String, start: Location, end: Location) -> Diagnostic {
+    Diagnostic {
+        severity: severity as i32,
+        message,
+        span: Some(Span {
+            start_line: start.line as u32,
+            start_column: start.column as u32,
+            end_line: end.line as u32,
+            end_column: end.column as u32,
+        }),
+    }
+}